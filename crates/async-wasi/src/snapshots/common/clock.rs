@@ -21,7 +21,14 @@ pub fn wasi_clock_time_get(
 ) -> Result<u64, Errno> {
     use std::time::SystemTime;
     match clock_id {
-        __wasi_clockid_t::__WASI_CLOCKID_REALTIME | __wasi_clockid_t::__WASI_CLOCKID_MONOTONIC => {
+        __wasi_clockid_t::__WASI_CLOCKID_REALTIME => {
+            let d = SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .unwrap();
+            let offset = ctx.timezone_offset_seconds() as i64 * 1_000_000_000;
+            Ok((d.as_nanos() as u64).saturating_add_signed(offset))
+        }
+        __wasi_clockid_t::__WASI_CLOCKID_MONOTONIC => {
             let d = SystemTime::now()
                 .duration_since(SystemTime::UNIX_EPOCH)
                 .unwrap();
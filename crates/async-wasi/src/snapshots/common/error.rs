@@ -232,3 +232,22 @@ impl From<std::io::Error> for Errno {
         Errno::from(&e)
     }
 }
+
+/// The error returned when mounting a file system into the [VFS](crate::snapshots::env::VFS) fails.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum WasiMountError {
+    /// The guest path already has a mounted file system.
+    AlreadyMounted(String),
+}
+
+impl std::fmt::Display for WasiMountError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WasiMountError::AlreadyMounted(path) => {
+                write!(f, "the guest path `{path}` is already mounted")
+            }
+        }
+    }
+}
+
+impl std::error::Error for WasiMountError {}
@@ -48,6 +48,13 @@ impl AsyncWasiSocketInner {
         }
     }
 
+    fn local_addr(&self) -> io::Result<SockAddr> {
+        match self {
+            AsyncWasiSocketInner::PreOpen(s) => s.local_addr(),
+            AsyncWasiSocketInner::AsyncFd(s) => s.get_ref().local_addr(),
+        }
+    }
+
     fn bind_device(&mut self, interface: Option<&[u8]>) -> io::Result<()> {
         match self {
             AsyncWasiSocketInner::PreOpen(s) => s.bind_device(interface),
@@ -62,6 +69,30 @@ impl AsyncWasiSocketInner {
         }
     }
 
+    fn join_multicast_v4(
+        &self,
+        multiaddr: &net::Ipv4Addr,
+        interface: &net::Ipv4Addr,
+    ) -> io::Result<()> {
+        match self {
+            AsyncWasiSocketInner::PreOpen(s) => s.join_multicast_v4(multiaddr, interface),
+            AsyncWasiSocketInner::AsyncFd(s) => s.get_ref().join_multicast_v4(multiaddr, interface),
+        }
+    }
+
+    fn leave_multicast_v4(
+        &self,
+        multiaddr: &net::Ipv4Addr,
+        interface: &net::Ipv4Addr,
+    ) -> io::Result<()> {
+        match self {
+            AsyncWasiSocketInner::PreOpen(s) => s.leave_multicast_v4(multiaddr, interface),
+            AsyncWasiSocketInner::AsyncFd(s) => {
+                s.get_ref().leave_multicast_v4(multiaddr, interface)
+            }
+        }
+    }
+
     fn listen(&mut self, backlog: i32) -> io::Result<()> {
         match self {
             AsyncWasiSocketInner::PreOpen(s) => {
@@ -315,7 +346,8 @@ impl AsyncWasiSocket {
         if let SocketType::Datagram = self.state.sock_type.1 {
             self.inner.register()?;
         }
-        self.state.local_addr = Some(addr);
+        // the port is assigned by the system if binding to the port 0
+        self.state.local_addr = Some(self.inner.local_addr()?.as_socket().unwrap_or(addr));
         Ok(())
     }
 
@@ -336,6 +368,31 @@ impl AsyncWasiSocket {
         Ok(())
     }
 
+    pub fn join_multicast_v4(
+        &mut self,
+        multiaddr: net::Ipv4Addr,
+        interface: net::Ipv4Addr,
+    ) -> io::Result<()> {
+        self.inner.join_multicast_v4(&multiaddr, &interface)?;
+        let membership = (multiaddr, interface);
+        if !self.state.ip_multicast_memberships.contains(&membership) {
+            self.state.ip_multicast_memberships.push(membership);
+        }
+        Ok(())
+    }
+
+    pub fn leave_multicast_v4(
+        &mut self,
+        multiaddr: net::Ipv4Addr,
+        interface: net::Ipv4Addr,
+    ) -> io::Result<()> {
+        self.inner.leave_multicast_v4(&multiaddr, &interface)?;
+        self.state
+            .ip_multicast_memberships
+            .retain(|m| *m != (multiaddr, interface));
+        Ok(())
+    }
+
     pub fn listen(&mut self, backlog: u32) -> io::Result<()> {
         self.inner.listen(backlog as i32)?;
         self.state.backlog = backlog;
@@ -640,7 +697,11 @@ impl AsyncWasiSocket {
     }
 
     pub fn shutdown(&mut self, how: net::Shutdown) -> io::Result<()> {
-        self.inner.get_ref()?.shutdown(how)?;
+        let sock = self.inner.get_ref()?;
+        if self.state.so_linger.is_some() {
+            sock.set_linger(self.state.so_linger)?;
+        }
+        sock.shutdown(how)?;
         self.state.shutdown.insert(how);
         Ok(())
     }
@@ -735,6 +796,15 @@ impl AsyncWasiSocket {
         self.state.so_send_timeout
     }
 
+    pub fn set_so_linger(&mut self, linger: Option<Duration>) -> io::Result<()> {
+        self.state.so_linger = linger;
+        Ok(())
+    }
+
+    pub fn get_so_linger(&self) -> Option<Duration> {
+        self.state.so_linger
+    }
+
     pub fn get_so_error(&mut self) -> io::Result<Option<io::Error>> {
         self.inner.get_ref()?.take_error()
     }
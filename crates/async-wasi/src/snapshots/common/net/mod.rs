@@ -66,6 +66,9 @@ pub struct WasiSocketState {
     pub so_send_buf_size: usize,
     pub so_recv_timeout: Option<Duration>,
     pub so_send_timeout: Option<Duration>,
+    pub so_linger: Option<Duration>,
+    /// The joined IPv4 multicast groups, as pairs of the group address and the local interface address.
+    pub ip_multicast_memberships: Vec<(net::Ipv4Addr, net::Ipv4Addr)>,
     pub fs_rights: WASIRights,
 }
 
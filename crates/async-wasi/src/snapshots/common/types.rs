@@ -384,6 +384,7 @@ pub struct __wasi_address_t {
 pub mod __wasi_sock_opt_level_t {
     pub type Type = u32;
     pub const __WASI_SOCK_OPT_LEVEL_SOL_SOCKET: Type = 0;
+    pub const __WASI_SOCK_OPT_LEVEL_IPPROTO_IP: Type = 1;
 }
 pub mod __wasi_sock_opt_so_t {
     pub type Type = u32;
@@ -403,6 +404,11 @@ pub mod __wasi_sock_opt_so_t {
     pub const __WASI_SOCK_OPT_SO_ACCEPTCONN: Type = 13;
     pub const __WASI_SOCK_OPT_SO_BINDTODEVICE: Type = 14;
 }
+pub mod __wasi_sock_opt_ip_t {
+    pub type Type = u32;
+    pub const __WASI_SOCK_OPT_IP_ADD_MEMBERSHIP: Type = 12;
+    pub const __WASI_SOCK_OPT_IP_DROP_MEMBERSHIP: Type = 13;
+}
 pub mod __wasi_aiflags_t {
     pub type Type = u16;
     pub const __WASI_AIFLAGS_AI_PASSIVE: Type = 1;
@@ -510,3 +516,17 @@ pub struct __wasi_timeval {
     pub tv_sec: i64,
     pub tv_usec: i64,
 }
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct __wasi_ip_mreq {
+    pub imr_multiaddr: [u8; 4],
+    pub imr_interface: [u8; 4],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct __wasi_linger {
+    pub l_onoff: i32,
+    pub l_linger: i32,
+}
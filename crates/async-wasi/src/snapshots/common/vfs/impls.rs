@@ -14,7 +14,7 @@ use super::{
 #[derive(Debug)]
 struct DirEntry {
     ino: usize,
-    is_dir: bool,
+    filetype: super::FileType,
 }
 
 #[derive(Debug)]
@@ -79,16 +79,8 @@ impl WasiNode for MemoryDir {
 impl WasiDir for MemoryDir {
     fn get_readdir(&self, start: u64) -> Result<Vec<(String, u64, super::FileType)>, Errno> {
         let mut r = vec![];
-        for (path, DirEntry { ino, is_dir }) in self.paths.iter().skip(start as usize) {
-            r.push((
-                path.clone(),
-                *ino as _,
-                if *is_dir {
-                    super::FileType::DIRECTORY
-                } else {
-                    super::FileType::REGULAR_FILE
-                },
-            ));
+        for (path, DirEntry { ino, filetype }) in self.paths.iter().skip(start as usize) {
+            r.push((path.clone(), *ino as _, *filetype));
         }
         Ok(r)
     }
@@ -97,9 +89,21 @@ impl WasiDir for MemoryDir {
 impl WasiVirtualDir for MemoryDir {
     fn create(ino: usize) -> Self {
         let mut paths = HashMap::default();
-        paths.insert(".".to_string(), DirEntry { ino, is_dir: true });
+        paths.insert(
+            ".".to_string(),
+            DirEntry {
+                ino,
+                filetype: super::FileType::DIRECTORY,
+            },
+        );
         if ino == 0 {
-            paths.insert("..".to_string(), DirEntry { ino, is_dir: true });
+            paths.insert(
+                "..".to_string(),
+                DirEntry {
+                    ino,
+                    filetype: super::FileType::DIRECTORY,
+                },
+            );
         }
 
         Self {
@@ -120,16 +124,37 @@ impl WasiVirtualDir for MemoryDir {
                 .to_str()
                 .ok_or(Errno::__WASI_ERRNO_ILSEQ)?
                 .to_string(),
-            DirEntry { ino, is_dir: true },
+            DirEntry {
+                ino,
+                filetype: super::FileType::DIRECTORY,
+            },
         );
         self.nlink += 1;
         Ok(())
     }
 
+    fn add_symlink<P: AsRef<std::path::Path>>(
+        &mut self,
+        path: &P,
+        ino: usize,
+    ) -> Result<(), Errno> {
+        self.paths.insert(
+            path.as_ref()
+                .to_str()
+                .ok_or(Errno::__WASI_ERRNO_ILSEQ)?
+                .to_string(),
+            DirEntry {
+                ino,
+                filetype: super::FileType::SYMBOLIC_LINK,
+            },
+        );
+        Ok(())
+    }
+
     fn remove_sub_dir<P: AsRef<std::path::Path>>(&mut self, path: &P) -> Result<(), Errno> {
         let path = path.as_ref().to_str().ok_or(Errno::__WASI_ERRNO_ILSEQ)?;
-        if let Some(DirEntry { ino, is_dir }) = self.paths.remove(path) {
-            if is_dir && self.nlink > 1 {
+        if let Some(DirEntry { ino, filetype }) = self.paths.remove(path) {
+            if filetype == super::FileType::DIRECTORY && self.nlink > 1 {
                 self.nlink -= 1;
             }
             Ok(())
@@ -148,7 +173,10 @@ impl WasiVirtualDir for MemoryDir {
                 .to_str()
                 .ok_or(Errno::__WASI_ERRNO_ILSEQ)?
                 .to_string(),
-            DirEntry { ino, is_dir: false },
+            DirEntry {
+                ino,
+                filetype: super::FileType::REGULAR_FILE,
+            },
         );
         Ok(())
     }
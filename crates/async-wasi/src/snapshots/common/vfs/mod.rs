@@ -347,6 +347,18 @@ pub trait WasiFile: WasiNode {
 
     fn fd_write(&mut self, bufs: &[io::IoSlice<'_>]) -> Result<usize, Errno>;
 
+    /// Returns the async writer backing the file, if any. The async `fd_write` awaits the writer instead of calling [WasiFile::fd_write], so that a full pipe yields to the runtime rather than blocking the thread.
+    #[cfg(feature = "async_tokio")]
+    fn async_writer(&mut self) -> Option<&mut (dyn tokio::io::AsyncWrite + Unpin + Send + Sync)> {
+        None
+    }
+
+    /// Returns a handle to wait for the file to become ready, if the file is an end of a [pipe](virtual_sys::PipeSys). The async `fd_read`, `fd_write` and `poll_oneoff` wait on it instead of failing with [Errno::__WASI_ERRNO_AGAIN].
+    #[cfg(feature = "async_tokio")]
+    fn pipe_readiness(&self) -> Option<virtual_sys::PipeReadiness> {
+        None
+    }
+
     fn fd_pwrite(
         &mut self,
         bufs: &[io::IoSlice<'_>],
@@ -451,10 +463,43 @@ pub trait WasiFileSys {
         follow_symlinks: bool,
     ) -> Result<Filestat, Errno>;
 
+    /// Sets the access and modification times of the file or directory at `path` relative to the directory `dir_ino`.
+    fn path_filestat_set_times(
+        &mut self,
+        dir_ino: Self::Index,
+        path: &str,
+        follow_symlinks: bool,
+        atim: wasi_types::__wasi_timestamp_t,
+        mtim: wasi_types::__wasi_timestamp_t,
+        fst_flags: wasi_types::__wasi_fstflags_t::Type,
+    ) -> Result<(), Errno> {
+        Err(Errno::__WASI_ERRNO_NOSYS)
+    }
+
+    /// Creates a symbolic link at `new_path` relative to the directory `dir_ino`, which points to `old_path`.
+    fn path_symlink(
+        &mut self,
+        old_path: &str,
+        dir_ino: Self::Index,
+        new_path: &str,
+    ) -> Result<(), Errno> {
+        Err(Errno::__WASI_ERRNO_NOSYS)
+    }
+
+    /// Returns the target of the symbolic link at `path` relative to the directory `dir_ino`.
+    fn path_readlink(&self, dir_ino: Self::Index, path: &str) -> Result<String, Errno> {
+        Err(Errno::__WASI_ERRNO_NOSYS)
+    }
+
     fn fclose(&mut self, ino: Self::Index) -> Result<(), Errno> {
         Ok(())
     }
 
+    /// Checks if all the inodes of the file system are closed for good, such as both ends of a pipe, so that the [VFS](crate::snapshots::env::VFS) drops the file system after closing its last file descriptor.
+    fn is_all_closed(&self) -> bool {
+        false
+    }
+
     fn get_mut_inode(&mut self, ino: usize) -> Result<&mut dyn WasiNode, Errno>;
     fn get_inode(&self, ino: usize) -> Result<&dyn WasiNode, Errno>;
 
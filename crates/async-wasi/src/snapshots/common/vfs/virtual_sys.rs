@@ -1,6 +1,7 @@
 use std::{
     io::{Read, Seek, Write},
     path::{Path, PathBuf},
+    sync::Arc,
     time::Duration,
 };
 
@@ -18,6 +19,7 @@ use super::{
 pub trait WasiVirtualDir: WasiDir {
     fn create(ino: usize) -> Self;
     fn add_sub_dir<P: AsRef<Path>>(&mut self, path: &P, ino: usize) -> Result<(), Errno>;
+    fn add_symlink<P: AsRef<Path>>(&mut self, path: &P, ino: usize) -> Result<(), Errno>;
     fn remove_sub_dir<P: AsRef<Path>>(&mut self, path: &P) -> Result<(), Errno>;
 
     fn link_inode<P: AsRef<Path>>(&mut self, path: &P, ino: usize) -> Result<(), Errno>;
@@ -45,9 +47,59 @@ pub trait WasiVirtualFile: WasiFile {
     fn close(&mut self) -> usize;
 }
 
+/// A symbolic link in a [WasiVirtualSys], which keeps the target path as is.
+#[derive(Debug)]
+pub struct VirtualSymlink {
+    ino: usize,
+    target: PathBuf,
+}
+
+impl VirtualSymlink {
+    pub fn target(&self) -> &Path {
+        &self.target
+    }
+}
+
+impl WasiNode for VirtualSymlink {
+    fn fd_fdstat_get(&self) -> Result<FdStat, Errno> {
+        Ok(FdStat {
+            filetype: FileType::SYMBOLIC_LINK,
+            fs_rights_base: WASIRights::empty(),
+            fs_rights_inheriting: WASIRights::empty(),
+            flags: FdFlags::empty(),
+        })
+    }
+
+    fn fd_filestat_get(&self) -> Result<Filestat, Errno> {
+        Ok(Filestat {
+            filetype: FileType::SYMBOLIC_LINK,
+            inode: self.ino as _,
+            nlink: 1,
+            size: self.target.as_os_str().len() as _,
+            atim: None,
+            mtim: None,
+            ctim: None,
+        })
+    }
+
+    fn fd_filestat_set_size(&mut self, size: wasi_types::__wasi_filesize_t) -> Result<(), Errno> {
+        Err(Errno::__WASI_ERRNO_BADF)
+    }
+
+    fn fd_filestat_set_times(
+        &mut self,
+        atim: wasi_types::__wasi_timestamp_t,
+        mtim: wasi_types::__wasi_timestamp_t,
+        fst_flags: wasi_types::__wasi_fstflags_t::Type,
+    ) -> Result<(), Errno> {
+        Ok(())
+    }
+}
+
 pub enum Inode<D: WasiVirtualDir, F: WasiVirtualFile> {
     Dir(D),
     File(F),
+    Symlink(VirtualSymlink),
 }
 
 impl<D: WasiVirtualDir, F: WasiVirtualFile> WasiNode for Inode<D, F> {
@@ -55,6 +107,7 @@ impl<D: WasiVirtualDir, F: WasiVirtualFile> WasiNode for Inode<D, F> {
         match self {
             Inode::Dir(dir) => dir.fd_fdstat_get(),
             Inode::File(file) => file.fd_fdstat_get(),
+            Inode::Symlink(link) => link.fd_fdstat_get(),
         }
     }
 
@@ -62,6 +115,7 @@ impl<D: WasiVirtualDir, F: WasiVirtualFile> WasiNode for Inode<D, F> {
         match self {
             Inode::Dir(dir) => dir.fd_fdstat_set_flags(flags),
             Inode::File(file) => file.fd_fdstat_set_flags(flags),
+            Inode::Symlink(link) => link.fd_fdstat_set_flags(flags),
         }
     }
 
@@ -73,6 +127,7 @@ impl<D: WasiVirtualDir, F: WasiVirtualFile> WasiNode for Inode<D, F> {
         match self {
             Inode::Dir(dir) => dir.fd_fdstat_set_rights(fs_rights_base, fs_rights_inheriting),
             Inode::File(file) => file.fd_fdstat_set_rights(fs_rights_base, fs_rights_inheriting),
+            Inode::Symlink(link) => link.fd_fdstat_set_rights(fs_rights_base, fs_rights_inheriting),
         }
     }
 
@@ -80,6 +135,7 @@ impl<D: WasiVirtualDir, F: WasiVirtualFile> WasiNode for Inode<D, F> {
         match self {
             Inode::Dir(dir) => dir.fd_filestat_get(),
             Inode::File(file) => file.fd_filestat_get(),
+            Inode::Symlink(link) => link.fd_filestat_get(),
         }
     }
 
@@ -87,6 +143,7 @@ impl<D: WasiVirtualDir, F: WasiVirtualFile> WasiNode for Inode<D, F> {
         match self {
             Inode::Dir(dir) => dir.fd_filestat_set_size(size),
             Inode::File(file) => file.fd_filestat_set_size(size),
+            Inode::Symlink(link) => link.fd_filestat_set_size(size),
         }
     }
 
@@ -99,6 +156,7 @@ impl<D: WasiVirtualDir, F: WasiVirtualFile> WasiNode for Inode<D, F> {
         match self {
             Inode::Dir(dir) => dir.fd_filestat_set_times(atim, mtim, fst_flags),
             Inode::File(file) => file.fd_filestat_set_times(atim, mtim, fst_flags),
+            Inode::Symlink(link) => link.fd_filestat_set_times(atim, mtim, fst_flags),
         }
     }
 }
@@ -216,6 +274,69 @@ impl<D: WasiVirtualDir, F: WasiVirtualFile> WasiVirtualSys<D, F> {
             Err(Errno::__WASI_ERRNO_NOTDIR)
         }
     }
+
+    pub fn create_symlink_inode<P: AsRef<Path>, T: AsRef<Path>>(
+        &mut self,
+        dir_ino: usize,
+        path: &P,
+        target: &T,
+    ) -> Result<usize, Errno> {
+        let new_link = VirtualSymlink {
+            ino: self.inodes.vacant_key(),
+            target: target.as_ref().to_path_buf(),
+        };
+        let new_ino = self.inodes.insert(Inode::Symlink(new_link));
+
+        if let Some(Inode::Dir(dir)) = self.inodes.get_mut(dir_ino) {
+            let r = dir.add_symlink(path, new_ino);
+            if r.is_err() {
+                self.inodes.remove(new_ino);
+            }
+            r?;
+            Ok(new_ino)
+        } else {
+            self.inodes.remove(new_ino);
+            Err(Errno::__WASI_ERRNO_NOTDIR)
+        }
+    }
+
+    /// Resolves the inode `ino` found at `path` until it is not a symbolic link. The target of a link is relative to the directory containing the link.
+    fn follow_symlinks<P: AsRef<Path>>(
+        &self,
+        dir_ino: usize,
+        path: &P,
+        mut ino: usize,
+    ) -> Result<usize, Errno> {
+        let mut path = path.as_ref().to_path_buf();
+        for _ in 0..MAX_SYMLINK_DEPTH {
+            match self.inodes.get(ino) {
+                Some(Inode::Symlink(link)) => {
+                    path = path
+                        .parent()
+                        .unwrap_or_else(|| Path::new(""))
+                        .join(link.target());
+                    ino = self.find_inode_index(dir_ino, &path)?;
+                }
+                _ => return Ok(ino),
+            }
+        }
+        Err(Errno::__WASI_ERRNO_LOOP)
+    }
+}
+
+const MAX_SYMLINK_DEPTH: usize = 32;
+
+/// Checks the target of a symbolic link to be created. An absolute target, or a target with a `..` component, could resolve outside the preopened directory, so only the targets descending from the directory containing the link are allowed.
+fn check_symlink_target(target: &str) -> Result<(), Errno> {
+    use std::path::Component;
+    if Path::new(target)
+        .components()
+        .all(|c| matches!(c, Component::Normal(_) | Component::CurDir))
+    {
+        Ok(())
+    } else {
+        Err(Errno::__WASI_ERRNO_PERM)
+    }
 }
 
 impl<D: WasiVirtualDir, F: WasiVirtualFile> WasiFileSys for WasiVirtualSys<D, F> {
@@ -249,7 +370,10 @@ impl<D: WasiVirtualDir, F: WasiVirtualFile> WasiFileSys for WasiVirtualSys<D, F>
         let read = fs_rights_base.contains(WASIRights::FD_READ);
         let write = fs_rights_base.contains(WASIRights::FD_WRITE);
 
-        let inode = self.find_inode_index(dir_ino, &path);
+        let inode = match self.find_inode_index(dir_ino, &path) {
+            Ok(ino) => Ok(self.follow_symlinks(dir_ino, &path, ino)?),
+            Err(e) => Err(e),
+        };
         match inode {
             Ok(ino) => match self.inodes.get_mut(ino).ok_or(Errno::__WASI_ERRNO_NOENT)? {
                 Inode::Dir(dir) => {
@@ -269,6 +393,7 @@ impl<D: WasiVirtualDir, F: WasiVirtualFile> WasiFileSys for WasiVirtualSys<D, F>
 
                     Ok(ino)
                 }
+                Inode::Symlink(_) => Err(Errno::__WASI_ERRNO_LOOP),
             },
             Err(e) => {
                 if oflags.intersects(OFlags::DIRECTORY) {
@@ -326,7 +451,7 @@ impl<D: WasiVirtualDir, F: WasiVirtualFile> WasiFileSys for WasiVirtualSys<D, F>
                         ino = self.create_dir_inode(ino, &entry)?;
                     }
                 }
-                Some(Inode::File(_)) => {
+                Some(Inode::File(_)) | Some(Inode::Symlink(_)) => {
                     return Err(Errno::__WASI_ERRNO_NOTDIR);
                 }
                 None => {
@@ -341,6 +466,7 @@ impl<D: WasiVirtualDir, F: WasiVirtualFile> WasiFileSys for WasiVirtualSys<D, F>
         let i = match self.inodes.get_mut(ino).ok_or(Errno::__WASI_ERRNO_BADF)? {
             Inode::Dir(dir) => dir.close(),
             Inode::File(file) => file.close(),
+            Inode::Symlink(_) => return Err(Errno::__WASI_ERRNO_BADF),
         };
         log::trace!("WasiVirtualSys path_open {ino} close_r={i}");
         if i == 0 {
@@ -406,20 +532,25 @@ impl<D: WasiVirtualDir, F: WasiVirtualFile> WasiFileSys for WasiVirtualSys<D, F>
             return Err(Errno::__WASI_ERRNO_NOTDIR);
         };
 
-        if let Inode::File(file) = self
+        match self
             .inodes
             .get_mut(file_ino)
             .ok_or(Errno::__WASI_ERRNO_BADF)?
         {
-            let link = file.dec_link()?;
-            log::trace!("WasiVirtualSys path_unlink_file {file_ino} nlink = {link}");
+            Inode::File(file) => {
+                let link = file.dec_link()?;
+                log::trace!("WasiVirtualSys path_unlink_file {file_ino} nlink = {link}");
 
-            if link == 0 && !file.is_open() {
+                if link == 0 && !file.is_open() {
+                    self.inodes.try_remove(file_ino);
+                }
+                Ok(())
+            }
+            Inode::Symlink(_) => {
                 self.inodes.try_remove(file_ino);
+                Ok(())
             }
-            Ok(())
-        } else {
-            Err(Errno::__WASI_ERRNO_ISDIR)
+            Inode::Dir(_) => Err(Errno::__WASI_ERRNO_ISDIR),
         }
     }
 
@@ -480,13 +611,74 @@ impl<D: WasiVirtualDir, F: WasiVirtualFile> WasiFileSys for WasiVirtualSys<D, F>
         let path: &Path = path.as_ref();
 
         self.dir_rights.can(WASIRights::PATH_FILESTAT_GET)?;
-        let inode = self.find_inode_index(dir_ino, &path)?;
+        let mut inode = self.find_inode_index(dir_ino, &path)?;
+        if follow_symlinks {
+            inode = self.follow_symlinks(dir_ino, &path, inode)?;
+        }
         self.inodes
             .get(inode)
             .ok_or(Errno::__WASI_ERRNO_NOENT)?
             .fd_filestat_get()
     }
 
+    fn path_symlink(
+        &mut self,
+        old_path: &str,
+        dir_ino: Self::Index,
+        new_path: &str,
+    ) -> Result<(), Errno> {
+        log::trace!("WasiVirtualSys path_symlink {old_path} ({dir_ino} {new_path})");
+        self.dir_rights.can(WASIRights::PATH_SYMLINK)?;
+        check_symlink_target(old_path)?;
+        if self.find_inode_index(dir_ino, &new_path).is_ok() {
+            return Err(Errno::__WASI_ERRNO_EXIST);
+        }
+
+        let new_path: &Path = new_path.as_ref();
+        let parent_dir_ino = if let Some(parent) = new_path.parent() {
+            self.find_inode_index(dir_ino, &parent)?
+        } else {
+            dir_ino
+        };
+
+        let file_name = new_path.file_name().ok_or(Errno::__WASI_ERRNO_INVAL)?;
+        self.create_symlink_inode(parent_dir_ino, &file_name, &old_path)?;
+        Ok(())
+    }
+
+    fn path_filestat_set_times(
+        &mut self,
+        dir_ino: Self::Index,
+        path: &str,
+        follow_symlinks: bool,
+        atim: wasi_types::__wasi_timestamp_t,
+        mtim: wasi_types::__wasi_timestamp_t,
+        fst_flags: wasi_types::__wasi_fstflags_t::Type,
+    ) -> Result<(), Errno> {
+        self.dir_rights.can(WASIRights::PATH_FILESTAT_SET_TIMES)?;
+        let mut inode = self.find_inode_index(dir_ino, &path)?;
+        if follow_symlinks {
+            inode = self.follow_symlinks(dir_ino, &path, inode)?;
+        }
+        self.inodes
+            .get_mut(inode)
+            .ok_or(Errno::__WASI_ERRNO_NOENT)?
+            .fd_filestat_set_times(atim, mtim, fst_flags)
+    }
+
+    fn path_readlink(&self, dir_ino: Self::Index, path: &str) -> Result<String, Errno> {
+        self.dir_rights.can(WASIRights::PATH_READLINK)?;
+        let inode = self.find_inode_index(dir_ino, &path)?;
+        match self.inodes.get(inode).ok_or(Errno::__WASI_ERRNO_NOENT)? {
+            Inode::Symlink(link) => Ok(link
+                .target()
+                .to_str()
+                .ok_or(Errno::__WASI_ERRNO_ILSEQ)?
+                .to_string()),
+            _ => Err(Errno::__WASI_ERRNO_INVAL),
+        }
+    }
+
     fn get_mut_inode(&mut self, ino: usize) -> Result<&mut dyn WasiNode, Errno> {
         Ok(self.inodes.get_mut(ino).ok_or(Errno::__WASI_ERRNO_BADF)?)
     }
@@ -570,12 +762,81 @@ fn systimespec(
     }
 }
 
+fn fst_times(
+    atim: wasi_types::__wasi_timestamp_t,
+    mtim: wasi_types::__wasi_timestamp_t,
+    fst_flags: wasi_types::__wasi_fstflags_t::Type,
+) -> Result<(Option<SystemTimeSpec>, Option<SystemTimeSpec>), Errno> {
+    use wasi_types::__wasi_fstflags_t;
+
+    let set_atim = (fst_flags & __wasi_fstflags_t::__WASI_FSTFLAGS_ATIM) > 0;
+    let set_atim_now = (fst_flags & __wasi_fstflags_t::__WASI_FSTFLAGS_ATIM_NOW) > 0;
+    let set_mtim = (fst_flags & __wasi_fstflags_t::__WASI_FSTFLAGS_MTIM) > 0;
+    let set_mtim_now = (fst_flags & __wasi_fstflags_t::__WASI_FSTFLAGS_MTIM_NOW) > 0;
+
+    Ok((
+        systimespec(set_atim, atim, set_atim_now)?,
+        systimespec(set_mtim, mtim, set_mtim_now)?,
+    ))
+}
+
+#[cfg(unix)]
+fn to_timespec(ts: Option<SystemTimeSpec>) -> libc::timespec {
+    match ts {
+        Some(SystemTimeSpec::Absolute(ts)) => libc::timespec {
+            tv_sec: ts.as_secs() as i64,
+            tv_nsec: ts.subsec_nanos() as i64,
+        },
+        Some(SystemTimeSpec::SymbolicNow) => libc::timespec {
+            tv_sec: 0,
+            tv_nsec: libc::UTIME_NOW,
+        },
+        None => libc::timespec {
+            tv_sec: 0,
+            tv_nsec: libc::UTIME_OMIT,
+        },
+    }
+}
+
+/// Sets the times of the file at the host `path` with `utimensat`.
+fn set_path_times(
+    path: &Path,
+    follow_symlinks: bool,
+    atim: wasi_types::__wasi_timestamp_t,
+    mtim: wasi_types::__wasi_timestamp_t,
+    fst_flags: wasi_types::__wasi_fstflags_t::Type,
+) -> Result<(), Errno> {
+    let (atim, mtim) = fst_times(atim, mtim, fst_flags)?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::ffi::OsStrExt;
+        let path = std::ffi::CString::new(path.as_os_str().as_bytes())
+            .or(Err(Errno::__WASI_ERRNO_INVAL))?;
+        let times = [to_timespec(atim), to_timespec(mtim)];
+        let flags = if follow_symlinks {
+            0
+        } else {
+            libc::AT_SYMLINK_NOFOLLOW
+        };
+        if unsafe { libc::utimensat(libc::AT_FDCWD, path.as_ptr(), times.as_ptr(), flags) } < 0 {
+            Err(std::io::Error::last_os_error())?;
+        }
+        Ok(())
+    }
+    #[cfg(not(unix))]
+    {
+        Err(Errno::__WASI_ERRNO_NOSYS)
+    }
+}
+
 #[derive(Debug)]
 pub struct DiskDir {
     // absolutize
     pub real_path: PathBuf,
     pub dir_rights: WASIRights,
     pub file_rights: WASIRights,
+    pub ref_count: u32,
 }
 
 impl DiskDir {
@@ -650,9 +911,8 @@ impl WasiNode for DiskDir {
         mtim: wasi_types::__wasi_timestamp_t,
         fst_flags: wasi_types::__wasi_fstflags_t::Type,
     ) -> Result<(), Errno> {
-        use wasi_types::__wasi_fstflags_t;
         self.dir_rights.can(WASIRights::FD_FILESTAT_SET_TIMES)?;
-        Err(Errno::__WASI_ERRNO_NOSYS)
+        set_path_times(&self.real_path, true, atim, mtim, fst_flags)
     }
 }
 
@@ -710,6 +970,7 @@ pub struct DiskFile {
     pub fd: std::fs::File,
     pub flags: FdFlags,
     pub right: WASIRights,
+    pub ref_count: u32,
 }
 
 impl WasiNode for DiskFile {
@@ -787,56 +1048,14 @@ impl WasiNode for DiskFile {
         mtim: wasi_types::__wasi_timestamp_t,
         fst_flags: wasi_types::__wasi_fstflags_t::Type,
     ) -> Result<(), Errno> {
-        use wasi_types::__wasi_fstflags_t;
-
         self.right.can(WASIRights::FD_FILESTAT_SET_TIMES)?;
-
-        let set_atim = (fst_flags & __wasi_fstflags_t::__WASI_FSTFLAGS_ATIM) > 0;
-        let set_atim_now = (fst_flags & __wasi_fstflags_t::__WASI_FSTFLAGS_ATIM_NOW) > 0;
-        let set_mtim = (fst_flags & __wasi_fstflags_t::__WASI_FSTFLAGS_MTIM) > 0;
-        let set_mtim_now = (fst_flags & __wasi_fstflags_t::__WASI_FSTFLAGS_MTIM_NOW) > 0;
-
-        let atim = systimespec(set_atim, atim, set_atim_now)?;
-        let mtim = systimespec(set_mtim, mtim, set_mtim_now)?;
+        let (atim, mtim) = fst_times(atim, mtim, fst_flags)?;
 
         #[cfg(unix)]
         {
             use std::os::unix::prelude::AsRawFd;
             let fd = self.fd.as_raw_fd();
-            let times = [
-                {
-                    match atim {
-                        Some(SystemTimeSpec::Absolute(atim)) => libc::timespec {
-                            tv_sec: atim.as_secs() as i64,
-                            tv_nsec: atim.subsec_nanos() as i64,
-                        },
-                        Some(SystemTimeSpec::SymbolicNow) => libc::timespec {
-                            tv_sec: 0,
-                            tv_nsec: libc::UTIME_NOW,
-                        },
-                        None => libc::timespec {
-                            tv_sec: 0,
-                            tv_nsec: libc::UTIME_OMIT,
-                        },
-                    }
-                },
-                {
-                    match mtim {
-                        Some(SystemTimeSpec::Absolute(mtim)) => libc::timespec {
-                            tv_sec: mtim.as_secs() as i64,
-                            tv_nsec: mtim.subsec_nanos() as i64,
-                        },
-                        Some(SystemTimeSpec::SymbolicNow) => libc::timespec {
-                            tv_sec: 0,
-                            tv_nsec: libc::UTIME_NOW,
-                        },
-                        None => libc::timespec {
-                            tv_sec: 0,
-                            tv_nsec: libc::UTIME_OMIT,
-                        },
-                    }
-                },
-            ];
+            let times = [to_timespec(atim), to_timespec(mtim)];
             if unsafe { libc::futimens(fd, times.as_ptr()) } < 0 {
                 Err(std::io::Error::last_os_error())?;
             }
@@ -865,10 +1084,27 @@ impl WasiFile for DiskFile {
         len: wasi_types::__wasi_filesize_t,
     ) -> Result<(), Errno> {
         self.right.can(WASIRights::FD_ALLOCATE)?;
+        let new_len = offset.checked_add(len).ok_or(Errno::__WASI_ERRNO_FBIG)?;
+
+        // `fallocate(2)` with mode 0 reserves the blocks on disk and extends the
+        // file size if needed, without writing the data.
+        #[cfg(target_os = "linux")]
+        {
+            use std::os::unix::prelude::AsRawFd;
+            let fd = self.fd.as_raw_fd();
+            if unsafe { libc::fallocate(fd, 0, offset as libc::off_t, len as libc::off_t) } == 0 {
+                return Ok(());
+            }
+            let err = std::io::Error::last_os_error();
+            // fall back to `set_len` if the underlying file system doesn't support it
+            if err.raw_os_error() != Some(libc::EOPNOTSUPP) {
+                Err(err)?;
+            }
+        }
+
         let f = &mut self.fd;
         let metadata = f.metadata()?;
         let file_len = metadata.len();
-        let new_len = offset + len;
         if new_len > file_len {
             let old_seek = f.stream_position()?;
             f.set_len(new_len)?;
@@ -1042,6 +1278,7 @@ impl DiskFileSys {
             real_path: host_path.clone(),
             dir_rights: WASIRights::dir_all(),
             file_rights: WASIRights::fd_all(),
+            ref_count: 1,
         }));
 
         Ok(DiskFileSys {
@@ -1093,6 +1330,9 @@ impl WasiFileSys for DiskFileSys {
 
         let path = parent_dir.get_absolutize_path(&path)?;
         if path == self.real_path {
+            if let Some(DiskInode::Dir(root)) = self.inodes.get_mut(0) {
+                root.ref_count += 1;
+            }
             return Ok(0);
         }
 
@@ -1103,10 +1343,26 @@ impl WasiFileSys for DiskFileSys {
             Some(meta) if meta.is_dir() => {
                 let dir_rights = self.dir_rights.clone() & fs_rights_base;
                 let file_rights = self.file_rights.clone() & fs_rights_inheriting;
+                // a directory re-opened with the same rights shares the inode, while every opened file keeps its own inode for its own offset
+                let opened = self.inodes.iter_mut().find_map(|(ino, inode)| match inode {
+                    DiskInode::Dir(dir)
+                        if dir.real_path == path
+                            && dir.dir_rights.bits() == dir_rights.bits()
+                            && dir.file_rights.bits() == file_rights.bits() =>
+                    {
+                        Some((ino, dir))
+                    }
+                    _ => None,
+                });
+                if let Some((ino, dir)) = opened {
+                    dir.ref_count += 1;
+                    return Ok(ino);
+                }
                 let ino = self.inodes.insert(DiskInode::Dir(DiskDir {
                     real_path: path,
                     dir_rights,
                     file_rights,
+                    ref_count: 1,
                 }));
                 return Ok(ino);
             }
@@ -1153,6 +1409,7 @@ impl WasiFileSys for DiskFileSys {
             fd,
             flags: fdflags,
             right: fs_rights_base,
+            ref_count: 1,
         }));
 
         Ok(ino)
@@ -1269,8 +1526,66 @@ impl WasiFileSys for DiskFileSys {
         })
     }
 
+    fn path_filestat_set_times(
+        &mut self,
+        dir_ino: Self::Index,
+        path: &str,
+        follow_symlinks: bool,
+        atim: wasi_types::__wasi_timestamp_t,
+        mtim: wasi_types::__wasi_timestamp_t,
+        fst_flags: wasi_types::__wasi_fstflags_t::Type,
+    ) -> Result<(), Errno> {
+        self.dir_rights.can(WASIRights::PATH_FILESTAT_SET_TIMES)?;
+        let parent_dir = match self.inodes.get(dir_ino).ok_or(Errno::__WASI_ERRNO_BADF)? {
+            DiskInode::Dir(dir) => dir,
+            _ => return Err(Errno::__WASI_ERRNO_NOTDIR),
+        };
+        let path = parent_dir.get_absolutize_path(&path)?;
+        set_path_times(&path, follow_symlinks, atim, mtim, fst_flags)
+    }
+
+    #[cfg(unix)]
+    fn path_symlink(
+        &mut self,
+        old_path: &str,
+        dir_ino: Self::Index,
+        new_path: &str,
+    ) -> Result<(), Errno> {
+        self.dir_rights.can(WASIRights::PATH_SYMLINK)?;
+        check_symlink_target(old_path)?;
+        let parent_dir = match self.inodes.get(dir_ino).ok_or(Errno::__WASI_ERRNO_BADF)? {
+            DiskInode::Dir(dir) => dir,
+            _ => return Err(Errno::__WASI_ERRNO_NOTDIR),
+        };
+        let new_path = parent_dir.get_absolutize_path(&new_path)?;
+        std::os::unix::fs::symlink(old_path, new_path)?;
+        Ok(())
+    }
+
+    fn path_readlink(&self, dir_ino: Self::Index, path: &str) -> Result<String, Errno> {
+        self.dir_rights.can(WASIRights::PATH_READLINK)?;
+        let parent_dir = match self.inodes.get(dir_ino).ok_or(Errno::__WASI_ERRNO_BADF)? {
+            DiskInode::Dir(dir) => dir,
+            _ => return Err(Errno::__WASI_ERRNO_NOTDIR),
+        };
+        let path = parent_dir.get_absolutize_path(&path)?;
+        let target = std::fs::read_link(path)?;
+        target
+            .into_os_string()
+            .into_string()
+            .or(Err(Errno::__WASI_ERRNO_ILSEQ))
+    }
+
     fn fclose(&mut self, ino: Self::Index) -> Result<(), Errno> {
-        self.inodes.try_remove(ino);
+        let ref_count = match self.inodes.get_mut(ino).ok_or(Errno::__WASI_ERRNO_BADF)? {
+            DiskInode::Dir(dir) => &mut dir.ref_count,
+            DiskInode::File(file) => &mut file.ref_count,
+        };
+        *ref_count = ref_count.saturating_sub(1);
+        log::trace!("DiskFileSys fclose {ino} ref_count={ref_count}");
+        if *ref_count == 0 {
+            self.inodes.remove(ino);
+        }
         Ok(())
     }
 
@@ -1311,88 +1626,1010 @@ impl WasiFileSys for DiskFileSys {
     }
 }
 
-// pipeline
-pub struct OutPipeline<W: Write>(W);
-impl<W: Write> From<W> for OutPipeline<W> {
-    fn from(value: W) -> Self {
-        Self(value)
-    }
+/// A writable [DiskFileSys] backed by a temporary directory on the host, which is useful as a scratch space for tests. The directory and everything in it are removed when the [TempFileSys] is dropped.
+pub struct TempFileSys {
+    // declared before `dir`, so the opened files are closed before the directory is removed
+    inner: DiskFileSys,
+    dir: tempfile::TempDir,
 }
-impl<W: Write> WasiNode for OutPipeline<W> {
-    fn fd_fdstat_get(&self) -> Result<FdStat, Errno> {
-        Ok(FdStat {
-            filetype: FileType::CHARACTER_DEVICE,
-            fs_rights_base: WASIRights::FD_WRITE | WASIRights::POLL_FD_READWRITE,
-            fs_rights_inheriting: WASIRights::empty(),
-            flags: FdFlags::APPEND,
-        })
+
+impl TempFileSys {
+    pub fn new() -> std::io::Result<Self> {
+        let dir = tempfile::tempdir()?;
+        let inner = DiskFileSys::new(dir.path().to_path_buf())?;
+        Ok(Self { inner, dir })
     }
 
-    fn fd_fdstat_set_flags(&mut self, flags: FdFlags) -> Result<(), Errno> {
-        Err(Errno::__WASI_ERRNO_BADF)
+    /// Returns the path of the temporary directory on the host.
+    pub fn host_path(&self) -> &Path {
+        self.dir.path()
     }
+}
 
-    fn fd_fdstat_set_rights(
+impl WasiFileSys for TempFileSys {
+    type Index = usize;
+
+    fn path_open(
         &mut self,
+        dir_ino: Self::Index,
+        path: &str,
+        oflags: OFlags,
         fs_rights_base: WASIRights,
-        _fs_rights_inheriting: WASIRights,
+        fs_rights_inheriting: WASIRights,
+        fdflags: FdFlags,
+    ) -> Result<Self::Index, Errno> {
+        self.inner.path_open(
+            dir_ino,
+            path,
+            oflags,
+            fs_rights_base,
+            fs_rights_inheriting,
+            fdflags,
+        )
+    }
+
+    fn path_rename(
+        &mut self,
+        old_dir: Self::Index,
+        old_path: &str,
+        new_dir: Self::Index,
+        new_path: &str,
     ) -> Result<(), Errno> {
-        Err(Errno::__WASI_ERRNO_BADF)
+        self.inner.path_rename(old_dir, old_path, new_dir, new_path)
     }
 
-    fn fd_filestat_get(&self) -> Result<Filestat, Errno> {
-        Ok(Filestat {
-            filetype: FileType::CHARACTER_DEVICE,
-            nlink: 0,
-            inode: 0,
-            size: 0,
-            atim: None,
-            mtim: None,
-            ctim: None,
-        })
+    fn path_create_directory(&mut self, dir_ino: Self::Index, path: &str) -> Result<(), Errno> {
+        self.inner.path_create_directory(dir_ino, path)
     }
 
-    fn fd_filestat_set_size(&mut self, size: wasi_types::__wasi_filesize_t) -> Result<(), Errno> {
-        Err(Errno::__WASI_ERRNO_BADF)
+    fn path_remove_directory(&mut self, dir_ino: Self::Index, path: &str) -> Result<(), Errno> {
+        self.inner.path_remove_directory(dir_ino, path)
     }
 
-    fn fd_filestat_set_times(
+    fn path_unlink_file(&mut self, dir_ino: Self::Index, path: &str) -> Result<(), Errno> {
+        self.inner.path_unlink_file(dir_ino, path)
+    }
+
+    fn path_symlink(
         &mut self,
-        atim: wasi_types::__wasi_timestamp_t,
-        mtim: wasi_types::__wasi_timestamp_t,
-        fst_flags: wasi_types::__wasi_fstflags_t::Type,
+        old_path: &str,
+        dir_ino: Self::Index,
+        new_path: &str,
     ) -> Result<(), Errno> {
-        Err(Errno::__WASI_ERRNO_BADF)
+        self.inner.path_symlink(old_path, dir_ino, new_path)
     }
-}
-impl<W: Write> WasiFile for OutPipeline<W> {
-    fn fd_advise(
+
+    fn path_readlink(&self, dir_ino: Self::Index, path: &str) -> Result<String, Errno> {
+        self.inner.path_readlink(dir_ino, path)
+    }
+
+    fn path_filestat_set_times(
+        &mut self,
+        dir_ino: Self::Index,
+        path: &str,
+        follow_symlinks: bool,
+        atim: wasi_types::__wasi_timestamp_t,
+        mtim: wasi_types::__wasi_timestamp_t,
+        fst_flags: wasi_types::__wasi_fstflags_t::Type,
+    ) -> Result<(), Errno> {
+        self.inner
+            .path_filestat_set_times(dir_ino, path, follow_symlinks, atim, mtim, fst_flags)
+    }
+
+    fn path_link_file(
+        &mut self,
+        old_dir: Self::Index,
+        old_path: &str,
+        new_dir: Self::Index,
+        new_path: &str,
+    ) -> Result<(), Errno> {
+        self.inner
+            .path_link_file(old_dir, old_path, new_dir, new_path)
+    }
+
+    fn path_filestat_get(
+        &self,
+        dir_ino: Self::Index,
+        path: &str,
+        follow_symlinks: bool,
+    ) -> Result<Filestat, Errno> {
+        self.inner.path_filestat_get(dir_ino, path, follow_symlinks)
+    }
+
+    fn fclose(&mut self, ino: Self::Index) -> Result<(), Errno> {
+        self.inner.fclose(ino)
+    }
+
+    fn get_mut_inode(&mut self, ino: usize) -> Result<&mut dyn WasiNode, Errno> {
+        self.inner.get_mut_inode(ino)
+    }
+
+    fn get_inode(&self, ino: usize) -> Result<&dyn WasiNode, Errno> {
+        self.inner.get_inode(ino)
+    }
+
+    fn get_mut_file(&mut self, ino: usize) -> Result<&mut dyn WasiFile, Errno> {
+        self.inner.get_mut_file(ino)
+    }
+
+    fn get_file(&self, ino: usize) -> Result<&dyn WasiFile, Errno> {
+        self.inner.get_file(ino)
+    }
+
+    fn get_mut_dir(&mut self, ino: usize) -> Result<&mut dyn WasiDir, Errno> {
+        self.inner.get_mut_dir(ino)
+    }
+
+    fn get_dir(&self, ino: usize) -> Result<&dyn WasiDir, Errno> {
+        self.inner.get_dir(ino)
+    }
+}
+
+/// A file system wrapper that makes the wrapped file system read-only. The read operations are delegated to the wrapped file system, while the operations which modify the file system or the files return `Errno::__WASI_ERRNO_ROFS`.
+pub struct ReadOnlyFileSys<F: WasiFileSys<Index = usize>> {
+    file: ReadOnlyFile<F>,
+}
+
+impl<F: WasiFileSys<Index = usize>> ReadOnlyFileSys<F> {
+    pub fn new(inner: F) -> Self {
+        Self {
+            file: ReadOnlyFile { inner, ino: 0 },
+        }
+    }
+
+    pub fn into_inner(self) -> F {
+        self.file.inner
+    }
+}
+
+// the view of an inode of the wrapped file system, which is handed out by `get_mut_inode` and `get_mut_file` to intercept the write operations
+struct ReadOnlyFile<F: WasiFileSys<Index = usize>> {
+    inner: F,
+    ino: usize,
+}
+
+impl<F: WasiFileSys<Index = usize>> WasiNode for ReadOnlyFile<F> {
+    fn fd_fdstat_get(&self) -> Result<FdStat, Errno> {
+        self.inner.get_inode(self.ino)?.fd_fdstat_get()
+    }
+
+    fn fd_fdstat_set_flags(&mut self, flags: FdFlags) -> Result<(), Errno> {
+        self.inner
+            .get_mut_inode(self.ino)?
+            .fd_fdstat_set_flags(flags)
+    }
+
+    fn fd_fdstat_set_rights(
+        &mut self,
+        fs_rights_base: WASIRights,
+        fs_rights_inheriting: WASIRights,
+    ) -> Result<(), Errno> {
+        self.inner
+            .get_mut_inode(self.ino)?
+            .fd_fdstat_set_rights(fs_rights_base, fs_rights_inheriting)
+    }
+
+    fn fd_filestat_get(&self) -> Result<Filestat, Errno> {
+        self.inner.get_inode(self.ino)?.fd_filestat_get()
+    }
+
+    fn fd_filestat_set_size(&mut self, size: wasi_types::__wasi_filesize_t) -> Result<(), Errno> {
+        Err(Errno::__WASI_ERRNO_ROFS)
+    }
+
+    fn fd_filestat_set_times(
+        &mut self,
+        atim: wasi_types::__wasi_timestamp_t,
+        mtim: wasi_types::__wasi_timestamp_t,
+        fst_flags: wasi_types::__wasi_fstflags_t::Type,
+    ) -> Result<(), Errno> {
+        Err(Errno::__WASI_ERRNO_ROFS)
+    }
+}
+
+impl<F: WasiFileSys<Index = usize>> WasiFile for ReadOnlyFile<F> {
+    fn fd_advise(
         &mut self,
         offset: wasi_types::__wasi_filesize_t,
         len: wasi_types::__wasi_filesize_t,
         advice: Advice,
     ) -> Result<(), Errno> {
-        Ok(())
+        self.inner
+            .get_mut_file(self.ino)?
+            .fd_advise(offset, len, advice)
+    }
+
+    fn fd_allocate(
+        &mut self,
+        offset: wasi_types::__wasi_filesize_t,
+        len: wasi_types::__wasi_filesize_t,
+    ) -> Result<(), Errno> {
+        Err(Errno::__WASI_ERRNO_ROFS)
+    }
+
+    fn fd_datasync(&mut self) -> Result<(), Errno> {
+        self.inner.get_mut_file(self.ino)?.fd_datasync()
+    }
+
+    fn fd_sync(&mut self) -> Result<(), Errno> {
+        self.inner.get_mut_file(self.ino)?.fd_sync()
+    }
+
+    fn fd_read(&mut self, bufs: &mut [std::io::IoSliceMut<'_>]) -> Result<usize, Errno> {
+        self.inner.get_mut_file(self.ino)?.fd_read(bufs)
+    }
+
+    fn fd_pread(
+        &mut self,
+        bufs: &mut [std::io::IoSliceMut<'_>],
+        offset: wasi_types::__wasi_filesize_t,
+    ) -> Result<usize, Errno> {
+        self.inner.get_mut_file(self.ino)?.fd_pread(bufs, offset)
+    }
+
+    fn fd_write(&mut self, bufs: &[std::io::IoSlice<'_>]) -> Result<usize, Errno> {
+        Err(Errno::__WASI_ERRNO_ROFS)
+    }
+
+    fn fd_pwrite(
+        &mut self,
+        bufs: &[std::io::IoSlice<'_>],
+        offset: wasi_types::__wasi_filesize_t,
+    ) -> Result<usize, Errno> {
+        Err(Errno::__WASI_ERRNO_ROFS)
+    }
+
+    fn fd_seek(
+        &mut self,
+        offset: wasi_types::__wasi_filedelta_t,
+        whence: wasi_types::__wasi_whence_t::Type,
+    ) -> Result<wasi_types::__wasi_filesize_t, Errno> {
+        self.inner.get_mut_file(self.ino)?.fd_seek(offset, whence)
+    }
+
+    fn fd_tell(&mut self) -> Result<wasi_types::__wasi_filesize_t, Errno> {
+        self.inner.get_mut_file(self.ino)?.fd_tell()
+    }
+}
+
+impl<F: WasiFileSys<Index = usize>> WasiFileSys for ReadOnlyFileSys<F> {
+    type Index = usize;
+
+    fn path_open(
+        &mut self,
+        dir_ino: Self::Index,
+        path: &str,
+        oflags: OFlags,
+        fs_rights_base: WASIRights,
+        fs_rights_inheriting: WASIRights,
+        fdflags: FdFlags,
+    ) -> Result<Self::Index, Errno> {
+        if oflags.intersects(OFlags::CREATE | OFlags::TRUNCATE) {
+            return Err(Errno::__WASI_ERRNO_ROFS);
+        }
+        self.file.inner.path_open(
+            dir_ino,
+            path,
+            oflags,
+            fs_rights_base,
+            fs_rights_inheriting,
+            fdflags,
+        )
+    }
+
+    fn path_rename(
+        &mut self,
+        old_dir: Self::Index,
+        old_path: &str,
+        new_dir: Self::Index,
+        new_path: &str,
+    ) -> Result<(), Errno> {
+        Err(Errno::__WASI_ERRNO_ROFS)
+    }
+
+    fn path_create_directory(&mut self, dir_ino: Self::Index, path: &str) -> Result<(), Errno> {
+        Err(Errno::__WASI_ERRNO_ROFS)
+    }
+
+    fn path_remove_directory(&mut self, dir_ino: Self::Index, path: &str) -> Result<(), Errno> {
+        Err(Errno::__WASI_ERRNO_ROFS)
+    }
+
+    fn path_unlink_file(&mut self, dir_ino: Self::Index, path: &str) -> Result<(), Errno> {
+        Err(Errno::__WASI_ERRNO_ROFS)
+    }
+
+    fn path_symlink(
+        &mut self,
+        old_path: &str,
+        dir_ino: Self::Index,
+        new_path: &str,
+    ) -> Result<(), Errno> {
+        Err(Errno::__WASI_ERRNO_ROFS)
+    }
+
+    fn path_readlink(&self, dir_ino: Self::Index, path: &str) -> Result<String, Errno> {
+        self.file.inner.path_readlink(dir_ino, path)
+    }
+
+    fn path_filestat_set_times(
+        &mut self,
+        dir_ino: Self::Index,
+        path: &str,
+        follow_symlinks: bool,
+        atim: wasi_types::__wasi_timestamp_t,
+        mtim: wasi_types::__wasi_timestamp_t,
+        fst_flags: wasi_types::__wasi_fstflags_t::Type,
+    ) -> Result<(), Errno> {
+        Err(Errno::__WASI_ERRNO_ROFS)
+    }
+
+    fn path_link_file(
+        &mut self,
+        old_dir: Self::Index,
+        old_path: &str,
+        new_dir: Self::Index,
+        new_path: &str,
+    ) -> Result<(), Errno> {
+        Err(Errno::__WASI_ERRNO_ROFS)
+    }
+
+    fn path_filestat_get(
+        &self,
+        dir_ino: Self::Index,
+        path: &str,
+        follow_symlinks: bool,
+    ) -> Result<Filestat, Errno> {
+        self.file
+            .inner
+            .path_filestat_get(dir_ino, path, follow_symlinks)
+    }
+
+    fn fclose(&mut self, ino: Self::Index) -> Result<(), Errno> {
+        self.file.inner.fclose(ino)
+    }
+
+    fn is_all_closed(&self) -> bool {
+        self.file.inner.is_all_closed()
+    }
+
+    fn get_mut_inode(&mut self, ino: usize) -> Result<&mut dyn WasiNode, Errno> {
+        self.file.inner.get_inode(ino)?;
+        self.file.ino = ino;
+        Ok(&mut self.file)
+    }
+
+    fn get_inode(&self, ino: usize) -> Result<&dyn WasiNode, Errno> {
+        self.file.inner.get_inode(ino)
+    }
+
+    fn get_mut_file(&mut self, ino: usize) -> Result<&mut dyn WasiFile, Errno> {
+        self.file.inner.get_file(ino)?;
+        self.file.ino = ino;
+        Ok(&mut self.file)
+    }
+
+    fn get_file(&self, ino: usize) -> Result<&dyn WasiFile, Errno> {
+        self.file.inner.get_file(ino)
+    }
+
+    fn get_mut_dir(&mut self, ino: usize) -> Result<&mut dyn WasiDir, Errno> {
+        self.file.inner.get_mut_dir(ino)
+    }
+
+    fn get_dir(&self, ino: usize) -> Result<&dyn WasiDir, Errno> {
+        self.file.inner.get_dir(ino)
+    }
+}
+
+// pipeline
+pub struct OutPipeline<W: Write>(W);
+impl<W: Write> From<W> for OutPipeline<W> {
+    fn from(value: W) -> Self {
+        Self(value)
+    }
+}
+impl<W: Write> WasiNode for OutPipeline<W> {
+    fn fd_fdstat_get(&self) -> Result<FdStat, Errno> {
+        Ok(FdStat {
+            filetype: FileType::CHARACTER_DEVICE,
+            fs_rights_base: WASIRights::FD_WRITE | WASIRights::POLL_FD_READWRITE,
+            fs_rights_inheriting: WASIRights::empty(),
+            flags: FdFlags::APPEND,
+        })
+    }
+
+    fn fd_fdstat_set_flags(&mut self, flags: FdFlags) -> Result<(), Errno> {
+        Err(Errno::__WASI_ERRNO_BADF)
+    }
+
+    fn fd_fdstat_set_rights(
+        &mut self,
+        fs_rights_base: WASIRights,
+        _fs_rights_inheriting: WASIRights,
+    ) -> Result<(), Errno> {
+        Err(Errno::__WASI_ERRNO_BADF)
+    }
+
+    fn fd_filestat_get(&self) -> Result<Filestat, Errno> {
+        Ok(Filestat {
+            filetype: FileType::CHARACTER_DEVICE,
+            nlink: 0,
+            inode: 0,
+            size: 0,
+            atim: None,
+            mtim: None,
+            ctim: None,
+        })
+    }
+
+    fn fd_filestat_set_size(&mut self, size: wasi_types::__wasi_filesize_t) -> Result<(), Errno> {
+        Err(Errno::__WASI_ERRNO_BADF)
+    }
+
+    fn fd_filestat_set_times(
+        &mut self,
+        atim: wasi_types::__wasi_timestamp_t,
+        mtim: wasi_types::__wasi_timestamp_t,
+        fst_flags: wasi_types::__wasi_fstflags_t::Type,
+    ) -> Result<(), Errno> {
+        Err(Errno::__WASI_ERRNO_BADF)
+    }
+}
+impl<W: Write> WasiFile for OutPipeline<W> {
+    fn fd_advise(
+        &mut self,
+        offset: wasi_types::__wasi_filesize_t,
+        len: wasi_types::__wasi_filesize_t,
+        advice: Advice,
+    ) -> Result<(), Errno> {
+        Ok(())
+    }
+
+    fn fd_allocate(
+        &mut self,
+        offset: wasi_types::__wasi_filesize_t,
+        len: wasi_types::__wasi_filesize_t,
+    ) -> Result<(), Errno> {
+        Err(Errno::__WASI_ERRNO_BADF)
+    }
+
+    fn fd_datasync(&mut self) -> Result<(), Errno> {
+        self.0.flush()?;
+        Ok(())
+    }
+
+    fn fd_sync(&mut self) -> Result<(), Errno> {
+        self.0.flush()?;
+        Ok(())
+    }
+
+    fn fd_read(&mut self, bufs: &mut [std::io::IoSliceMut<'_>]) -> Result<usize, Errno> {
+        Err(Errno::__WASI_ERRNO_BADF)
+    }
+
+    fn fd_pread(
+        &mut self,
+        bufs: &mut [std::io::IoSliceMut<'_>],
+        offset: wasi_types::__wasi_filesize_t,
+    ) -> Result<usize, Errno> {
+        Err(Errno::__WASI_ERRNO_BADF)
+    }
+
+    fn fd_write(&mut self, bufs: &[std::io::IoSlice<'_>]) -> Result<usize, Errno> {
+        Ok(self.0.write_vectored(bufs)?)
+    }
+
+    fn fd_pwrite(
+        &mut self,
+        bufs: &[std::io::IoSlice<'_>],
+        offset: wasi_types::__wasi_filesize_t,
+    ) -> Result<usize, Errno> {
+        Err(Errno::__WASI_ERRNO_SPIPE)
+    }
+
+    fn fd_seek(
+        &mut self,
+        offset: wasi_types::__wasi_filedelta_t,
+        whence: wasi_types::__wasi_whence_t::Type,
+    ) -> Result<wasi_types::__wasi_filesize_t, Errno> {
+        Err(Errno::__WASI_ERRNO_SPIPE)
+    }
+
+    fn fd_tell(&mut self) -> Result<wasi_types::__wasi_filesize_t, Errno> {
+        Err(Errno::__WASI_ERRNO_SPIPE)
+    }
+}
+
+pub struct InPipline<R: Read>(R);
+impl<R: Read> From<R> for InPipline<R> {
+    fn from(value: R) -> Self {
+        Self(value)
+    }
+}
+impl<R: Read> WasiNode for InPipline<R> {
+    fn fd_fdstat_get(&self) -> Result<FdStat, Errno> {
+        Ok(FdStat {
+            filetype: FileType::CHARACTER_DEVICE,
+            fs_rights_base: WASIRights::FD_READ | WASIRights::POLL_FD_READWRITE,
+            fs_rights_inheriting: WASIRights::empty(),
+            flags: FdFlags::empty(),
+        })
+    }
+
+    fn fd_fdstat_set_flags(&mut self, flags: FdFlags) -> Result<(), Errno> {
+        Err(Errno::__WASI_ERRNO_BADF)
+    }
+
+    fn fd_fdstat_set_rights(
+        &mut self,
+        fs_rights_base: WASIRights,
+        _fs_rights_inheriting: WASIRights,
+    ) -> Result<(), Errno> {
+        Err(Errno::__WASI_ERRNO_BADF)
+    }
+
+    fn fd_filestat_get(&self) -> Result<Filestat, Errno> {
+        Ok(Filestat {
+            filetype: FileType::CHARACTER_DEVICE,
+            nlink: 0,
+            inode: 0,
+            size: 0,
+            atim: None,
+            mtim: None,
+            ctim: None,
+        })
+    }
+
+    fn fd_filestat_set_size(&mut self, size: wasi_types::__wasi_filesize_t) -> Result<(), Errno> {
+        Err(Errno::__WASI_ERRNO_BADF)
+    }
+
+    fn fd_filestat_set_times(
+        &mut self,
+        atim: wasi_types::__wasi_timestamp_t,
+        mtim: wasi_types::__wasi_timestamp_t,
+        fst_flags: wasi_types::__wasi_fstflags_t::Type,
+    ) -> Result<(), Errno> {
+        Err(Errno::__WASI_ERRNO_BADF)
+    }
+}
+impl<R: Read> WasiFile for InPipline<R> {
+    fn fd_advise(
+        &mut self,
+        offset: wasi_types::__wasi_filesize_t,
+        len: wasi_types::__wasi_filesize_t,
+        advice: Advice,
+    ) -> Result<(), Errno> {
+        Ok(())
+    }
+
+    fn fd_allocate(
+        &mut self,
+        offset: wasi_types::__wasi_filesize_t,
+        len: wasi_types::__wasi_filesize_t,
+    ) -> Result<(), Errno> {
+        Err(Errno::__WASI_ERRNO_BADF)
+    }
+
+    fn fd_datasync(&mut self) -> Result<(), Errno> {
+        Ok(())
+    }
+
+    fn fd_sync(&mut self) -> Result<(), Errno> {
+        Ok(())
+    }
+
+    fn fd_read(&mut self, bufs: &mut [std::io::IoSliceMut<'_>]) -> Result<usize, Errno> {
+        Ok(self.0.read_vectored(bufs)?)
+    }
+
+    fn fd_pread(
+        &mut self,
+        bufs: &mut [std::io::IoSliceMut<'_>],
+        offset: wasi_types::__wasi_filesize_t,
+    ) -> Result<usize, Errno> {
+        Err(Errno::__WASI_ERRNO_SPIPE)
+    }
+
+    fn fd_write(&mut self, bufs: &[std::io::IoSlice<'_>]) -> Result<usize, Errno> {
+        Err(Errno::__WASI_ERRNO_BADF)
+    }
+
+    fn fd_pwrite(
+        &mut self,
+        bufs: &[std::io::IoSlice<'_>],
+        offset: wasi_types::__wasi_filesize_t,
+    ) -> Result<usize, Errno> {
+        Err(Errno::__WASI_ERRNO_BADF)
+    }
+
+    fn fd_seek(
+        &mut self,
+        offset: wasi_types::__wasi_filedelta_t,
+        whence: wasi_types::__wasi_whence_t::Type,
+    ) -> Result<wasi_types::__wasi_filesize_t, Errno> {
+        Err(Errno::__WASI_ERRNO_SPIPE)
+    }
+
+    fn fd_tell(&mut self) -> Result<wasi_types::__wasi_filesize_t, Errno> {
+        Err(Errno::__WASI_ERRNO_SPIPE)
+    }
+}
+
+pub struct StdioSys<IN, OUT, ERR>
+where
+    IN: std::io::Read,
+    OUT: std::io::Write,
+    ERR: std::io::Write,
+{
+    stdin: InPipline<IN>,
+    stdout: OutPipeline<OUT>,
+    stderr: OutPipeline<ERR>,
+}
+
+impl<IN, OUT, ERR> StdioSys<IN, OUT, ERR>
+where
+    IN: std::io::Read,
+    OUT: std::io::Write,
+    ERR: std::io::Write,
+{
+    pub fn new(stdin: IN, stdout: OUT, stderr: ERR) -> Self {
+        Self {
+            stdin: InPipline(stdin),
+            stdout: OutPipeline(stdout),
+            stderr: OutPipeline(stderr),
+        }
+    }
+}
+
+impl<IN, OUT, ERR> WasiFileSys for StdioSys<IN, OUT, ERR>
+where
+    IN: std::io::Read,
+    OUT: std::io::Write,
+    ERR: std::io::Write,
+{
+    type Index = usize;
+
+    fn path_open(
+        &mut self,
+        dir_ino: usize,
+        path: &str,
+        oflags: OFlags,
+        fs_rights_base: WASIRights,
+        fs_rights_inheriting: WASIRights,
+        fdflags: FdFlags,
+    ) -> Result<Self::Index, Errno> {
+        Err(Errno::__WASI_ERRNO_BADF)
+    }
+
+    fn path_rename(
+        &mut self,
+        old_dir: usize,
+        old_path: &str,
+        new_dir: usize,
+        new_path: &str,
+    ) -> Result<(), Errno> {
+        Err(Errno::__WASI_ERRNO_BADF)
+    }
+
+    fn path_create_directory(&mut self, dir_ino: usize, path: &str) -> Result<(), Errno> {
+        Err(Errno::__WASI_ERRNO_BADF)
+    }
+
+    fn path_remove_directory(&mut self, dir_ino: usize, path: &str) -> Result<(), Errno> {
+        Err(Errno::__WASI_ERRNO_BADF)
+    }
+
+    fn path_unlink_file(&mut self, dir_ino: Self::Index, path: &str) -> Result<(), Errno> {
+        Err(Errno::__WASI_ERRNO_BADF)
+    }
+
+    fn path_link_file(
+        &mut self,
+        old_dir: Self::Index,
+        old_path: &str,
+        new_dir: Self::Index,
+        new_path: &str,
+    ) -> Result<(), Errno> {
+        Err(Errno::__WASI_ERRNO_BADF)
+    }
+
+    fn path_filestat_get(
+        &self,
+        dir_ino: usize,
+        path: &str,
+        follow_symlinks: bool,
+    ) -> Result<Filestat, Errno> {
+        Err(Errno::__WASI_ERRNO_BADF)
+    }
+
+    fn get_mut_inode(&mut self, ino: usize) -> Result<&mut dyn WasiNode, Errno> {
+        match ino {
+            0 => Ok(&mut self.stdin),
+            1 => Ok(&mut self.stdout),
+            2 => Ok(&mut self.stderr),
+            _ => Err(Errno::__WASI_ERRNO_BADF),
+        }
+    }
+
+    fn get_inode(&self, ino: usize) -> Result<&dyn WasiNode, Errno> {
+        match ino {
+            0 => Ok(&self.stdin),
+            1 => Ok(&self.stdout),
+            2 => Ok(&self.stderr),
+            _ => Err(Errno::__WASI_ERRNO_BADF),
+        }
+    }
+
+    fn get_mut_file(&mut self, ino: usize) -> Result<&mut dyn WasiFile, Errno> {
+        match ino {
+            0 => Ok(&mut self.stdin),
+            1 => Ok(&mut self.stdout),
+            2 => Ok(&mut self.stderr),
+            _ => Err(Errno::__WASI_ERRNO_BADF),
+        }
+    }
+
+    fn get_file(&self, ino: usize) -> Result<&dyn WasiFile, Errno> {
+        match ino {
+            0 => Ok(&self.stdin),
+            1 => Ok(&self.stdout),
+            2 => Ok(&self.stderr),
+            _ => Err(Errno::__WASI_ERRNO_BADF),
+        }
+    }
+
+    fn get_mut_dir(&mut self, ino: usize) -> Result<&mut dyn WasiDir, Errno> {
+        Err(Errno::__WASI_ERRNO_NOTDIR)
+    }
+
+    fn get_dir(&self, ino: usize) -> Result<&dyn WasiDir, Errno> {
+        Err(Errno::__WASI_ERRNO_NOTDIR)
+    }
+}
+
+// anonymous pipe
+#[cfg(feature = "async_tokio")]
+#[derive(Debug, Default)]
+struct PipeBuffer {
+    data: std::collections::VecDeque<u8>,
+    reader_closed: bool,
+    writer_closed: bool,
+}
+
+#[cfg(feature = "async_tokio")]
+#[derive(Debug, Default)]
+struct PipeShared {
+    buffer: std::sync::Mutex<PipeBuffer>,
+    // notified whenever the buffered bytes or the open ends change
+    changed: tokio::sync::Notify,
+}
+
+#[cfg(feature = "async_tokio")]
+impl PipeShared {
+    fn lock(&self) -> std::sync::MutexGuard<'_, PipeBuffer> {
+        self.buffer.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn update<R>(&self, f: impl FnOnce(&mut PipeBuffer) -> R) -> R {
+        let r = f(&mut self.lock());
+        self.changed.notify_waiters();
+        r
+    }
+}
+
+/// A handle to wait for an end of a [PipeSys] to become ready, which stays valid after the end is borrowed or closed.
+#[cfg(feature = "async_tokio")]
+#[derive(Debug, Clone)]
+pub struct PipeReadiness(Arc<PipeShared>);
+
+#[cfg(feature = "async_tokio")]
+impl PipeReadiness {
+    /// Waits until the pipe holds bytes to read or its write end is closed. Returns the number of the buffered bytes and whether the write end is closed.
+    pub async fn readable(&self) -> (usize, bool) {
+        self.wait(|buf| {
+            (!buf.data.is_empty() || buf.writer_closed)
+                .then_some((buf.data.len(), buf.writer_closed))
+        })
+        .await
+    }
+
+    /// Waits until the pipe has room to write or its read end is closed. Returns the number of the free bytes and whether the read end is closed.
+    pub async fn writable(&self) -> (usize, bool) {
+        self.wait(|buf| {
+            let free = PipeSys::CAPACITY.saturating_sub(buf.data.len());
+            (free > 0 || buf.reader_closed).then_some((free, buf.reader_closed))
+        })
+        .await
+    }
+
+    async fn wait<R>(&self, ready: impl Fn(&PipeBuffer) -> Option<R>) -> R {
+        loop {
+            let notified = self.0.changed.notified();
+            tokio::pin!(notified);
+            // register before checking the state, so that a change in between is not missed
+            notified.as_mut().enable();
+            if let Some(r) = ready(&self.0.lock()) {
+                return r;
+            }
+            notified.await;
+        }
+    }
+}
+
+#[cfg(feature = "async_tokio")]
+pub struct PipeReader {
+    shared: Arc<PipeShared>,
+    flags: FdFlags,
+}
+#[cfg(feature = "async_tokio")]
+impl Drop for PipeReader {
+    fn drop(&mut self) {
+        self.shared.update(|buf| buf.reader_closed = true);
+    }
+}
+#[cfg(feature = "async_tokio")]
+impl WasiNode for PipeReader {
+    fn fd_fdstat_get(&self) -> Result<FdStat, Errno> {
+        Ok(FdStat {
+            filetype: FileType::CHARACTER_DEVICE,
+            fs_rights_base: WASIRights::FD_READ | WASIRights::POLL_FD_READWRITE,
+            fs_rights_inheriting: WASIRights::empty(),
+            flags: self.flags.clone(),
+        })
+    }
+
+    fn fd_fdstat_set_flags(&mut self, flags: FdFlags) -> Result<(), Errno> {
+        Err(Errno::__WASI_ERRNO_BADF)
+    }
+
+    fn fd_fdstat_set_rights(
+        &mut self,
+        fs_rights_base: WASIRights,
+        _fs_rights_inheriting: WASIRights,
+    ) -> Result<(), Errno> {
+        Err(Errno::__WASI_ERRNO_BADF)
+    }
+
+    fn fd_filestat_get(&self) -> Result<Filestat, Errno> {
+        Ok(Filestat {
+            filetype: FileType::CHARACTER_DEVICE,
+            nlink: 0,
+            inode: 0,
+            size: 0,
+            atim: None,
+            mtim: None,
+            ctim: None,
+        })
+    }
+
+    fn fd_filestat_set_size(&mut self, size: wasi_types::__wasi_filesize_t) -> Result<(), Errno> {
+        Err(Errno::__WASI_ERRNO_BADF)
+    }
+
+    fn fd_filestat_set_times(
+        &mut self,
+        atim: wasi_types::__wasi_timestamp_t,
+        mtim: wasi_types::__wasi_timestamp_t,
+        fst_flags: wasi_types::__wasi_fstflags_t::Type,
+    ) -> Result<(), Errno> {
+        Err(Errno::__WASI_ERRNO_BADF)
+    }
+}
+#[cfg(feature = "async_tokio")]
+impl WasiFile for PipeReader {
+    fn fd_read(&mut self, bufs: &mut [std::io::IoSliceMut<'_>]) -> Result<usize, Errno> {
+        self.shared.update(|buf| {
+            if buf.data.is_empty() {
+                // the write end is closed, so this is the end of the pipe
+                if buf.writer_closed {
+                    return Ok(0);
+                }
+                return Err(Errno::__WASI_ERRNO_AGAIN);
+            }
+            Ok(buf.data.read_vectored(bufs)?)
+        })
+    }
+
+    fn fd_pread(
+        &mut self,
+        bufs: &mut [std::io::IoSliceMut<'_>],
+        offset: wasi_types::__wasi_filesize_t,
+    ) -> Result<usize, Errno> {
+        Err(Errno::__WASI_ERRNO_SPIPE)
+    }
+
+    fn fd_write(&mut self, bufs: &[std::io::IoSlice<'_>]) -> Result<usize, Errno> {
+        Err(Errno::__WASI_ERRNO_BADF)
+    }
+
+    fn fd_pwrite(
+        &mut self,
+        bufs: &[std::io::IoSlice<'_>],
+        offset: wasi_types::__wasi_filesize_t,
+    ) -> Result<usize, Errno> {
+        Err(Errno::__WASI_ERRNO_BADF)
+    }
+
+    fn fd_seek(
+        &mut self,
+        offset: wasi_types::__wasi_filedelta_t,
+        whence: wasi_types::__wasi_whence_t::Type,
+    ) -> Result<wasi_types::__wasi_filesize_t, Errno> {
+        Err(Errno::__WASI_ERRNO_SPIPE)
+    }
+
+    fn fd_tell(&mut self) -> Result<wasi_types::__wasi_filesize_t, Errno> {
+        Err(Errno::__WASI_ERRNO_SPIPE)
+    }
+
+    fn pipe_readiness(&self) -> Option<PipeReadiness> {
+        Some(PipeReadiness(self.shared.clone()))
+    }
+}
+
+#[cfg(feature = "async_tokio")]
+pub struct PipeWriter {
+    shared: Arc<PipeShared>,
+    flags: FdFlags,
+}
+#[cfg(feature = "async_tokio")]
+impl Drop for PipeWriter {
+    fn drop(&mut self) {
+        self.shared.update(|buf| buf.writer_closed = true);
+    }
+}
+#[cfg(feature = "async_tokio")]
+impl WasiNode for PipeWriter {
+    fn fd_fdstat_get(&self) -> Result<FdStat, Errno> {
+        Ok(FdStat {
+            filetype: FileType::CHARACTER_DEVICE,
+            fs_rights_base: WASIRights::FD_WRITE | WASIRights::POLL_FD_READWRITE,
+            fs_rights_inheriting: WASIRights::empty(),
+            flags: self.flags.clone(),
+        })
+    }
+
+    fn fd_fdstat_set_flags(&mut self, flags: FdFlags) -> Result<(), Errno> {
+        Err(Errno::__WASI_ERRNO_BADF)
+    }
+
+    fn fd_fdstat_set_rights(
+        &mut self,
+        fs_rights_base: WASIRights,
+        _fs_rights_inheriting: WASIRights,
+    ) -> Result<(), Errno> {
+        Err(Errno::__WASI_ERRNO_BADF)
+    }
+
+    fn fd_filestat_get(&self) -> Result<Filestat, Errno> {
+        Ok(Filestat {
+            filetype: FileType::CHARACTER_DEVICE,
+            nlink: 0,
+            inode: 0,
+            size: 0,
+            atim: None,
+            mtim: None,
+            ctim: None,
+        })
+    }
+
+    fn fd_filestat_set_size(&mut self, size: wasi_types::__wasi_filesize_t) -> Result<(), Errno> {
+        Err(Errno::__WASI_ERRNO_BADF)
     }
 
-    fn fd_allocate(
+    fn fd_filestat_set_times(
         &mut self,
-        offset: wasi_types::__wasi_filesize_t,
-        len: wasi_types::__wasi_filesize_t,
+        atim: wasi_types::__wasi_timestamp_t,
+        mtim: wasi_types::__wasi_timestamp_t,
+        fst_flags: wasi_types::__wasi_fstflags_t::Type,
     ) -> Result<(), Errno> {
         Err(Errno::__WASI_ERRNO_BADF)
     }
-
-    fn fd_datasync(&mut self) -> Result<(), Errno> {
-        self.0.flush()?;
-        Ok(())
-    }
-
-    fn fd_sync(&mut self) -> Result<(), Errno> {
-        self.0.flush()?;
-        Ok(())
-    }
-
+}
+#[cfg(feature = "async_tokio")]
+impl WasiFile for PipeWriter {
     fn fd_read(&mut self, bufs: &mut [std::io::IoSliceMut<'_>]) -> Result<usize, Errno> {
         Err(Errno::__WASI_ERRNO_BADF)
     }
@@ -1406,7 +2643,29 @@ impl<W: Write> WasiFile for OutPipeline<W> {
     }
 
     fn fd_write(&mut self, bufs: &[std::io::IoSlice<'_>]) -> Result<usize, Errno> {
-        Ok(self.0.write_vectored(bufs)?)
+        let len: usize = bufs.iter().map(|b| b.len()).sum();
+        if len == 0 {
+            return Ok(0);
+        }
+        self.shared.update(|buf| {
+            // the read end is closed
+            if buf.reader_closed {
+                return Err(Errno::__WASI_ERRNO_PIPE);
+            }
+            let free = PipeSys::CAPACITY.saturating_sub(buf.data.len());
+            if free == 0 {
+                return Err(Errno::__WASI_ERRNO_AGAIN);
+            }
+            // the pipe is nearly full, so the write is partial
+            let n = len.min(free);
+            let mut rest = n;
+            for b in bufs {
+                let take = rest.min(b.len());
+                buf.data.extend(&b[..take]);
+                rest -= take;
+            }
+            Ok(n)
+        })
     }
 
     fn fd_pwrite(
@@ -1428,21 +2687,199 @@ impl<W: Write> WasiFile for OutPipeline<W> {
     fn fd_tell(&mut self) -> Result<wasi_types::__wasi_filesize_t, Errno> {
         Err(Errno::__WASI_ERRNO_SPIPE)
     }
+
+    fn pipe_readiness(&self) -> Option<PipeReadiness> {
+        Some(PipeReadiness(self.shared.clone()))
+    }
 }
 
-pub struct InPipline<R: Read>(R);
-impl<R: Read> From<R> for InPipline<R> {
-    fn from(value: R) -> Self {
+/// An anonymous pipe. The read end is the inode [PipeSys::READ_END] and the write end is the inode [PipeSys::WRITE_END]. Both ends are reported as character devices.
+///
+/// The pipe buffers at most [PipeSys::CAPACITY] bytes. A write to a full pipe is partial, or returns [Errno::__WASI_ERRNO_AGAIN] if nothing is written.
+#[cfg(feature = "async_tokio")]
+pub struct PipeSys {
+    reader: Option<PipeReader>,
+    writer: Option<PipeWriter>,
+}
+
+#[cfg(feature = "async_tokio")]
+impl PipeSys {
+    pub const READ_END: usize = 1;
+    pub const WRITE_END: usize = 2;
+    /// The maximum number of the bytes buffered in the pipe.
+    pub const CAPACITY: usize = 64 * 1024;
+
+    /// Creates a non-blocking pipe.
+    pub fn new() -> Self {
+        Self::with_flags(FdFlags::NONBLOCK)
+    }
+
+    /// Creates a pipe whose ends report the given fd flags.
+    ///
+    /// The ends themselves never block: reading an empty pipe whose write end is open, or writing a full pipe, returns [Errno::__WASI_ERRNO_AGAIN]. If [FdFlags::NONBLOCK] is absent, the async `fd_read` and `fd_write` of [preview_1](crate::snapshots::preview_1) wait on the [PipeReadiness] of the end instead.
+    pub fn with_flags(flags: FdFlags) -> Self {
+        let shared = Arc::new(PipeShared::default());
+        Self {
+            reader: Some(PipeReader {
+                shared: shared.clone(),
+                flags: flags.clone(),
+            }),
+            writer: Some(PipeWriter { shared, flags }),
+        }
+    }
+}
+
+#[cfg(feature = "async_tokio")]
+impl Default for PipeSys {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "async_tokio")]
+impl WasiFileSys for PipeSys {
+    type Index = usize;
+
+    fn path_open(
+        &mut self,
+        dir_ino: usize,
+        path: &str,
+        oflags: OFlags,
+        fs_rights_base: WASIRights,
+        fs_rights_inheriting: WASIRights,
+        fdflags: FdFlags,
+    ) -> Result<Self::Index, Errno> {
+        Err(Errno::__WASI_ERRNO_BADF)
+    }
+
+    fn path_rename(
+        &mut self,
+        old_dir: usize,
+        old_path: &str,
+        new_dir: usize,
+        new_path: &str,
+    ) -> Result<(), Errno> {
+        Err(Errno::__WASI_ERRNO_BADF)
+    }
+
+    fn path_create_directory(&mut self, dir_ino: usize, path: &str) -> Result<(), Errno> {
+        Err(Errno::__WASI_ERRNO_BADF)
+    }
+
+    fn path_remove_directory(&mut self, dir_ino: usize, path: &str) -> Result<(), Errno> {
+        Err(Errno::__WASI_ERRNO_BADF)
+    }
+
+    fn path_unlink_file(&mut self, dir_ino: Self::Index, path: &str) -> Result<(), Errno> {
+        Err(Errno::__WASI_ERRNO_BADF)
+    }
+
+    fn path_link_file(
+        &mut self,
+        old_dir: Self::Index,
+        old_path: &str,
+        new_dir: Self::Index,
+        new_path: &str,
+    ) -> Result<(), Errno> {
+        Err(Errno::__WASI_ERRNO_BADF)
+    }
+
+    fn path_filestat_get(
+        &self,
+        dir_ino: usize,
+        path: &str,
+        follow_symlinks: bool,
+    ) -> Result<Filestat, Errno> {
+        Err(Errno::__WASI_ERRNO_BADF)
+    }
+
+    fn fclose(&mut self, ino: Self::Index) -> Result<(), Errno> {
+        match ino {
+            Self::READ_END => self.reader.take().map(drop),
+            Self::WRITE_END => self.writer.take().map(drop),
+            _ => None,
+        }
+        .ok_or(Errno::__WASI_ERRNO_BADF)
+    }
+
+    fn is_all_closed(&self) -> bool {
+        self.reader.is_none() && self.writer.is_none()
+    }
+
+    fn get_mut_inode(&mut self, ino: usize) -> Result<&mut dyn WasiNode, Errno> {
+        match ino {
+            Self::READ_END => Ok(self.reader.as_mut().ok_or(Errno::__WASI_ERRNO_BADF)?),
+            Self::WRITE_END => Ok(self.writer.as_mut().ok_or(Errno::__WASI_ERRNO_BADF)?),
+            _ => Err(Errno::__WASI_ERRNO_BADF),
+        }
+    }
+
+    fn get_inode(&self, ino: usize) -> Result<&dyn WasiNode, Errno> {
+        match ino {
+            Self::READ_END => Ok(self.reader.as_ref().ok_or(Errno::__WASI_ERRNO_BADF)?),
+            Self::WRITE_END => Ok(self.writer.as_ref().ok_or(Errno::__WASI_ERRNO_BADF)?),
+            _ => Err(Errno::__WASI_ERRNO_BADF),
+        }
+    }
+
+    fn get_mut_file(&mut self, ino: usize) -> Result<&mut dyn WasiFile, Errno> {
+        match ino {
+            Self::READ_END => Ok(self.reader.as_mut().ok_or(Errno::__WASI_ERRNO_BADF)?),
+            Self::WRITE_END => Ok(self.writer.as_mut().ok_or(Errno::__WASI_ERRNO_BADF)?),
+            _ => Err(Errno::__WASI_ERRNO_BADF),
+        }
+    }
+
+    fn get_file(&self, ino: usize) -> Result<&dyn WasiFile, Errno> {
+        match ino {
+            Self::READ_END => Ok(self.reader.as_ref().ok_or(Errno::__WASI_ERRNO_BADF)?),
+            Self::WRITE_END => Ok(self.writer.as_ref().ok_or(Errno::__WASI_ERRNO_BADF)?),
+            _ => Err(Errno::__WASI_ERRNO_BADF),
+        }
+    }
+
+    fn get_mut_dir(&mut self, ino: usize) -> Result<&mut dyn WasiDir, Errno> {
+        Err(Errno::__WASI_ERRNO_NOTDIR)
+    }
+
+    fn get_dir(&self, ino: usize) -> Result<&dyn WasiDir, Errno> {
+        Err(Errno::__WASI_ERRNO_NOTDIR)
+    }
+}
+
+/// A write-only pipeline backed by a [tokio::io::AsyncWrite]. The async `fd_write` awaits the writer, so writing to a full pipe yields to the runtime instead of blocking the thread. The synchronous [WasiFile::fd_write] returns [Errno::__WASI_ERRNO_AGAIN] if the writer is not ready.
+#[cfg(feature = "async_tokio")]
+pub struct AsyncOutPipeline<W: tokio::io::AsyncWrite + Unpin + Send + Sync>(W);
+#[cfg(feature = "async_tokio")]
+impl<W: tokio::io::AsyncWrite + Unpin + Send + Sync> From<W> for AsyncOutPipeline<W> {
+    fn from(value: W) -> Self {
         Self(value)
     }
 }
-impl<R: Read> WasiNode for InPipline<R> {
+#[cfg(feature = "async_tokio")]
+impl<W: tokio::io::AsyncWrite + Unpin + Send + Sync> AsyncOutPipeline<W> {
+    fn poll_once<T>(
+        &mut self,
+        f: impl FnOnce(
+            std::pin::Pin<&mut W>,
+            &mut std::task::Context<'_>,
+        ) -> std::task::Poll<std::io::Result<T>>,
+    ) -> Result<T, Errno> {
+        let mut cx = std::task::Context::from_waker(futures::task::noop_waker_ref());
+        match f(std::pin::Pin::new(&mut self.0), &mut cx) {
+            std::task::Poll::Ready(r) => Ok(r?),
+            std::task::Poll::Pending => Err(Errno::__WASI_ERRNO_AGAIN),
+        }
+    }
+}
+#[cfg(feature = "async_tokio")]
+impl<W: tokio::io::AsyncWrite + Unpin + Send + Sync> WasiNode for AsyncOutPipeline<W> {
     fn fd_fdstat_get(&self) -> Result<FdStat, Errno> {
         Ok(FdStat {
             filetype: FileType::CHARACTER_DEVICE,
-            fs_rights_base: WASIRights::FD_READ | WASIRights::POLL_FD_READWRITE,
+            fs_rights_base: WASIRights::FD_WRITE | WASIRights::POLL_FD_READWRITE,
             fs_rights_inheriting: WASIRights::empty(),
-            flags: FdFlags::empty(),
+            flags: FdFlags::APPEND,
         })
     }
 
@@ -1483,34 +2920,18 @@ impl<R: Read> WasiNode for InPipline<R> {
         Err(Errno::__WASI_ERRNO_BADF)
     }
 }
-impl<R: Read> WasiFile for InPipline<R> {
-    fn fd_advise(
-        &mut self,
-        offset: wasi_types::__wasi_filesize_t,
-        len: wasi_types::__wasi_filesize_t,
-        advice: Advice,
-    ) -> Result<(), Errno> {
-        Ok(())
-    }
-
-    fn fd_allocate(
-        &mut self,
-        offset: wasi_types::__wasi_filesize_t,
-        len: wasi_types::__wasi_filesize_t,
-    ) -> Result<(), Errno> {
-        Err(Errno::__WASI_ERRNO_BADF)
-    }
-
+#[cfg(feature = "async_tokio")]
+impl<W: tokio::io::AsyncWrite + Unpin + Send + Sync> WasiFile for AsyncOutPipeline<W> {
     fn fd_datasync(&mut self) -> Result<(), Errno> {
-        Ok(())
+        self.poll_once(|w, cx| w.poll_flush(cx))
     }
 
     fn fd_sync(&mut self) -> Result<(), Errno> {
-        Ok(())
+        self.poll_once(|w, cx| w.poll_flush(cx))
     }
 
     fn fd_read(&mut self, bufs: &mut [std::io::IoSliceMut<'_>]) -> Result<usize, Errno> {
-        Ok(self.0.read_vectored(bufs)?)
+        Err(Errno::__WASI_ERRNO_BADF)
     }
 
     fn fd_pread(
@@ -1518,11 +2939,15 @@ impl<R: Read> WasiFile for InPipline<R> {
         bufs: &mut [std::io::IoSliceMut<'_>],
         offset: wasi_types::__wasi_filesize_t,
     ) -> Result<usize, Errno> {
-        Err(Errno::__WASI_ERRNO_SPIPE)
+        Err(Errno::__WASI_ERRNO_BADF)
     }
 
     fn fd_write(&mut self, bufs: &[std::io::IoSlice<'_>]) -> Result<usize, Errno> {
-        Err(Errno::__WASI_ERRNO_BADF)
+        self.poll_once(|w, cx| w.poll_write_vectored(cx, bufs))
+    }
+
+    fn async_writer(&mut self) -> Option<&mut (dyn tokio::io::AsyncWrite + Unpin + Send + Sync)> {
+        Some(&mut self.0)
     }
 
     fn fd_pwrite(
@@ -1530,7 +2955,7 @@ impl<R: Read> WasiFile for InPipline<R> {
         bufs: &[std::io::IoSlice<'_>],
         offset: wasi_types::__wasi_filesize_t,
     ) -> Result<usize, Errno> {
-        Err(Errno::__WASI_ERRNO_BADF)
+        Err(Errno::__WASI_ERRNO_SPIPE)
     }
 
     fn fd_seek(
@@ -1546,38 +2971,39 @@ impl<R: Read> WasiFile for InPipline<R> {
     }
 }
 
-pub struct StdioSys<IN, OUT, ERR>
-where
-    IN: std::io::Read,
-    OUT: std::io::Write,
-    ERR: std::io::Write,
-{
-    stdin: InPipline<IN>,
-    stdout: OutPipeline<OUT>,
-    stderr: OutPipeline<ERR>,
+/// A file system holding a single [AsyncOutPipeline] as the inode [AsyncOutPipelineSys::INO].
+#[cfg(feature = "async_tokio")]
+pub struct AsyncOutPipelineSys<W: tokio::io::AsyncWrite + Unpin + Send + Sync> {
+    pipeline: Option<AsyncOutPipeline<W>>,
 }
 
-impl<IN, OUT, ERR> StdioSys<IN, OUT, ERR>
-where
-    IN: std::io::Read,
-    OUT: std::io::Write,
-    ERR: std::io::Write,
-{
-    pub fn new(stdin: IN, stdout: OUT, stderr: ERR) -> Self {
+#[cfg(feature = "async_tokio")]
+impl<W: tokio::io::AsyncWrite + Unpin + Send + Sync> AsyncOutPipelineSys<W> {
+    pub const INO: usize = 1;
+
+    pub fn new(writer: W) -> Self {
         Self {
-            stdin: InPipline(stdin),
-            stdout: OutPipeline(stdout),
-            stderr: OutPipeline(stderr),
+            pipeline: Some(AsyncOutPipeline(writer)),
+        }
+    }
+
+    fn get_pipeline(&self, ino: usize) -> Result<&AsyncOutPipeline<W>, Errno> {
+        match ino {
+            Self::INO => self.pipeline.as_ref().ok_or(Errno::__WASI_ERRNO_BADF),
+            _ => Err(Errno::__WASI_ERRNO_BADF),
+        }
+    }
+
+    fn get_mut_pipeline(&mut self, ino: usize) -> Result<&mut AsyncOutPipeline<W>, Errno> {
+        match ino {
+            Self::INO => self.pipeline.as_mut().ok_or(Errno::__WASI_ERRNO_BADF),
+            _ => Err(Errno::__WASI_ERRNO_BADF),
         }
     }
 }
 
-impl<IN, OUT, ERR> WasiFileSys for StdioSys<IN, OUT, ERR>
-where
-    IN: std::io::Read,
-    OUT: std::io::Write,
-    ERR: std::io::Write,
-{
+#[cfg(feature = "async_tokio")]
+impl<W: tokio::io::AsyncWrite + Unpin + Send + Sync> WasiFileSys for AsyncOutPipelineSys<W> {
     type Index = usize;
 
     fn path_open(
@@ -1633,40 +3059,32 @@ where
         Err(Errno::__WASI_ERRNO_BADF)
     }
 
-    fn get_mut_inode(&mut self, ino: usize) -> Result<&mut dyn WasiNode, Errno> {
+    fn fclose(&mut self, ino: Self::Index) -> Result<(), Errno> {
         match ino {
-            0 => Ok(&mut self.stdin),
-            1 => Ok(&mut self.stdout),
-            2 => Ok(&mut self.stderr),
-            _ => Err(Errno::__WASI_ERRNO_BADF),
+            Self::INO => self.pipeline.take().map(drop),
+            _ => None,
         }
+        .ok_or(Errno::__WASI_ERRNO_BADF)
+    }
+
+    fn is_all_closed(&self) -> bool {
+        self.pipeline.is_none()
+    }
+
+    fn get_mut_inode(&mut self, ino: usize) -> Result<&mut dyn WasiNode, Errno> {
+        Ok(self.get_mut_pipeline(ino)?)
     }
 
     fn get_inode(&self, ino: usize) -> Result<&dyn WasiNode, Errno> {
-        match ino {
-            0 => Ok(&self.stdin),
-            1 => Ok(&self.stdout),
-            2 => Ok(&self.stderr),
-            _ => Err(Errno::__WASI_ERRNO_BADF),
-        }
+        Ok(self.get_pipeline(ino)?)
     }
 
     fn get_mut_file(&mut self, ino: usize) -> Result<&mut dyn WasiFile, Errno> {
-        match ino {
-            0 => Ok(&mut self.stdin),
-            1 => Ok(&mut self.stdout),
-            2 => Ok(&mut self.stderr),
-            _ => Err(Errno::__WASI_ERRNO_BADF),
-        }
+        Ok(self.get_mut_pipeline(ino)?)
     }
 
     fn get_file(&self, ino: usize) -> Result<&dyn WasiFile, Errno> {
-        match ino {
-            0 => Ok(&self.stdin),
-            1 => Ok(&self.stdout),
-            2 => Ok(&self.stderr),
-            _ => Err(Errno::__WASI_ERRNO_BADF),
-        }
+        Ok(self.get_pipeline(ino)?)
     }
 
     fn get_mut_dir(&mut self, ino: usize) -> Result<&mut dyn WasiDir, Errno> {
@@ -1677,3 +3095,136 @@ where
         Err(Errno::__WASI_ERRNO_NOTDIR)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::snapshots::common::vfs::impls::{MemoryDir, MemoryFile};
+
+    #[cfg(unix)]
+    #[test]
+    fn test_disk_path_symlink_escape() {
+        let tmp = tempfile::tempdir().unwrap();
+        let root = tmp.path().join("root");
+        std::fs::create_dir_all(root.join("sub")).unwrap();
+        std::fs::write(tmp.path().join("secret.txt"), b"secret").unwrap();
+        std::fs::write(root.join("sub").join("a.txt"), b"a").unwrap();
+
+        let mut fs = DiskFileSys::new(root.clone()).unwrap();
+        for target in ["..", "../secret.txt", "sub/../..", "sub/..", "/etc"] {
+            assert_eq!(
+                fs.path_symlink(target, 0, "esc"),
+                Err(Errno::__WASI_ERRNO_PERM),
+                "{target}"
+            );
+        }
+        assert!(std::fs::symlink_metadata(root.join("esc")).is_err());
+        assert!(fs
+            .path_open(
+                0,
+                "esc/secret.txt",
+                OFlags::empty(),
+                WASIRights::fd_all(),
+                WASIRights::empty(),
+                FdFlags::empty(),
+            )
+            .is_err());
+
+        // the targets descending from the directory of the link are allowed
+        fs.path_symlink("./sub/a.txt", 0, "link").unwrap();
+        assert_eq!(fs.path_readlink(0, "link").unwrap(), "./sub/a.txt");
+        let ino = fs
+            .path_open(
+                0,
+                "link",
+                OFlags::empty(),
+                WASIRights::fd_all(),
+                WASIRights::empty(),
+                FdFlags::empty(),
+            )
+            .unwrap();
+        let mut buf = [0u8; 1];
+        let n = fs
+            .get_mut_file(ino)
+            .unwrap()
+            .fd_read(&mut [std::io::IoSliceMut::new(&mut buf)])
+            .unwrap();
+        assert_eq!(&buf[..n], b"a");
+    }
+
+    #[test]
+    fn test_disk_reopen_ref_count() {
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(tmp.path().join("sub")).unwrap();
+        std::fs::write(tmp.path().join("a.txt"), b"a").unwrap();
+        let mut fs = DiskFileSys::new(tmp.path().to_path_buf()).unwrap();
+        let open = |fs: &mut DiskFileSys, path: &str| {
+            fs.path_open(
+                0,
+                path,
+                OFlags::empty(),
+                WASIRights::fd_all(),
+                WASIRights::fd_all(),
+                FdFlags::empty(),
+            )
+        };
+
+        // the re-opened directory shares the inode until the last close
+        let dir = open(&mut fs, "sub").unwrap();
+        assert_eq!(open(&mut fs, "sub").unwrap(), dir);
+        fs.fclose(dir).unwrap();
+        assert!(fs.get_dir(dir).is_ok());
+        fs.fclose(dir).unwrap();
+        assert!(fs.get_dir(dir).is_err());
+
+        // the root is counted as well, so it outlives the re-opened one
+        assert_eq!(open(&mut fs, ".").unwrap(), 0);
+        fs.fclose(0).unwrap();
+        assert!(fs.get_dir(0).is_ok());
+
+        // every opened file has its own inode
+        let file1 = open(&mut fs, "a.txt").unwrap();
+        let file2 = open(&mut fs, "a.txt").unwrap();
+        assert_ne!(file1, file2);
+        fs.fclose(file1).unwrap();
+        let mut buf = [0u8; 1];
+        let n = fs
+            .get_mut_file(file2)
+            .unwrap()
+            .fd_read(&mut [std::io::IoSliceMut::new(&mut buf)])
+            .unwrap();
+        assert_eq!(&buf[..n], b"a");
+
+        // the re-opened root gets a new fd, which can be closed unlike the preopened one
+        let mut vfs = crate::snapshots::env::VFS::new();
+        vfs.mount_file_sys("/", Box::new(fs)).unwrap();
+        let fd = vfs
+            .path_open(
+                3,
+                ".",
+                OFlags::empty(),
+                WASIRights::fd_all(),
+                WASIRights::fd_all(),
+                FdFlags::empty(),
+            )
+            .unwrap();
+        assert_ne!(fd, 3);
+        vfs.fd_close(fd).unwrap();
+        assert_eq!(vfs.fd_close(3), Err(Errno::__WASI_ERRNO_NOTSUP));
+    }
+
+    #[test]
+    fn test_virtual_path_symlink_escape() {
+        let mut fs = WasiVirtualSys::<MemoryDir, MemoryFile>::new();
+        for target in ["..", "../a", "a/../..", "/a"] {
+            assert_eq!(
+                fs.path_symlink(target, 0, "esc"),
+                Err(Errno::__WASI_ERRNO_PERM),
+                "{target}"
+            );
+        }
+        assert!(fs.find_inode_index(0, &"esc").is_err());
+        fs.path_symlink("a", 0, "link").unwrap();
+        assert_eq!(fs.path_readlink(0, "link").unwrap(), "a");
+    }
+}
@@ -1,8 +1,11 @@
 use std::fmt::Debug;
 
-use self::vfs::{virtual_sys::StdioSys, WasiDir, WasiFile, WasiFileSys, WasiNode};
+use self::vfs::{virtual_sys::StdioSys, FdStat, WasiDir, WasiFile, WasiFileSys, WasiNode};
 
-pub use super::common::{error::Errno, types as wasi_types, vfs};
+pub use super::common::{
+    error::{Errno, WasiMountError},
+    types as wasi_types, vfs,
+};
 
 #[cfg(all(unix, feature = "async_tokio"))]
 pub use super::common::net::async_tokio::AsyncWasiSocket;
@@ -34,7 +37,8 @@ pub trait AsyncVM: Send + Sync {
 
 pub struct VFS {
     vfs: slab::Slab<Box<dyn WasiFileSys<Index = usize> + Send + Sync>>,
-    preopens: Vec<(String, usize)>,
+    // the guest path, the device and the file descriptor of each preopened directory
+    preopens: Vec<(String, usize, usize)>,
     fds: slab::Slab<VFD>,
 }
 
@@ -88,17 +92,46 @@ impl VFS {
         }
     }
 
+    /// Mounts the file system at the given guest path as a preopened directory.
+    ///
+    /// Returns [WasiMountError::AlreadyMounted] if the guest path already has a mounted file system. Use [force_mount_file_sys](VFS::force_mount_file_sys) to replace it instead.
     pub fn mount_file_sys(
         &mut self,
         path: &str,
         file_sys: Box<dyn WasiFileSys<Index = usize> + Send + Sync>,
+    ) -> Result<(), WasiMountError> {
+        if self.preopens.iter().any(|(p, _, _)| p == path) {
+            return Err(WasiMountError::AlreadyMounted(path.to_string()));
+        }
+        self.force_mount_file_sys(path, file_sys);
+        Ok(())
+    }
+
+    /// Mounts the file system at the given guest path as a preopened directory. If the guest path already has a mounted file system, then the preopened directory is switched to the new one, while the file descriptors already opened from the old one keep working.
+    pub fn force_mount_file_sys(
+        &mut self,
+        path: &str,
+        file_sys: Box<dyn WasiFileSys<Index = usize> + Send + Sync>,
     ) {
         let vfs_id = self.vfs.insert(file_sys);
-        self.preopens.push((path.to_string(), vfs_id));
-        self.fds.insert(VFD::Inode {
-            dev: vfs_id,
-            ino: 0,
-        });
+        match self.preopens.iter_mut().find(|(p, _, _)| p == path) {
+            Some((_, dev, fd)) => {
+                *dev = vfs_id;
+                if let Some(vfd) = self.fds.get_mut(*fd) {
+                    *vfd = VFD::Inode {
+                        dev: vfs_id,
+                        ino: 0,
+                    };
+                }
+            }
+            None => {
+                let fd = self.fds.insert(VFD::Inode {
+                    dev: vfs_id,
+                    ino: 0,
+                });
+                self.preopens.push((path.to_string(), vfs_id, fd));
+            }
+        }
     }
 }
 
@@ -125,11 +158,7 @@ impl VFS {
         )?;
         log::trace!("path_open {dirfd} {path} fd=({dev},{ino})");
 
-        if ino != 0 {
-            Ok(self.fds.insert(VFD::Inode { dev, ino }))
-        } else {
-            Ok(dirfd)
-        }
+        Ok(self.fds.insert(VFD::Inode { dev, ino }))
     }
 
     pub fn path_rename(
@@ -186,13 +215,10 @@ impl VFS {
 
     pub fn fd_preopen_get(&mut self, fd: usize) -> Result<String, Errno> {
         log::trace!("fd_preopen_get({fd})");
-        if fd < 3 {
-            return Err(Errno::__WASI_ERRNO_BADF);
-        }
-        let fd = fd - 3;
         Ok(self
             .preopens
-            .get(fd)
+            .iter()
+            .find(|(_, _, preopen_fd)| *preopen_fd == fd)
             .ok_or(Errno::__WASI_ERRNO_BADF)?
             .0
             .clone())
@@ -215,12 +241,23 @@ impl VFS {
     pub fn fd_close(&mut self, fd: usize) -> Result<(), Errno> {
         match self.fds.get(fd) {
             Some(VFD::Inode { dev, ino }) => {
-                if *ino == 0 {
+                // the stdin and the preopened directories cannot be closed, while the root directories re-opened by `path_open` can
+                if fd == 0
+                    || self
+                        .preopens
+                        .iter()
+                        .any(|(_, _, preopen_fd)| *preopen_fd == fd)
+                {
                     return Err(Errno::__WASI_ERRNO_NOTSUP);
                 }
-                if let Some(vfs) = self.vfs.get_mut(*dev) {
-                    log::trace!("fclose fd=({},{})", *dev, *ino);
+                let dev = *dev;
+                if let Some(vfs) = self.vfs.get_mut(dev) {
+                    log::trace!("fclose fd=({},{})", dev, *ino);
                     vfs.fclose(*ino)?;
+                    if vfs.is_all_closed() {
+                        log::trace!("fclose drop dev={dev}");
+                        self.vfs.remove(dev);
+                    }
                 }
                 self.fds.remove(fd);
             }
@@ -265,6 +302,37 @@ impl VFS {
         vfs.path_unlink_file(ino, path)
     }
 
+    pub fn path_symlink(
+        &mut self,
+        old_path: &str,
+        dir_fd: usize,
+        new_path: &str,
+    ) -> Result<(), Errno> {
+        let (dev, ino) = self.get_inode_index(dir_fd)?;
+        let vfs = self.vfs.get_mut(dev).ok_or(Errno::__WASI_ERRNO_BADF)?;
+        vfs.path_symlink(old_path, ino, new_path)
+    }
+
+    pub fn path_filestat_set_times(
+        &mut self,
+        dir_fd: usize,
+        path: &str,
+        follow_symlinks: bool,
+        atim: wasi_types::__wasi_timestamp_t,
+        mtim: wasi_types::__wasi_timestamp_t,
+        fst_flags: wasi_types::__wasi_fstflags_t::Type,
+    ) -> Result<(), Errno> {
+        let (dev, ino) = self.get_inode_index(dir_fd)?;
+        let vfs = self.vfs.get_mut(dev).ok_or(Errno::__WASI_ERRNO_BADF)?;
+        vfs.path_filestat_set_times(ino, path, follow_symlinks, atim, mtim, fst_flags)
+    }
+
+    pub fn path_readlink(&self, dir_fd: usize, path: &str) -> Result<String, Errno> {
+        let (dev, ino) = self.get_inode_index(dir_fd)?;
+        let vfs = self.vfs.get(dev).ok_or(Errno::__WASI_ERRNO_BADF)?;
+        vfs.path_readlink(ino, path)
+    }
+
     fn get_inode_index(&self, fd: usize) -> Result<(usize, usize), Errno> {
         if let VFD::Inode { dev, ino } = self.fds.get(fd).ok_or(Errno::__WASI_ERRNO_BADF)? {
             Ok((*dev, *ino))
@@ -348,6 +416,81 @@ impl VFS {
         vfs.get_dir(ino)
     }
 
+    /// Returns the number of the open file descriptors, except the stdio ones (`0`, `1` and `2`).
+    pub fn open_fd_count(&self) -> usize {
+        self.fds.iter().filter(|(fd, _)| *fd > 2).count()
+    }
+
+    /// Returns the open file descriptors, except the stdio ones (`0`, `1` and `2`), and their stats.
+    pub fn fd_list(&self) -> Vec<(usize, FdStat)> {
+        self.fds
+            .iter()
+            .filter(|(fd, _)| *fd > 2)
+            .filter_map(|(fd, vfd)| {
+                let fd_stat = match vfd {
+                    VFD::Inode { dev, ino } => {
+                        self.vfs.get(*dev)?.get_inode(*ino).ok()?.fd_fdstat_get()
+                    }
+                    #[cfg(all(unix, feature = "async_tokio"))]
+                    VFD::AsyncSocket(s) => s.fd_fdstat_get(),
+                };
+                Some((fd, fd_stat.ok()?))
+            })
+            .collect()
+    }
+
+    /// Creates a non-blocking anonymous pipe and returns the file descriptors of its read end and write end.
+    #[cfg(feature = "async_tokio")]
+    pub fn create_nonblocking_pipe(&mut self) -> Result<(usize, usize), Errno> {
+        self.insert_pipe(vfs::virtual_sys::PipeSys::new())
+    }
+
+    /// Creates an anonymous pipe without fd flags and returns the file descriptors of its read end and write end.
+    #[cfg(feature = "async_tokio")]
+    pub fn create_pipe(&mut self) -> Result<(usize, usize), Errno> {
+        self.insert_pipe(vfs::virtual_sys::PipeSys::with_flags(vfs::FdFlags::empty()))
+    }
+
+    /// Inserts a write-only file backed by the given async writer and returns its file descriptor. The async `fd_write` on the file descriptor waits until the writer is ready, instead of blocking the thread.
+    ///
+    /// # Argument
+    ///
+    /// * `writer` - The async writer, such as the write half of a [tokio::net::UnixStream].
+    #[cfg(feature = "async_tokio")]
+    pub fn insert_async_writer<W>(&mut self, writer: W) -> usize
+    where
+        W: tokio::io::AsyncWrite + Unpin + Send + Sync + 'static,
+    {
+        use self::vfs::virtual_sys::AsyncOutPipelineSys;
+
+        let dev = self.vfs.insert(Box::new(AsyncOutPipelineSys::new(writer)));
+        let fd = self.fds.insert(VFD::Inode {
+            dev,
+            ino: AsyncOutPipelineSys::<W>::INO,
+        });
+        log::trace!("insert_async_writer fd={fd} dev={dev}");
+
+        fd
+    }
+
+    #[cfg(feature = "async_tokio")]
+    fn insert_pipe(&mut self, pipe: vfs::virtual_sys::PipeSys) -> Result<(usize, usize), Errno> {
+        use self::vfs::virtual_sys::PipeSys;
+
+        let dev = self.vfs.insert(Box::new(pipe));
+        let read_fd = self.fds.insert(VFD::Inode {
+            dev,
+            ino: PipeSys::READ_END,
+        });
+        let write_fd = self.fds.insert(VFD::Inode {
+            dev,
+            ino: PipeSys::WRITE_END,
+        });
+        log::trace!("insert_pipe fds=({read_fd},{write_fd}) dev={dev}");
+
+        Ok((read_fd, write_fd))
+    }
+
     #[cfg(all(unix, feature = "async_tokio"))]
     pub fn get_mut_socket(&mut self, fd: usize) -> Result<&mut AsyncWasiSocket, Errno> {
         if let VFD::AsyncSocket(s) = self.fds.get_mut(fd).ok_or(Errno::__WASI_ERRNO_BADF)? {
@@ -369,3 +512,124 @@ impl VFS {
         Ok(self.fds.insert(VFD::AsyncSocket(s)))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_close_reopened_root_in_freed_fd() {
+        use vfs::{virtual_sys::DiskFileSys, FdFlags, OFlags, WASIRights};
+
+        let tmp = tempfile::tempdir().unwrap();
+        let mut vfs = VFS::new();
+        vfs.mount_file_sys(
+            ".",
+            Box::new(DiskFileSys::new(tmp.path().to_path_buf()).unwrap()),
+        )
+        .unwrap();
+        assert_eq!(vfs.fd_preopen_get(3).unwrap(), ".");
+
+        // the root re-opened in the fd freed by stdout can be closed, unlike the preopened one
+        vfs.fd_close(1).unwrap();
+        let fd = vfs
+            .path_open(
+                3,
+                ".",
+                OFlags::empty(),
+                WASIRights::fd_all(),
+                WASIRights::fd_all(),
+                FdFlags::empty(),
+            )
+            .unwrap();
+        assert_eq!(fd, 1);
+        assert_eq!(vfs.fd_preopen_get(fd), Err(Errno::__WASI_ERRNO_BADF));
+        vfs.fd_close(fd).unwrap();
+        assert_eq!(vfs.fd_close(3), Err(Errno::__WASI_ERRNO_NOTSUP));
+        assert_eq!(vfs.fd_close(0), Err(Errno::__WASI_ERRNO_NOTSUP));
+    }
+
+    #[cfg(feature = "async_tokio")]
+    #[test]
+    fn test_pipe_bounded_and_dropped() {
+        use vfs::virtual_sys::PipeSys;
+
+        let mut vfs = VFS::new();
+        let devs = vfs.vfs.len();
+        let (read_fd, write_fd) = vfs.create_nonblocking_pipe().unwrap();
+        assert_eq!(vfs.vfs.len(), devs + 1);
+
+        // the write to a full pipe is partial, and then fails with EAGAIN
+        let data = vec![1u8; PipeSys::CAPACITY + 1];
+        let writer = vfs.get_mut_file(write_fd).unwrap();
+        assert_eq!(
+            writer.fd_write(&[std::io::IoSlice::new(&data)]),
+            Ok(PipeSys::CAPACITY)
+        );
+        assert_eq!(
+            writer.fd_write(&[std::io::IoSlice::new(&data)]),
+            Err(Errno::__WASI_ERRNO_AGAIN)
+        );
+
+        // the pipe is dropped once both ends are closed
+        vfs.fd_close(write_fd).unwrap();
+        assert_eq!(vfs.vfs.len(), devs + 1);
+        vfs.fd_close(read_fd).unwrap();
+        assert_eq!(vfs.vfs.len(), devs);
+    }
+
+    #[cfg(feature = "async_tokio")]
+    #[tokio::test]
+    async fn test_pipe_readiness() {
+        use std::time::Duration;
+        use vfs::{virtual_sys::PipeSys, FdFlags};
+
+        let mut vfs = VFS::new();
+        let (read_fd, write_fd) = vfs.create_pipe().unwrap();
+        let reader = vfs.get_file(read_fd).unwrap();
+        assert!(!reader
+            .fd_fdstat_get()
+            .unwrap()
+            .flags
+            .contains(FdFlags::NONBLOCK));
+        let read_ready = reader.pipe_readiness().unwrap();
+        let write_ready = vfs.get_file(write_fd).unwrap().pipe_readiness().unwrap();
+
+        // an empty pipe is writable but not readable
+        assert_eq!(write_ready.writable().await, (PipeSys::CAPACITY, false));
+        let pending = tokio::time::timeout(Duration::from_millis(10), read_ready.readable());
+        assert!(pending.await.is_err());
+
+        // a waiting reader is woken by a write
+        let waiting = tokio::spawn({
+            let read_ready = read_ready.clone();
+            async move { read_ready.readable().await }
+        });
+        tokio::task::yield_now().await;
+        let writer = vfs.get_mut_file(write_fd).unwrap();
+        assert_eq!(writer.fd_write(&[std::io::IoSlice::new(b"abc")]), Ok(3));
+        assert_eq!(waiting.await.unwrap(), (3, false));
+
+        // a full pipe is not writable until it is read
+        let data = vec![1u8; PipeSys::CAPACITY];
+        let writer = vfs.get_mut_file(write_fd).unwrap();
+        assert_eq!(
+            writer.fd_write(&[std::io::IoSlice::new(&data)]),
+            Ok(PipeSys::CAPACITY - 3)
+        );
+        let pending = tokio::time::timeout(Duration::from_millis(10), write_ready.writable());
+        assert!(pending.await.is_err());
+        let mut buf = [0u8; 8];
+        let reader = vfs.get_mut_file(read_fd).unwrap();
+        assert_eq!(
+            reader.fd_read(&mut [std::io::IoSliceMut::new(&mut buf)]),
+            Ok(8)
+        );
+        assert_eq!(&buf[..3], b"abc");
+        assert_eq!(write_ready.writable().await, (8, false));
+
+        // closing the write end hangs up the read end
+        vfs.fd_close(write_fd).unwrap();
+        assert_eq!(read_ready.readable().await, (PipeSys::CAPACITY - 8, true));
+    }
+}
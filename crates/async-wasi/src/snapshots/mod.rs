@@ -2,9 +2,17 @@ pub mod common;
 pub mod env;
 pub mod preview_1;
 
-use common::error::Errno;
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc,
+};
 
-use self::env::{vfs::WasiFileSys, VFS};
+use common::error::{Errno, WasiMountError};
+
+use self::env::{
+    vfs::{FdStat, WasiFileSys},
+    VFS,
+};
 
 #[derive(Debug)]
 pub struct WasiCtx {
@@ -12,6 +20,9 @@ pub struct WasiCtx {
     envs: Vec<String>,
     pub(crate) vfs: VFS,
     pub exit_code: u32,
+    timezone_offset_seconds: i32,
+    allow_privileged_ports: bool,
+    host_cpu_ns: CpuTimeCounter,
 }
 impl Default for WasiCtx {
     fn default() -> Self {
@@ -25,6 +36,9 @@ impl WasiCtx {
             envs: vec![],
             vfs: VFS::new(),
             exit_code: 0,
+            timezone_offset_seconds: 0,
+            allow_privileged_ports: false,
+            host_cpu_ns: CpuTimeCounter::default(),
         }
     }
 
@@ -34,17 +48,30 @@ impl WasiCtx {
             envs: vec![],
             vfs,
             exit_code: 0,
+            timezone_offset_seconds: 0,
+            allow_privileged_ports: false,
+            host_cpu_ns: CpuTimeCounter::default(),
         }
     }
 
+    /// Mounts the file system at the given guest path. Returns [WasiMountError::AlreadyMounted] if the guest path is already mounted.
     pub fn mount_file_sys(
         &mut self,
         guest_path: &str,
         file_sys: Box<dyn WasiFileSys<Index = usize> + Send + Sync>,
-    ) {
+    ) -> Result<(), WasiMountError> {
         self.vfs.mount_file_sys(guest_path, file_sys)
     }
 
+    /// Mounts the file system at the given guest path, replacing the one already mounted there if any.
+    pub fn force_mount_file_sys(
+        &mut self,
+        guest_path: &str,
+        file_sys: Box<dyn WasiFileSys<Index = usize> + Send + Sync>,
+    ) {
+        self.vfs.force_mount_file_sys(guest_path, file_sys)
+    }
+
     pub fn push_arg(&mut self, arg: String) {
         self.args.push(arg);
     }
@@ -61,7 +88,92 @@ impl WasiCtx {
     pub fn push_envs(&mut self, envs: Vec<String>) {
         self.envs.extend(envs);
     }
+
+    /// Sets the offset of the host timezone from UTC in seconds, which is positive for the timezones east of UTC. The offset is added to the timestamps of `CLOCK_REALTIME` returned by `clock_time_get`.
+    pub fn set_timezone_offset_seconds(&mut self, offset_seconds: i32) {
+        self.timezone_offset_seconds = offset_seconds;
+    }
+
+    /// Returns the offset of the host timezone from UTC in seconds.
+    pub fn timezone_offset_seconds(&self) -> i32 {
+        self.timezone_offset_seconds
+    }
+
+    /// Sets if the guest is allowed to bind sockets to the privileged ports (1-1023). By default, the binding is denied with `ACCES`.
+    pub fn set_allow_privileged_ports(&mut self, allow: bool) {
+        self.allow_privileged_ports = allow;
+    }
+
+    /// Returns if the guest is allowed to bind sockets to the privileged ports (1-1023).
+    pub fn allow_privileged_ports(&self) -> bool {
+        self.allow_privileged_ports
+    }
+
+    /// Returns the approximate CPU time in nanoseconds consumed by the WASI host functions, which is recorded by the host function wrappers. For the asynchronous host functions, only the time spent in polling them is included, but not the time they spend waiting for I/O.
+    pub fn consumed_cpu_ns(&self) -> u64 {
+        self.host_cpu_ns.get()
+    }
+
+    /// Adds the CPU time consumed by a WASI host function call, which is called by the host function wrappers.
+    pub fn add_consumed_cpu_ns(&mut self, ns: u64) {
+        self.host_cpu_ns.add(ns);
+    }
+
+    /// Returns a handle to the counter of the CPU time consumed by the WASI host functions, which is used to record the time spent in polling an asynchronous host function while the function borrows this context.
+    pub fn cpu_time_counter(&self) -> CpuTimeCounter {
+        self.host_cpu_ns.clone()
+    }
+
+    /// Returns the number of the open file descriptors, except the stdio ones, which is useful to detect file descriptor leaks.
+    pub fn open_fd_count(&self) -> usize {
+        self.vfs.open_fd_count()
+    }
+
+    /// Returns a snapshot of the open file descriptors, except the stdio ones, and their stats.
+    pub fn fd_list(&self) -> Vec<(usize, FdStat)> {
+        self.vfs.fd_list()
+    }
+}
+
+/// A shared counter of the CPU time consumed by the WASI host functions, see [WasiCtx::cpu_time_counter].
+#[derive(Debug, Clone, Default)]
+pub struct CpuTimeCounter(Arc<AtomicU64>);
+impl CpuTimeCounter {
+    /// Adds the CPU time in nanoseconds to the counter.
+    pub fn add(&self, ns: u64) {
+        let _ = self
+            .0
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |total| {
+                Some(total.saturating_add(ns))
+            });
+    }
+
+    /// Returns the total CPU time in nanoseconds.
+    pub fn get(&self) -> u64 {
+        self.0.load(Ordering::Relaxed)
+    }
 }
 
 // unsafe impl Send for WasiCtx {}
 // unsafe impl Sync for WasiCtx {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_consumed_cpu_ns() {
+        let mut ctx = WasiCtx::new();
+        assert_eq!(ctx.consumed_cpu_ns(), 0);
+
+        ctx.add_consumed_cpu_ns(5);
+        // the time recorded via the counter is added to the same total
+        let counter = ctx.cpu_time_counter();
+        counter.add(7);
+        assert_eq!(ctx.consumed_cpu_ns(), 12);
+        assert_eq!(counter.get(), 12);
+
+        ctx.add_consumed_cpu_ns(u64::MAX);
+        assert_eq!(ctx.consumed_cpu_ns(), u64::MAX);
+    }
+}
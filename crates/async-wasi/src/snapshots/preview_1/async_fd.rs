@@ -0,0 +1,85 @@
+use crate::snapshots::{
+    common::{
+        memory::{Memory, WasmPtr},
+        types::*,
+        vfs::{virtual_sys::PipeReadiness, FdFlags, WasiFile},
+    },
+    Errno, WasiCtx,
+};
+use tokio::io::AsyncWriteExt;
+
+/// Reads from a file descriptor like [fd_read](super::fd_read), but waits for data if the file is the read end of a [pipe](crate::snapshots::common::vfs::virtual_sys::PipeSys) without [FdFlags::NONBLOCK].
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, mem), err(Debug))
+)]
+pub async fn fd_read<M: Memory>(
+    ctx: &mut WasiCtx,
+    mem: &mut M,
+    fd: __wasi_fd_t,
+    iovs: WasmPtr<__wasi_iovec_t>,
+    iovs_len: __wasi_size_t,
+    nread: WasmPtr<__wasi_size_t>,
+) -> Result<(), Errno> {
+    log::trace!("fd_read {fd}");
+
+    loop {
+        let fs = ctx.vfs.get_mut_file(fd as usize)?;
+        let readiness = blocking_pipe(fs);
+        let mut bufs = mem.mut_iovec(iovs, iovs_len)?;
+        let result = fs.fd_read(&mut bufs);
+        drop(bufs);
+        match (result, readiness) {
+            (Err(Errno::__WASI_ERRNO_AGAIN), Some(readiness)) => {
+                readiness.readable().await;
+            }
+            (result, _) => {
+                let n = result? as __wasi_size_t;
+                return mem.write_data(nread, n.to_le());
+            }
+        }
+    }
+}
+
+/// Writes to a file descriptor like [fd_write](super::fd_write), but awaits the file's [async writer](WasiFile::async_writer) if it has one, so that a full pipe yields instead of blocking. A write to a full [pipe](crate::snapshots::common::vfs::virtual_sys::PipeSys) without [FdFlags::NONBLOCK] waits for room.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, mem), err(Debug))
+)]
+pub async fn fd_write<M: Memory>(
+    ctx: &mut WasiCtx,
+    mem: &mut M,
+    fd: __wasi_fd_t,
+    iovs: WasmPtr<__wasi_ciovec_t>,
+    iovs_len: __wasi_size_t,
+    nwritten: WasmPtr<__wasi_size_t>,
+) -> Result<(), Errno> {
+    log::trace!("fd_write {fd}");
+
+    loop {
+        let fs = ctx.vfs.get_mut_file(fd as usize)?;
+        let readiness = blocking_pipe(fs);
+        let bufs = mem.get_iovec(iovs, iovs_len)?;
+        let result = match fs.async_writer() {
+            Some(writer) => writer.write_vectored(&bufs).await.map_err(Errno::from),
+            None => fs.fd_write(&bufs),
+        };
+        drop(bufs);
+        match (result, readiness) {
+            (Err(Errno::__WASI_ERRNO_AGAIN), Some(readiness)) => {
+                readiness.writable().await;
+            }
+            (result, _) => {
+                let n = result? as __wasi_size_t;
+                return mem.write_data(nwritten, n.to_le());
+            }
+        }
+    }
+}
+
+// the readiness of a pipe end without the NONBLOCK flag, on which the calls wait instead of returning EAGAIN
+fn blocking_pipe(fs: &dyn WasiFile) -> Option<PipeReadiness> {
+    let readiness = fs.pipe_readiness()?;
+    let flags = fs.fd_fdstat_get().ok()?.flags;
+    (!flags.contains(FdFlags::NONBLOCK)).then_some(readiness)
+}
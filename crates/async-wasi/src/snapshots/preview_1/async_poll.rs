@@ -3,10 +3,11 @@ use crate::snapshots::{
         memory::{Memory, WasmPtr},
         net::{self, ConnectState, SubscriptionClock},
         types::*,
+        vfs::virtual_sys::PipeReadiness,
     },
     Errno, WasiCtx,
 };
-use futures::{stream::FuturesUnordered, StreamExt};
+use futures::{future::Either, stream::FuturesUnordered, StreamExt};
 use net::{async_tokio::AsyncWasiSocket, PrePoll, SubscriptionFd, SubscriptionFdType};
 use std::time::Duration;
 
@@ -120,6 +121,49 @@ async fn wait_fd(
     }
 }
 
+fn pipe_readiness(ctx: &WasiCtx, fd: __wasi_fd_t) -> Option<PipeReadiness> {
+    ctx.vfs.get_file(fd as usize).ok()?.pipe_readiness()
+}
+
+async fn wait_pipe(
+    readiness: PipeReadiness,
+    type_: SubscriptionFdType,
+) -> Result<(__wasi_event_t, Option<usize>), Errno> {
+    let event = |userdata, type_, (nbytes, closed): (usize, bool)| __wasi_event_t {
+        userdata,
+        error: 0,
+        type_,
+        fd_readwrite: __wasi_event_fd_readwrite_t {
+            nbytes: nbytes as __wasi_filesize_t,
+            flags: if closed {
+                __wasi_eventrwflags_t::__WASI_EVENTRWFLAGS_FD_READWRITE_HANGUP
+            } else {
+                0
+            },
+        },
+    };
+
+    let event = match type_ {
+        SubscriptionFdType::Read(userdata) => event(
+            userdata,
+            __wasi_eventtype_t::__WASI_EVENTTYPE_FD_READ,
+            readiness.readable().await,
+        ),
+        SubscriptionFdType::Write(userdata) => event(
+            userdata,
+            __wasi_eventtype_t::__WASI_EVENTTYPE_FD_WRITE,
+            readiness.writable().await,
+        ),
+        SubscriptionFdType::Both { read, write } => {
+            tokio::select! {
+                r = readiness.readable() => event(read, __wasi_eventtype_t::__WASI_EVENTTYPE_FD_READ, r),
+                w = readiness.writable() => event(write, __wasi_eventtype_t::__WASI_EVENTTYPE_FD_WRITE, w),
+            }
+        }
+    };
+    Ok((event, None))
+}
+
 async fn poll_only_fd<M: Memory>(
     ctx: &mut WasiCtx,
     mem: &mut M,
@@ -137,12 +181,17 @@ async fn poll_only_fd<M: Memory>(
         for SubscriptionFd { fd, type_ } in fd_vec {
             match ctx.vfs.get_socket(fd as usize) {
                 Ok(s) => {
-                    wait.push(wait_fd(fd as usize, s, type_));
-                }
-                Err(e) => {
-                    r_events[i] = handle_event_err(type_, e);
-                    i += 1;
+                    wait.push(Either::Left(wait_fd(fd as usize, s, type_)));
                 }
+                Err(e) => match pipe_readiness(ctx, fd) {
+                    Some(readiness) => {
+                        wait.push(Either::Right(wait_pipe(readiness, type_)));
+                    }
+                    None => {
+                        r_events[i] = handle_event_err(type_, e);
+                        i += 1;
+                    }
+                },
             }
         }
 
@@ -207,12 +256,17 @@ async fn poll_fd_timeout<M: Memory>(
     for SubscriptionFd { fd, type_ } in fd_vec {
         match ctx.vfs.get_socket(fd as usize) {
             Ok(s) => {
-                wait.push(wait_fd(fd as usize, s, type_));
-            }
-            Err(e) => {
-                r_events[i] = handle_event_err(type_, e);
-                i += 1;
+                wait.push(Either::Left(wait_fd(fd as usize, s, type_)));
             }
+            Err(e) => match pipe_readiness(ctx, fd) {
+                Some(readiness) => {
+                    wait.push(Either::Right(wait_pipe(readiness, type_)));
+                }
+                None => {
+                    r_events[i] = handle_event_err(type_, e);
+                    i += 1;
+                }
+            },
         }
     }
 
@@ -270,6 +324,10 @@ async fn poll_fd_timeout<M: Memory>(
     Ok(())
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, mem), err(Debug))
+)]
 pub async fn poll_oneoff<M: Memory>(
     ctx: &mut WasiCtx,
     mem: &mut M,
@@ -6,7 +6,7 @@ use crate::snapshots::{
     },
     Errno, WasiCtx,
 };
-use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV6};
 
 cfg_if::cfg_if! {
     if #[cfg(any(
@@ -44,6 +44,10 @@ fn parse_wasi_ip<M: Memory>(mem: &M, addr_ptr: WasmPtr<__wasi_address_t>) -> Res
     Ok(addr)
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, mem), err(Debug))
+)]
 pub fn sock_open<M: Memory>(
     ctx: &mut WasiCtx,
     mem: &mut M,
@@ -81,6 +85,10 @@ pub fn sock_open<M: Memory>(
     Ok(())
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, mem), err(Debug))
+)]
 pub fn sock_bind<M: Memory>(
     ctx: &mut WasiCtx,
     mem: &M,
@@ -90,6 +98,11 @@ pub fn sock_bind<M: Memory>(
 ) -> Result<(), Errno> {
     log::trace!("sock_bind {fd}");
 
+    // binding to the privileged ports requires the permission of the embedder
+    if (1..1024).contains(&port) && !ctx.allow_privileged_ports() {
+        return Err(Errno::__WASI_ERRNO_ACCES);
+    }
+
     let ip = parse_wasi_ip(mem, addr_ptr)?;
     let addr = SocketAddr::new(ip, port as u16);
 
@@ -98,6 +111,10 @@ pub fn sock_bind<M: Memory>(
     Ok(())
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, _mem), err(Debug))
+)]
 pub fn sock_listen<M: Memory>(
     ctx: &mut WasiCtx,
     _mem: &mut M,
@@ -111,6 +128,10 @@ pub fn sock_listen<M: Memory>(
     Ok(())
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, mem), err(Debug))
+)]
 pub async fn sock_accept<M: Memory>(
     ctx: &mut WasiCtx,
     mem: &mut M,
@@ -126,6 +147,10 @@ pub async fn sock_accept<M: Memory>(
     Ok(())
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, mem), err(Debug))
+)]
 pub async fn sock_connect<M: Memory>(
     ctx: &mut WasiCtx,
     mem: &M,
@@ -142,6 +167,10 @@ pub async fn sock_connect<M: Memory>(
     Ok(())
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, mem), err(Debug))
+)]
 pub async fn sock_recv<M: Memory>(
     ctx: &mut WasiCtx,
     mem: &mut M,
@@ -178,6 +207,10 @@ pub async fn sock_recv<M: Memory>(
     Ok(())
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, mem), err(Debug))
+)]
 pub async fn sock_recv_from<M: Memory>(
     ctx: &mut WasiCtx,
     mem: &mut M,
@@ -211,6 +244,14 @@ pub async fn sock_recv_from<M: Memory>(
 
     let (n, trunc, addr) = s.recv_from(&mut iovec, native_flags).await?;
 
+    // the source address follows the address family of the socket, so an IPv6 socket always returns 16-byte addresses
+    let addr = match (s.get_so_type().0, addr) {
+        (AddressFamily::Inet6, Some(SocketAddr::V4(addrv4))) => Some(SocketAddr::V6(
+            SocketAddrV6::new(addrv4.ip().to_ipv6_mapped(), addrv4.port(), 0, 0),
+        )),
+        (_, addr) => addr,
+    };
+
     match addr {
         Some(SocketAddr::V4(addrv4)) => {
             let family_ptr = WasmPtr::<u16>::from(wasi_addr.buf as usize);
@@ -251,6 +292,10 @@ pub async fn sock_recv_from<M: Memory>(
     Ok(())
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, mem), err(Debug))
+)]
 pub async fn sock_send<M: Memory>(
     ctx: &mut WasiCtx,
     mem: &mut M,
@@ -270,6 +315,10 @@ pub async fn sock_send<M: Memory>(
     Ok(())
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, mem), err(Debug))
+)]
 pub async fn sock_send_to<M: Memory>(
     ctx: &mut WasiCtx,
     mem: &mut M,
@@ -295,6 +344,10 @@ pub async fn sock_send_to<M: Memory>(
     Ok(())
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, _mem), err(Debug))
+)]
 pub fn sock_shutdown<M: Memory>(
     ctx: &mut WasiCtx,
     _mem: &mut M,
@@ -321,6 +374,10 @@ pub fn sock_shutdown<M: Memory>(
     Ok(())
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, mem), err(Debug))
+)]
 pub fn sock_getpeeraddr<M: Memory>(
     ctx: &mut WasiCtx,
     mem: &mut M,
@@ -360,6 +417,10 @@ pub fn sock_getpeeraddr<M: Memory>(
     Ok(())
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, mem), err(Debug))
+)]
 pub fn sock_getlocaladdr<M: Memory>(
     ctx: &mut WasiCtx,
     mem: &mut M,
@@ -400,6 +461,57 @@ pub fn sock_getlocaladdr<M: Memory>(
     Ok(())
 }
 
+/// Writes the local address bound to the socket into the buffer of `wasi_addr_ptr` in the layout of `sockaddr`: the address family in `u16`, the port in `u16` of the network byte order, and then the IP address. The number of the bytes written is stored into `addr_len_ptr`.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, mem), err(Debug))
+)]
+pub fn sock_getsockname<M: Memory>(
+    ctx: &mut WasiCtx,
+    mem: &mut M,
+    fd: __wasi_fd_t,
+    wasi_addr_ptr: WasmPtr<__wasi_address_t>,
+    addr_len_ptr: WasmPtr<__wasi_size_t>,
+) -> Result<(), Errno> {
+    log::trace!("sock_getsockname {fd}");
+
+    let s = ctx.vfs.get_mut_socket(fd as usize)?;
+
+    let wasi_addr = *(mem.mut_data(wasi_addr_ptr)?);
+
+    let addr = s.get_local()?;
+
+    let (family, ip) = match addr {
+        SocketAddr::V4(addrv4) => (
+            __wasi_address_family_t::__WASI_ADDRESS_FAMILY_INET4,
+            addrv4.ip().octets().to_vec(),
+        ),
+        SocketAddr::V6(addrv6) => (
+            __wasi_address_family_t::__WASI_ADDRESS_FAMILY_INET6,
+            addrv6.ip().octets().to_vec(),
+        ),
+    };
+
+    let addr_len = 4 + ip.len();
+    if (wasi_addr.buf_len as usize) < addr_len {
+        return Err(Errno::__WASI_ERRNO_INVAL);
+    }
+
+    let wasi_addr_buf_ptr = WasmPtr::<u8>::from(wasi_addr.buf as usize);
+    let wasi_addr_buf = mem.mut_slice(wasi_addr_buf_ptr, addr_len)?;
+    wasi_addr_buf[0..2].copy_from_slice(&(family as u16).to_le_bytes());
+    wasi_addr_buf[2..4].copy_from_slice(&addr.port().to_be_bytes());
+    wasi_addr_buf[4..].copy_from_slice(&ip);
+
+    mem.write_data(addr_len_ptr, (addr_len as __wasi_size_t).to_le())?;
+
+    Ok(())
+}
+
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, mem), err(Debug))
+)]
 pub fn sock_getsockopt<M: Memory>(
     ctx: &mut WasiCtx,
     mem: &mut M,
@@ -467,7 +579,26 @@ pub fn sock_getsockopt<M: Memory>(
             return Err(Errno::__WASI_ERRNO_NOSYS);
         }
         __wasi_sock_opt_so_t::__WASI_SOCK_OPT_SO_LINGER => {
-            return Err(Errno::__WASI_ERRNO_NOSYS);
+            if (flag_size as usize) != std::mem::size_of::<__wasi_linger>() {
+                return Err(Errno::__WASI_ERRNO_INVAL);
+            }
+
+            let linger = if let Some(linger) = s.get_so_linger() {
+                __wasi_linger {
+                    l_onoff: 1_i32.to_le(),
+                    l_linger: (linger.as_secs() as i32).to_le(),
+                }
+            } else {
+                __wasi_linger {
+                    l_onoff: 0,
+                    l_linger: 0,
+                }
+            };
+
+            let offset = WasmPtr::<__wasi_linger>::from(flag.0);
+            mem.write_data(offset, linger)?;
+
+            return Ok(());
         }
         __wasi_sock_opt_so_t::__WASI_SOCK_OPT_SO_RCVLOWAT => {
             return Err(Errno::__WASI_ERRNO_NOSYS);
@@ -545,6 +676,10 @@ pub fn sock_getsockopt<M: Memory>(
     Ok(())
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, mem), err(Debug))
+)]
 pub fn sock_setsockopt<M: Memory>(
     ctx: &mut WasiCtx,
     mem: &M,
@@ -558,6 +693,10 @@ pub fn sock_setsockopt<M: Memory>(
 
     let s = ctx.vfs.get_mut_socket(fd as usize)?;
 
+    if level == __wasi_sock_opt_level_t::__WASI_SOCK_OPT_LEVEL_IPPROTO_IP {
+        return sock_setsockopt_ip(s, mem, name, flag, flag_size);
+    }
+
     if level != __wasi_sock_opt_level_t::__WASI_SOCK_OPT_LEVEL_SOL_SOCKET {
         return Err(Errno::__WASI_ERRNO_NOSYS);
     }
@@ -599,7 +738,20 @@ pub fn sock_setsockopt<M: Memory>(
             return Err(Errno::__WASI_ERRNO_NOSYS);
         }
         __wasi_sock_opt_so_t::__WASI_SOCK_OPT_SO_LINGER => {
-            return Err(Errno::__WASI_ERRNO_NOSYS);
+            if (flag_size as usize) != std::mem::size_of::<__wasi_linger>() {
+                return Err(Errno::__WASI_ERRNO_INVAL);
+            }
+            let offset = WasmPtr::<__wasi_linger>::from(flag.0);
+            let linger = *(mem.get_data(offset)?);
+            let (l_onoff, l_linger) = (i32::from_le(linger.l_onoff), i32::from_le(linger.l_linger));
+
+            let linger = if l_onoff == 0 {
+                None
+            } else {
+                Some(std::time::Duration::from_secs(l_linger.max(0) as u64))
+            };
+
+            s.set_so_linger(linger)?;
         }
         __wasi_sock_opt_so_t::__WASI_SOCK_OPT_SO_RCVLOWAT => {
             return Err(Errno::__WASI_ERRNO_NOSYS);
@@ -657,6 +809,42 @@ pub fn sock_setsockopt<M: Memory>(
     Ok(())
 }
 
+fn sock_setsockopt_ip<M: Memory>(
+    s: &mut net::async_tokio::AsyncWasiSocket,
+    mem: &M,
+    name: __wasi_sock_opt_ip_t::Type,
+    flag: WasmPtr<i32>,
+    flag_size: __wasi_size_t,
+) -> Result<(), Errno> {
+    match name {
+        __wasi_sock_opt_ip_t::__WASI_SOCK_OPT_IP_ADD_MEMBERSHIP
+        | __wasi_sock_opt_ip_t::__WASI_SOCK_OPT_IP_DROP_MEMBERSHIP => {
+            if (flag_size as usize) != std::mem::size_of::<__wasi_ip_mreq>() {
+                return Err(Errno::__WASI_ERRNO_INVAL);
+            }
+            if !matches!(s.get_so_type().0, AddressFamily::Inet4) {
+                return Err(Errno::__WASI_ERRNO_INVAL);
+            }
+            let offset = WasmPtr::<__wasi_ip_mreq>::from(flag.0);
+            let mreq = *(mem.get_data(offset)?);
+            let multiaddr = Ipv4Addr::from(mreq.imr_multiaddr);
+            let interface = Ipv4Addr::from(mreq.imr_interface);
+
+            if name == __wasi_sock_opt_ip_t::__WASI_SOCK_OPT_IP_ADD_MEMBERSHIP {
+                s.join_multicast_v4(multiaddr, interface)?;
+            } else {
+                s.leave_multicast_v4(multiaddr, interface)?;
+            }
+            Ok(())
+        }
+        _ => Err(Errno::__WASI_ERRNO_NOPROTOOPT),
+    }
+}
+
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(_ctx, mem), err(Debug))
+)]
 pub async fn sock_lookup_ip<M: Memory>(
     _ctx: &mut WasiCtx,
     mem: &mut M,
@@ -12,11 +12,17 @@ use super::{
     WasiCtx,
 };
 
+#[cfg(feature = "async_tokio")]
+pub mod async_fd;
 #[cfg(all(unix, feature = "async_tokio"))]
 pub mod async_poll;
 #[cfg(all(unix, feature = "async_tokio"))]
 pub mod async_socket;
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, mem), err(Debug))
+)]
 pub fn args_get<M: Memory>(
     ctx: &WasiCtx,
     mem: &mut M,
@@ -41,6 +47,10 @@ pub fn args_get<M: Memory>(
     Ok(())
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, mem), err(Debug))
+)]
 pub fn args_sizes_get<M: Memory>(
     ctx: &WasiCtx,
     mem: &mut M,
@@ -65,6 +75,10 @@ pub fn args_sizes_get<M: Memory>(
     Ok(())
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, mem), err(Debug))
+)]
 pub fn environ_get<M: Memory>(
     ctx: &WasiCtx,
     mem: &mut M,
@@ -90,6 +104,10 @@ pub fn environ_get<M: Memory>(
     Ok(())
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, mem), err(Debug))
+)]
 pub fn environ_sizes_get<M: Memory>(
     ctx: &WasiCtx,
     mem: &mut M,
@@ -117,6 +135,10 @@ pub fn environ_sizes_get<M: Memory>(
     Ok(())
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(_ctx, mem), err(Debug))
+)]
 pub fn clock_res_get<M: Memory>(
     _ctx: &mut WasiCtx,
     mem: &mut M,
@@ -131,6 +153,10 @@ pub fn clock_res_get<M: Memory>(
     Ok(())
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, mem), err(Debug))
+)]
 pub fn clock_time_get<M: Memory>(
     ctx: &WasiCtx,
     mem: &mut M,
@@ -146,6 +172,10 @@ pub fn clock_time_get<M: Memory>(
     Ok(())
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(_ctx, mem), err(Debug))
+)]
 pub fn random_get<M: Memory>(
     _ctx: &mut WasiCtx,
     mem: &mut M,
@@ -158,6 +188,10 @@ pub fn random_get<M: Memory>(
     getrandom::getrandom(u8_buffer).map_err(|_| Errno(__wasi_errno_t::__WASI_ERRNO_IO))
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, mem), err(Debug))
+)]
 pub fn fd_prestat_get<M: Memory>(
     ctx: &mut WasiCtx,
     mem: &mut M,
@@ -177,6 +211,10 @@ pub fn fd_prestat_get<M: Memory>(
     Ok(())
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, mem), err(Debug))
+)]
 pub fn fd_prestat_dir_name<M: Memory>(
     ctx: &mut WasiCtx,
     mem: &mut M,
@@ -197,6 +235,10 @@ pub fn fd_prestat_dir_name<M: Memory>(
     Ok(())
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, _mem), err(Debug))
+)]
 pub fn fd_renumber<M: Memory>(
     ctx: &mut WasiCtx,
     _mem: &mut M,
@@ -208,6 +250,10 @@ pub fn fd_renumber<M: Memory>(
     ctx.vfs.fd_renumber(from as usize, to as usize)
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, _mem), err(Debug))
+)]
 pub fn fd_advise<M: Memory>(
     ctx: &mut WasiCtx,
     _mem: &mut M,
@@ -221,6 +267,10 @@ pub fn fd_advise<M: Memory>(
     ctx.vfs.fd_advise(fd as usize, offset, len, advice)
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, _mem), err(Debug))
+)]
 pub fn fd_allocate<M: Memory>(
     ctx: &mut WasiCtx,
     _mem: &mut M,
@@ -233,12 +283,71 @@ pub fn fd_allocate<M: Memory>(
     ctx.vfs.get_mut_file(fd as usize)?.fd_allocate(offset, len)
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, _mem), err(Debug))
+)]
 pub fn fd_close<M: Memory>(ctx: &mut WasiCtx, _mem: &mut M, fd: __wasi_fd_t) -> Result<(), Errno> {
     log::trace!("fd_close {fd}");
 
     ctx.vfs.fd_close(fd as usize)
 }
 
+/// Creates a blocking [pipe](crate::snapshots::common::vfs::virtual_sys::PipeSys) and writes its read and write fds.
+///
+/// The ends only block in the async [fd_read](async_fd::fd_read), [fd_write](async_fd::fd_write) and [poll_oneoff](async_poll::poll_oneoff). The sync [fd_read] returns [Errno::__WASI_ERRNO_AGAIN] on an empty pipe.
+#[cfg(feature = "async_tokio")]
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, mem), err(Debug))
+)]
+pub fn fd_pipe<M: Memory>(
+    ctx: &mut WasiCtx,
+    mem: &mut M,
+    ro_fd0: WasmPtr<__wasi_fd_t>,
+    ro_fd1: WasmPtr<__wasi_fd_t>,
+) -> Result<(), Errno> {
+    log::trace!("fd_pipe");
+
+    let (read_fd, write_fd) = ctx.vfs.create_pipe()?;
+    mem.write_data(ro_fd0, (read_fd as __wasi_fd_t).to_le())?;
+    mem.write_data(ro_fd1, (write_fd as __wasi_fd_t).to_le())?;
+    Ok(())
+}
+
+#[cfg(feature = "async_tokio")]
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, mem), err(Debug))
+)]
+pub fn fd_pipe2<M: Memory>(
+    ctx: &mut WasiCtx,
+    mem: &mut M,
+    flags: __wasi_fdflags_t::Type,
+    ro_fd0: WasmPtr<__wasi_fd_t>,
+    ro_fd1: WasmPtr<__wasi_fd_t>,
+) -> Result<(), Errno> {
+    log::trace!("fd_pipe2 {flags}");
+
+    // only the non-blocking flag is supported
+    if flags & !__wasi_fdflags_t::__WASI_FDFLAGS_NONBLOCK != 0 {
+        return Err(Errno::__WASI_ERRNO_NOTSUP);
+    }
+
+    let (read_fd, write_fd) = if flags == __wasi_fdflags_t::__WASI_FDFLAGS_NONBLOCK {
+        ctx.vfs.create_nonblocking_pipe()?
+    } else {
+        ctx.vfs.create_pipe()?
+    };
+    mem.write_data(ro_fd0, (read_fd as __wasi_fd_t).to_le())?;
+    mem.write_data(ro_fd1, (write_fd as __wasi_fd_t).to_le())?;
+    Ok(())
+}
+
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, mem), err(Debug))
+)]
 pub fn fd_seek<M: Memory>(
     ctx: &mut WasiCtx,
     mem: &mut M,
@@ -255,6 +364,10 @@ pub fn fd_seek<M: Memory>(
     Ok(())
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, _mem), err(Debug))
+)]
 pub fn fd_sync<M: Memory>(ctx: &mut WasiCtx, _mem: &mut M, fd: __wasi_fd_t) -> Result<(), Errno> {
     log::trace!("fd_sync {fd}");
 
@@ -262,6 +375,10 @@ pub fn fd_sync<M: Memory>(ctx: &mut WasiCtx, _mem: &mut M, fd: __wasi_fd_t) -> R
     fs.fd_sync()
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, _mem), err(Debug))
+)]
 pub fn fd_datasync<M: Memory>(
     ctx: &mut WasiCtx,
     _mem: &mut M,
@@ -273,6 +390,10 @@ pub fn fd_datasync<M: Memory>(
     fs.fd_datasync()
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, mem), err(Debug))
+)]
 pub fn fd_tell<M: Memory>(
     ctx: &mut WasiCtx,
     mem: &mut M,
@@ -285,6 +406,10 @@ pub fn fd_tell<M: Memory>(
     mem.write_data(offset, fs.fd_tell()?)
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, mem), err(Debug))
+)]
 pub fn fd_fdstat_get<M: Memory>(
     ctx: &mut WasiCtx,
     mem: &mut M,
@@ -310,6 +435,10 @@ pub fn fd_fdstat_get<M: Memory>(
     mem.write_data(buf_ptr, __wasi_fdstat_t::from(fd_stat))
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, _mem), err(Debug))
+)]
 pub fn fd_fdstat_set_flags<M: Memory>(
     ctx: &mut WasiCtx,
     _mem: &mut M,
@@ -333,6 +462,10 @@ pub fn fd_fdstat_set_flags<M: Memory>(
     Err(Errno::__WASI_ERRNO_BADF)
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, _mem), err(Debug))
+)]
 pub fn fd_fdstat_set_rights<M: Memory>(
     ctx: &mut WasiCtx,
     _mem: &mut M,
@@ -349,6 +482,10 @@ pub fn fd_fdstat_set_rights<M: Memory>(
         .fd_fdstat_set_rights(fs_rights_base, fs_rights_inheriting)
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, mem), err(Debug))
+)]
 pub fn fd_filestat_get<M: Memory>(
     ctx: &mut WasiCtx,
     mem: &mut M,
@@ -361,6 +498,10 @@ pub fn fd_filestat_get<M: Memory>(
     mem.write_data(buf, __wasi_filestat_t::from(filestat))
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, _mem), err(Debug))
+)]
 pub fn fd_filestat_set_size<M: Memory>(
     ctx: &mut WasiCtx,
     _mem: &mut M,
@@ -373,6 +514,10 @@ pub fn fd_filestat_set_size<M: Memory>(
     fs.fd_filestat_set_size(st_size)
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, _mem), err(Debug))
+)]
 pub fn fd_filestat_set_times<M: Memory>(
     ctx: &mut WasiCtx,
     _mem: &mut M,
@@ -387,6 +532,10 @@ pub fn fd_filestat_set_times<M: Memory>(
     inode.fd_filestat_set_times(st_atim, st_mtim, fst_flags)
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, mem), err(Debug))
+)]
 pub fn fd_read<M: Memory>(
     ctx: &mut WasiCtx,
     mem: &mut M,
@@ -403,6 +552,10 @@ pub fn fd_read<M: Memory>(
     mem.write_data(nread, n.to_le())
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, mem), err(Debug))
+)]
 pub fn fd_pread<M: Memory>(
     ctx: &mut WasiCtx,
     mem: &mut M,
@@ -420,6 +573,10 @@ pub fn fd_pread<M: Memory>(
     mem.write_data(nread, n.to_le())
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, mem), err(Debug))
+)]
 pub fn fd_write<M: Memory>(
     ctx: &mut WasiCtx,
     mem: &mut M,
@@ -436,6 +593,10 @@ pub fn fd_write<M: Memory>(
     mem.write_data(nwritten, n.to_le())
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, mem), err(Debug))
+)]
 pub fn fd_pwrite<M: Memory>(
     ctx: &mut WasiCtx,
     mem: &mut M,
@@ -453,6 +614,10 @@ pub fn fd_pwrite<M: Memory>(
     mem.write_data(nwritten, n.to_le())
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, mem), err(Debug))
+)]
 pub fn fd_readdir<M: Memory>(
     ctx: &mut WasiCtx,
     mem: &mut M,
@@ -472,6 +637,83 @@ pub fn fd_readdir<M: Memory>(
     Ok(())
 }
 
+/// A WasmEdge extension of [fd_readdir], which also writes the stat of each entry, so that listing a directory with the stats of its entries takes a single call.
+///
+/// Each entry is written as a `__wasi_dirent_t`, followed by a `__wasi_filestat_t` and the name of `d_namlen` bytes. Like [fd_readdir], the last entry is truncated if the buffer is full. If the stat of an entry is unavailable, then its filestat only carries the file type and the inode of the dirent.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, mem), err(Debug))
+)]
+pub fn fd_readdir_stat<M: Memory>(
+    ctx: &mut WasiCtx,
+    mem: &mut M,
+    fd: __wasi_fd_t,
+    buf: WasmPtr<u8>,
+    buf_len: __wasi_size_t,
+    cookie: __wasi_dircookie_t,
+    bufused_ptr: WasmPtr<__wasi_size_t>,
+) -> Result<(), Errno> {
+    log::trace!("fd_readdir_stat {fd}");
+
+    fn as_bytes<T>(value: &T) -> &[u8] {
+        unsafe {
+            std::slice::from_raw_parts(value as *const T as *const u8, std::mem::size_of::<T>())
+        }
+    }
+
+    let entries = ctx.vfs.get_dir(fd as usize)?.get_readdir(cookie)?;
+    let write_buf = mem.mut_slice(buf, buf_len as usize)?;
+
+    let mut bufused = 0;
+    for (next, (name, inode, filetype)) in (cookie + 1..).zip(entries) {
+        let file_stat: __wasi_filestat_t = ctx
+            .vfs
+            .path_filestat_get(fd as usize, &name, false)
+            .unwrap_or((
+                0,
+                vfs::Filestat {
+                    filetype,
+                    inode,
+                    nlink: 0,
+                    size: 0,
+                    atim: None,
+                    mtim: None,
+                    ctim: None,
+                },
+            ))
+            .into();
+        let entity = vfs::ReaddirEntity {
+            next,
+            inode,
+            name,
+            filetype,
+        };
+        let dirent = __wasi_dirent_t::from(&entity);
+
+        let mut entry = Vec::with_capacity(
+            std::mem::size_of::<__wasi_dirent_t>()
+                + std::mem::size_of::<__wasi_filestat_t>()
+                + entity.name.len(),
+        );
+        entry.extend_from_slice(as_bytes(&dirent));
+        entry.extend_from_slice(as_bytes(&file_stat));
+        entry.extend_from_slice(entity.name.as_bytes());
+
+        let n = entry.len().min(write_buf.len() - bufused);
+        write_buf[bufused..bufused + n].copy_from_slice(&entry[..n]);
+        bufused += n;
+        if bufused == write_buf.len() {
+            break;
+        }
+    }
+
+    mem.write_data(bufused_ptr, (bufused as __wasi_size_t).to_le())
+}
+
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, mem), err(Debug))
+)]
 pub fn path_create_directory<M: Memory>(
     ctx: &mut WasiCtx,
     mem: &M,
@@ -488,6 +730,10 @@ pub fn path_create_directory<M: Memory>(
     ctx.vfs.path_create_directory(dirfd as usize, path_str)
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, mem), err(Debug))
+)]
 pub fn path_filestat_get<M: Memory>(
     ctx: &mut WasiCtx,
     mem: &mut M,
@@ -509,20 +755,41 @@ pub fn path_filestat_get<M: Memory>(
     Ok(())
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, mem), err(Debug))
+)]
 pub fn path_filestat_set_times<M: Memory>(
-    _ctx: &mut WasiCtx,
-    _mem: &mut M,
-    _dirfd: __wasi_fd_t,
-    _flags: __wasi_lookupflags_t::Type,
-    _path: WasmPtr<u8>,
-    _path_len: __wasi_size_t,
-    _st_atim: __wasi_timestamp_t,
-    _st_mtim: __wasi_timestamp_t,
-    _fst_flags: __wasi_fstflags_t::Type,
+    ctx: &mut WasiCtx,
+    mem: &mut M,
+    dirfd: __wasi_fd_t,
+    flags: __wasi_lookupflags_t::Type,
+    path: WasmPtr<u8>,
+    path_len: __wasi_size_t,
+    st_atim: __wasi_timestamp_t,
+    st_mtim: __wasi_timestamp_t,
+    fst_flags: __wasi_fstflags_t::Type,
 ) -> Result<(), Errno> {
-    Err(Errno::__WASI_ERRNO_NOSYS)
+    let path_buf = mem.get_slice(path, path_len as usize)?;
+    let path = std::str::from_utf8(path_buf).or(Err(Errno::__WASI_ERRNO_ILSEQ))?;
+
+    log::trace!("path_filestat_set_times {dirfd} {path}");
+
+    let follow_symlinks = flags & __wasi_lookupflags_t::__WASI_LOOKUPFLAGS_SYMLINK_FOLLOW > 0;
+    ctx.vfs.path_filestat_set_times(
+        dirfd as usize,
+        path,
+        follow_symlinks,
+        st_atim,
+        st_mtim,
+        fst_flags,
+    )
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(_ctx, _mem), err(Debug))
+)]
 pub fn path_link<M: Memory>(
     _ctx: &mut WasiCtx,
     _mem: &mut M,
@@ -537,6 +804,10 @@ pub fn path_link<M: Memory>(
     Err(Errno::__WASI_ERRNO_NOSYS)
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, mem), err(Debug))
+)]
 pub fn path_open<M: Memory>(
     ctx: &mut WasiCtx,
     mem: &mut M,
@@ -570,19 +841,40 @@ pub fn path_open<M: Memory>(
     mem.write_data(fd_ptr, vfd as i32)
 }
 
+/// Reads the target of the symbolic link at `path` into `buf`. If the target is longer than `buf_len`, then only `buf_len` bytes are written, and `buf_used` still reports the full length, so the caller can retry with a larger buffer.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, mem), err(Debug))
+)]
 pub fn path_readlink<M: Memory>(
-    _ctx: &mut WasiCtx,
-    _mem: &mut M,
-    _dir_fd: __wasi_fd_t,
-    _path: WasmPtr<u8>,
-    _path_len: __wasi_size_t,
-    _buf: WasmPtr<u8>,
-    _buf_len: __wasi_size_t,
-    _buf_used: WasmPtr<__wasi_size_t>,
+    ctx: &mut WasiCtx,
+    mem: &mut M,
+    dir_fd: __wasi_fd_t,
+    path: WasmPtr<u8>,
+    path_len: __wasi_size_t,
+    buf: WasmPtr<u8>,
+    buf_len: __wasi_size_t,
+    buf_used: WasmPtr<__wasi_size_t>,
 ) -> Result<(), Errno> {
-    Err(Errno::__WASI_ERRNO_NOSYS)
+    let path_buf = mem.get_slice(path, path_len as usize)?;
+    let path = std::str::from_utf8(path_buf).or(Err(Errno::__WASI_ERRNO_ILSEQ))?;
+
+    log::trace!("path_readlink {dir_fd} {path}");
+
+    let target = ctx.vfs.path_readlink(dir_fd as usize, path)?;
+    let target = target.as_bytes();
+    let n = target.len().min(buf_len as usize);
+    let buf = mem.mut_slice(buf, n)?;
+    buf.copy_from_slice(&target[..n]);
+    let buf_used = mem.mut_data(buf_used)?;
+    *buf_used = (target.len() as __wasi_size_t).to_le();
+    Ok(())
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, mem), err(Debug))
+)]
 pub fn path_remove_directory<M: Memory>(
     ctx: &mut WasiCtx,
     mem: &M,
@@ -596,6 +888,10 @@ pub fn path_remove_directory<M: Memory>(
     ctx.vfs.path_remove_directory(dirfd as usize, path)
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, mem), err(Debug))
+)]
 pub fn path_rename<M: Memory>(
     ctx: &mut WasiCtx,
     mem: &M,
@@ -618,18 +914,34 @@ pub fn path_rename<M: Memory>(
         .path_rename(old_fd as usize, old_path, new_fd as usize, new_path)
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, mem), err(Debug))
+)]
 pub fn path_symlink<M: Memory>(
-    _ctx: &mut WasiCtx,
-    _mem: &mut M,
-    _old_path: WasmPtr<u8>,
-    _old_path_len: __wasi_size_t,
-    _fd: __wasi_fd_t,
-    _new_path: WasmPtr<u8>,
-    _new_path_len: __wasi_size_t,
+    ctx: &mut WasiCtx,
+    mem: &M,
+    old_path: WasmPtr<u8>,
+    old_path_len: __wasi_size_t,
+    fd: __wasi_fd_t,
+    new_path: WasmPtr<u8>,
+    new_path_len: __wasi_size_t,
 ) -> Result<(), Errno> {
-    Err(Errno::__WASI_ERRNO_NOSYS)
+    let old_path = mem.get_slice(old_path, old_path_len as usize)?;
+    let old_path = std::str::from_utf8(old_path).or(Err(Errno::__WASI_ERRNO_ILSEQ))?;
+
+    let new_path = mem.get_slice(new_path, new_path_len as usize)?;
+    let new_path = std::str::from_utf8(new_path).or(Err(Errno::__WASI_ERRNO_ILSEQ))?;
+
+    log::trace!("path_symlink {old_path} {fd} {new_path}");
+
+    ctx.vfs.path_symlink(old_path, fd as usize, new_path)
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, mem), err(Debug))
+)]
 pub fn path_unlink_file<M: Memory>(
     ctx: &mut WasiCtx,
     mem: &M,
@@ -644,10 +956,18 @@ pub fn path_unlink_file<M: Memory>(
     ctx.vfs.path_unlink_file(dirfd as usize, path)
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(ctx, _mem))
+)]
 pub fn proc_exit<M: Memory>(ctx: &mut WasiCtx, _mem: &mut M, code: __wasi_exitcode_t) {
     ctx.exit_code = u32::from_le(code)
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(_ctx, _mem), err(Debug))
+)]
 pub fn proc_raise<M: Memory>(
     _ctx: &mut WasiCtx,
     _mem: &mut M,
@@ -656,6 +976,136 @@ pub fn proc_raise<M: Memory>(
     Err(Errno::__WASI_ERRNO_NOSYS)
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(_ctx, vm), err(Debug))
+)]
 pub fn sched_yield<VM: AsyncVM>(_ctx: &mut WasiCtx, vm: &mut VM) -> Result<(), Errno> {
     vm.yield_now()
 }
+
+#[cfg(all(test, feature = "tracing"))]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    // a memory holding only the bytes the functions under test read
+    struct TestMemory(Vec<u8>);
+
+    impl Memory for TestMemory {
+        fn get_data<T: Sized>(&self, _offset: WasmPtr<T>) -> Result<&T, Errno> {
+            Err(Errno::__WASI_ERRNO_FAULT)
+        }
+
+        fn get_slice<T: Sized>(&self, offset: WasmPtr<T>, len: usize) -> Result<&[T], Errno> {
+            assert_eq!(std::mem::size_of::<T>(), 1);
+            let bytes = self
+                .0
+                .get(offset.0..offset.0 + len)
+                .ok_or(Errno::__WASI_ERRNO_FAULT)?;
+            Ok(unsafe { std::slice::from_raw_parts(bytes.as_ptr() as *const T, len) })
+        }
+
+        fn get_iovec<'a>(
+            &self,
+            _iovec_ptr: WasmPtr<__wasi_ciovec_t>,
+            _iovec_len: __wasi_size_t,
+        ) -> Result<Vec<std::io::IoSlice<'a>>, Errno> {
+            Err(Errno::__WASI_ERRNO_FAULT)
+        }
+
+        fn mut_data<T: Sized>(&mut self, _offset: WasmPtr<T>) -> Result<&mut T, Errno> {
+            Err(Errno::__WASI_ERRNO_FAULT)
+        }
+
+        fn mut_slice<T: Sized>(
+            &mut self,
+            _offset: WasmPtr<T>,
+            _len: usize,
+        ) -> Result<&mut [T], Errno> {
+            Err(Errno::__WASI_ERRNO_FAULT)
+        }
+
+        fn mut_iovec(
+            &mut self,
+            _iovec_ptr: WasmPtr<__wasi_iovec_t>,
+            _iovec_len: __wasi_size_t,
+        ) -> Result<Vec<std::io::IoSliceMut<'_>>, Errno> {
+            Err(Errno::__WASI_ERRNO_FAULT)
+        }
+
+        fn write_data<T: Sized>(&mut self, _offset: WasmPtr<T>, _data: T) -> Result<(), Errno> {
+            Err(Errno::__WASI_ERRNO_FAULT)
+        }
+    }
+
+    // records the names of the created spans and the errors of the events
+    #[derive(Clone, Default)]
+    struct Recorder {
+        spans: Arc<Mutex<Vec<&'static str>>>,
+        errors: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl tracing::field::Visit for Recorder {
+        fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+            if field.name() == "error" {
+                self.errors.lock().unwrap().push(format!("{value:?}"));
+            }
+        }
+    }
+
+    impl tracing::Subscriber for Recorder {
+        fn enabled(&self, _metadata: &tracing::Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &tracing::span::Attributes<'_>) -> tracing::span::Id {
+            let mut spans = self.spans.lock().unwrap();
+            spans.push(span.metadata().name());
+            tracing::span::Id::from_u64(spans.len() as u64)
+        }
+
+        fn record(&self, _span: &tracing::span::Id, _values: &tracing::span::Record<'_>) {}
+
+        fn record_follows_from(&self, _span: &tracing::span::Id, _follows: &tracing::span::Id) {}
+
+        fn event(&self, event: &tracing::Event<'_>) {
+            event.record(&mut self.clone());
+        }
+
+        fn enter(&self, _span: &tracing::span::Id) {}
+
+        fn exit(&self, _span: &tracing::span::Id) {}
+    }
+
+    #[test]
+    fn test_instrumented_errors() {
+        let recorder = Recorder::default();
+        let mut ctx = WasiCtx::new();
+        let mut mem = TestMemory(vec![0xff, 0xfe]);
+
+        tracing::subscriber::with_default(recorder.clone(), || {
+            // the error of decoding the path is recorded as well
+            assert_eq!(
+                path_create_directory(&mut ctx, &mem, 3, WasmPtr::from(0), 2),
+                Err(Errno::__WASI_ERRNO_ILSEQ)
+            );
+            assert_eq!(
+                proc_raise(&mut ctx, &mut mem, 0),
+                Err(Errno::__WASI_ERRNO_NOSYS)
+            );
+        });
+
+        assert_eq!(
+            *recorder.spans.lock().unwrap(),
+            ["path_create_directory", "proc_raise"]
+        );
+        assert_eq!(
+            *recorder.errors.lock().unwrap(),
+            [
+                format!("{:?}", Errno::__WASI_ERRNO_ILSEQ),
+                format!("{:?}", Errno::__WASI_ERRNO_NOSYS)
+            ]
+        );
+    }
+}
@@ -13,6 +13,23 @@ use syn::{parse_macro_input, parse_quote, spanned::Spanned, FnArg, Item, Pat, Pa
 // ================== macros for wasmedge-sdk ==================
 
 /// Declare a native function that will be used to create a host function instance.
+///
+/// The native function takes the [CallingFrame](https://docs.rs/wasmedge-sdk/latest/wasmedge_sdk/type.CallingFrame.html) of the call, the arguments, and optionally the host context data as `&mut T` or `Option<&mut T>`. The macro turns it into a [HostFn](https://docs.rs/wasmedge-sdk/latest/wasmedge_sdk/type.HostFn.html), which can be passed to `ImportObjectBuilder::with_func`. Without the host context data argument, the host function can be used with the host context data of any type.
+///
+/// If the native function returns `Result<R, E>`, then `R` can be any type implementing [WasmValList](https://docs.rs/wasmedge-sdk/latest/wasmedge_sdk/trait.WasmValList.html), such as `Vec<WasmValue>`, `i32`, or `(i32, i64)`, and `E` can be any error type convertible into `CoreError`. Any other return type is taken as `R`.
+///
+/// ```ignore
+/// #[host_function]
+/// fn add(_frame: &mut CallingFrame, args: Vec<WasmValue>) -> Result<i32, CoreError> {
+///     Ok(args[0].to_i32() + args[1].to_i32())
+/// }
+///
+/// #[host_function]
+/// fn count(_frame: &mut CallingFrame, _args: Vec<WasmValue>, data: &mut i64) -> i64 {
+///     *data += 1;
+///     *data
+/// }
+/// ```
 #[proc_macro_attribute]
 pub fn host_function(_attr: TokenStream, item: TokenStream) -> TokenStream {
     let body_ast = parse_macro_input!(item as Item);
@@ -27,185 +44,150 @@ pub fn host_function(_attr: TokenStream, item: TokenStream) -> TokenStream {
 }
 
 fn expand_host_func(item_fn: &syn::ItemFn) -> syn::Result<proc_macro2::TokenStream> {
-    // extract T from Option<&mut T>
-    let ret = match &item_fn.sig.inputs.len() {
-        2 => expand_host_func_with_two_args(item_fn),
-        3 => expand_host_func_with_three_args(item_fn),
-        _ => panic!(
-            "Invalid numbers of host function arguments: {}",
-            &item_fn.sig.inputs.len()
-        ),
-    };
+    // the wrapper of a sync host function cannot drive the future returned by an async function
+    if let Some(asyncness) = &item_fn.sig.asyncness {
+        return Err(syn::Error::new_spanned(
+            asyncness,
+            "use #[async_host_function] for async functions; #[host_function] only supports synchronous functions",
+        ));
+    }
 
-    Ok(ret)
-}
+    // name and visibility of wrapper function
+    let wrapper_fn_name_ident = &item_fn.sig.ident;
+    let wrapper_visibility = &item_fn.vis;
 
-fn expand_host_func_with_two_args(item_fn: &syn::ItemFn) -> proc_macro2::TokenStream {
-    // * define the signature of wrapper function
-    // name of wrapper function
-    let wrapper_fn_name_ident = item_fn.sig.ident.clone();
-    let wrapper_fn_name_literal = wrapper_fn_name_ident.to_string();
-    // arguments of wrapper function
-    let wrapper_fn_inputs: syn::punctuated::Punctuated<FnArg, syn::token::Comma> = parse_quote!(
-        frame: wasmedge_sdk::CallingFrame,
-        args: Vec<wasmedge_sdk::WasmValue>,
-        _data: *mut std::os::raw::c_void
+    // the inner function is the native function itself, so any argument pattern can be used
+    let inner_fn_name_ident = syn::Ident::new(
+        &format!("inner_{wrapper_fn_name_ident}"),
+        item_fn.sig.ident.span(),
     );
-    // return type of wrapper function
-    let wrapper_fn_return = item_fn.sig.output.clone();
-    // visibility of wrapper function
-    let wrapper_visibility = item_fn.vis.clone();
-
-    // get the name of the first argument
-    let (ident_first_arg, mutability) = match &item_fn.sig.inputs[0] {
-        FnArg::Typed(PatType { pat, .. }) => match &**pat {
-            Pat::Ident(pat_ident) => (pat_ident.ident.clone(), pat_ident.mutability),
-            Pat::Wild(_) => (
-                proc_macro2::Ident::new("_caller", proc_macro2::Span::call_site()),
-                None,
-            ),
-            _ => panic!("The argument pattern of the first argument is not a simple ident"),
-        },
-        FnArg::Receiver(_) => panic!("The first argument is a receiver"),
-    };
-
-    // get the name of the second argument
-    let ident_second_arg = match &item_fn.sig.inputs[1] {
-        FnArg::Typed(PatType { pat, .. }) => match &**pat {
-            Pat::Ident(pat_ident) => pat_ident.ident.clone(),
-            Pat::Wild(_) => proc_macro2::Ident::new("_args", proc_macro2::Span::call_site()),
-            _ => panic!("The argument pattern of the second argument is not a simple ident"),
-        },
-        FnArg::Receiver(_) => panic!("The second argument is a receiver"),
+    let inner_fn_inputs = &item_fn.sig.inputs;
+    let inner_fn_return = &item_fn.sig.output;
+    let inner_fn_block = &item_fn.block;
+
+    let (data_ty, data_arg) = expand_host_func_data(item_fn)?;
+    let (generics, data_ident, inner_fn_args) = match data_arg {
+        Some(data_arg) => (
+            proc_macro2::TokenStream::new(),
+            quote!(data),
+            quote!(frame, args, #data_arg),
+        ),
+        None => (quote!(<#data_ty>), quote!(_data), quote!(frame, args)),
     };
+    let returns = expand_host_func_return(
+        &item_fn.sig.output,
+        quote!(#inner_fn_name_ident(#inner_fn_args)),
+    );
 
-    // * define the signature of inner function
-    // name of inner function
-    let inner_fn_name_literal = format!("inner_{wrapper_fn_name_literal}");
-    let inner_fn_name_ident = syn::Ident::new(&inner_fn_name_literal, item_fn.sig.span());
-    // arguments of inner function
-    let inner_fn_inputs = item_fn.sig.inputs.clone();
-    // return type of inner function
-    let inner_fn_return = item_fn.sig.output.clone();
-    // body of inner function
-    let inner_fn_block = item_fn.block.clone();
-
-    quote!(
-        # wrapper_visibility fn #wrapper_fn_name_ident (#wrapper_fn_inputs) #wrapper_fn_return {
+    Ok(quote!(
+        #wrapper_visibility fn #wrapper_fn_name_ident #generics (
+            #data_ident: &mut #data_ty,
+            _inst: &mut wasmedge_sdk::Instance,
+            frame: &mut wasmedge_sdk::CallingFrame,
+            args: Vec<wasmedge_sdk::WasmValue>,
+        ) -> Result<Vec<wasmedge_sdk::WasmValue>, wasmedge_sdk::error::CoreError> {
             // define inner function
-            fn #inner_fn_name_ident (#inner_fn_inputs) #inner_fn_return {
-                #inner_fn_block
-            }
+            fn #inner_fn_name_ident (#inner_fn_inputs) #inner_fn_return #inner_fn_block
 
-            // create a Caller instance
-            let #mutability #ident_first_arg = Caller::new(frame);
-
-            let #ident_second_arg = args;
-
-            #inner_fn_name_ident(#ident_first_arg, #ident_second_arg)
+            #returns
         }
-    )
+    ))
 }
 
-fn expand_host_func_with_three_args(item_fn: &syn::ItemFn) -> proc_macro2::TokenStream {
-    // * define the signature of wrapper function
-    // name of wrapper function
-    let wrapper_fn_name_ident = item_fn.sig.ident.clone();
-    let wrapper_fn_name_literal = wrapper_fn_name_ident.to_string();
-    // arguments of wrapper function
-    let wrapper_fn_inputs: syn::punctuated::Punctuated<FnArg, syn::token::Comma> = parse_quote!(
-        frame: wasmedge_sdk::CallingFrame,
-        args: Vec<wasmedge_sdk::WasmValue>,
-        data: *mut std::os::raw::c_void
-    );
-    // return type of wrapper function
-    let wrapper_fn_return = item_fn.sig.output.clone();
-    // visibility of wrapper function
-    let wrapper_visibility = item_fn.vis.clone();
-
-    // * define the signature of inner function
-    // name of inner function
-    let inner_fn_name_literal = format!("inner_{wrapper_fn_name_literal}");
-    let inner_fn_name_ident = syn::Ident::new(&inner_fn_name_literal, item_fn.sig.span());
-    // arguments of inner function
-    let inner_fn_inputs = item_fn.sig.inputs.clone();
-    // return type of inner function
-    let inner_fn_return = item_fn.sig.output.clone();
-    // body of inner function
-    let inner_fn_block = item_fn.block.clone();
-
-    // extract T from Option<&mut T>
+// Returns the type of the host context data taken by the wrapper function and the argument passing the data to the native function. If the native function takes no host context data, then the data type is the generic `__Data` of the wrapper function, which is named so as not to shadow the types used in the native function.
+fn expand_host_func_data(
+    item_fn: &syn::ItemFn,
+) -> syn::Result<(syn::Type, Option<proc_macro2::TokenStream>)> {
+    let inputs = &item_fn.sig.inputs;
+    if let Some(receiver) = inputs.iter().find(|arg| matches!(arg, FnArg::Receiver(_))) {
+        return Err(syn::Error::new_spanned(
+            receiver,
+            "a host function cannot take a receiver",
+        ));
+    }
+    match inputs.len() {
+        2 => Ok((parse_quote!(__Data), None)),
+        3 => {
+            let FnArg::Typed(PatType { ty, .. }) = &inputs[2] else {
+                unreachable!("the receiver is rejected above");
+            };
+            if let syn::Type::Reference(syn::TypeReference {
+                mutability: Some(_),
+                elem,
+                ..
+            }) = &**ty
+            {
+                return Ok(((**elem).clone(), Some(quote!(data))));
+            }
+            if let Some(elem) = option_mut_ref_elem(ty) {
+                return Ok((elem, Some(quote!(Some(data)))));
+            }
+            Err(syn::Error::new_spanned(
+                ty,
+                "the host context data must be taken as `&mut T` or `Option<&mut T>`",
+            ))
+        }
+        n => Err(syn::Error::new_spanned(
+            inputs,
+            format!("Invalid numbers of host function arguments: {n}"),
+        )),
+    }
+}
 
-    let data_arg = item_fn.sig.inputs.last().unwrap().clone();
-    let ty_ptr = match &data_arg {
-        FnArg::Typed(PatType { ref ty, .. }) => match **ty {
-            syn::Type::Reference(syn::TypeReference { ref elem, .. }) => syn::TypePtr {
-                star_token: parse_quote!(*),
-                const_token: None,
-                mutability: Some(parse_quote!(mut)),
-                elem: elem.clone(),
-            },
-            syn::Type::Path(syn::TypePath { ref path, .. }) => match path.segments.last() {
-                Some(segment) => {
-                    let id = segment.ident.to_string();
-                    match id == "Option" {
-                        true => match segment.arguments {
-                            syn::PathArguments::AngleBracketed(
-                                syn::AngleBracketedGenericArguments { ref args, .. },
-                            ) => {
-                                let last_generic_arg = args.last();
-                                match last_generic_arg {
-                                    Some(arg) => match arg {
-                                        syn::GenericArgument::Type(ty) => match ty {
-                                            syn::Type::Reference(syn::TypeReference {
-                                                ref elem,
-                                                ..
-                                            }) => syn::TypePtr {
-                                                star_token: parse_quote!(*),
-                                                const_token: None,
-                                                mutability: Some(parse_quote!(mut)),
-                                                elem: elem.clone(),
-                                            },
-                                            _ => panic!("Not found syn::Type::Reference"),
-                                        },
-                                        _ => {
-                                            panic!("Not found syn::GenericArgument::Type")
-                                        }
-                                    },
-                                    None => panic!("Not found the last GenericArgument"),
-                                }
-                            }
-                            _ => panic!("Not found syn::PathArguments::AngleBracketed"),
-                        },
-                        false => panic!("Not found segment ident: Option"),
-                    }
-                }
-                None => panic!("Not found path segments"),
-            },
-            _ => panic!("Unsupported syn::Type type"),
-        },
-        _ => panic!("Unsupported syn::FnArg type"),
+// Extracts `T` from `Option<&mut T>`.
+fn option_mut_ref_elem(ty: &syn::Type) -> Option<syn::Type> {
+    let syn::Type::Path(syn::TypePath { path, .. }) = ty else {
+        return None;
     };
+    let segment = path.segments.last().filter(|s| s.ident == "Option")?;
+    let syn::PathArguments::AngleBracketed(syn::AngleBracketedGenericArguments { args, .. }) =
+        &segment.arguments
+    else {
+        return None;
+    };
+    match args.first() {
+        Some(syn::GenericArgument::Type(syn::Type::Reference(syn::TypeReference {
+            mutability: Some(_),
+            elem,
+            ..
+        }))) if args.len() == 1 => Some((**elem).clone()),
+        _ => None,
+    }
+}
 
-    // generate token stream
-    quote!(
-        # wrapper_visibility fn #wrapper_fn_name_ident (#wrapper_fn_inputs) #wrapper_fn_return {
-            // define inner function
-            fn #inner_fn_name_ident (#inner_fn_inputs) #inner_fn_return {
-                #inner_fn_block
+// Converts the value returned by the native function into the `Result<Vec<WasmValue>, CoreError>` returned by the wrapper function. A `Result<R, E>` is converted by `R: WasmValList` and `E: Into<CoreError>`, and any other value is converted as `R`.
+fn expand_host_func_return(
+    output: &syn::ReturnType,
+    returned: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    if let syn::ReturnType::Type(_, ty) = output {
+        if let syn::Type::Path(syn::TypePath { path, .. }) = &**ty {
+            if path.segments.last().is_some_and(|s| s.ident == "Result") {
+                return quote!(
+                    #returned
+                        .map(wasmedge_sdk::WasmValList::to_wasm_values)
+                        .map_err(Into::into)
+                );
             }
-
-            // create a Caller instance
-            let caller = Caller::new(frame);
-
-            let data = unsafe { &mut *(data as #ty_ptr) };
-
-            #inner_fn_name_ident(caller, args, data)
         }
-    )
+    }
+
+    quote!(Ok(wasmedge_sdk::WasmValList::to_wasm_values(#returned)))
 }
 
 /// Declare a native async function that will be used to create an async host function instance.
+///
+/// The native async function takes the same arguments as the one of [host_function](macro@host_function), and the macro turns it into an [AsyncFn](https://docs.rs/wasmedge-sys/latest/wasmedge_sys/async/function/type.AsyncFn.html), which can be passed to `r#async::import::ImportObjectBuilder::with_func`. The host context data must be `Send` to be used across the awaits.
+///
+/// If the native async function returns `Result<R, E>`, then `R` can be any type implementing [WasmValList](https://docs.rs/wasmedge-sdk/latest/wasmedge_sdk/trait.WasmValList.html), such as `Vec<WasmValue>`, `i32`, or `(i32, i64)`, and `E` can be any error type convertible into `CoreError`. Any other return type is taken as `R`.
+///
+/// ```ignore
+/// #[async_host_function]
+/// async fn sleep(_frame: &mut CallingFrame, args: Vec<WasmValue>) -> Result<(), CoreError> {
+///     tokio::time::sleep(std::time::Duration::from_millis(args[0].to_i64() as u64)).await;
+///     Ok(())
+/// }
+/// ```
 #[proc_macro_attribute]
 pub fn async_host_function(_attr: TokenStream, item: TokenStream) -> TokenStream {
     let body_ast = parse_macro_input!(item as Item);
@@ -224,191 +206,60 @@ pub fn async_host_function(_attr: TokenStream, item: TokenStream) -> TokenStream
 }
 
 fn expand_async_host_func(item_fn: &syn::ItemFn) -> syn::Result<proc_macro2::TokenStream> {
-    // extract T from Option<&mut T>
-    let ret = match &item_fn.sig.inputs.len() {
-        2 => expand_async_host_func_with_two_args(item_fn),
-        3 => expand_async_host_func_with_three_args(item_fn),
-        _ => panic!(
-            "Invalid numbers of host function arguments: {}",
-            &item_fn.sig.inputs.len()
-        ),
-    };
-
-    Ok(ret)
-}
-
-fn expand_async_host_func_with_two_args(item_fn: &syn::ItemFn) -> proc_macro2::TokenStream {
-    // * define the signature of wrapper function
-    // name of wrapper function
-    let wrapper_fn_name_ident = item_fn.sig.ident.clone();
-    // arguments of wrapper function
-    let wrapper_fn_inputs: syn::punctuated::Punctuated<FnArg, syn::token::Comma> = parse_quote!(
-        frame: wasmedge_sdk::CallingFrame,
-        args: Vec<wasmedge_sdk::WasmValue>,
-        _data: *mut std::ffi::c_void
+    // name and visibility of wrapper function
+    let wrapper_fn_name_ident = &item_fn.sig.ident;
+    let wrapper_visibility = &item_fn.vis;
+
+    // the inner function is the native async function itself, so any argument pattern can be used
+    let inner_fn_name_ident = syn::Ident::new(
+        &format!("inner_{wrapper_fn_name_ident}"),
+        item_fn.sig.ident.span(),
     );
-
-    // visibility of wrapper function
-    let wrapper_visibility = item_fn.vis.clone();
-
-    // get the name of the first argument
-    let (ident_first_arg, mutability) = match &item_fn.sig.inputs[0] {
-        FnArg::Typed(PatType { pat, .. }) => match &**pat {
-            Pat::Ident(pat_ident) => (pat_ident.ident.clone(), pat_ident.mutability),
-            Pat::Wild(_) => (
-                proc_macro2::Ident::new("_caller", proc_macro2::Span::call_site()),
-                None,
-            ),
-            _ => panic!("The argument pattern of the first argument is not a simple ident"),
-        },
-        FnArg::Receiver(_) => panic!("The first argument is a receiver"),
-    };
-
-    // get the name of the second argument
-    let ident_second_arg = match &item_fn.sig.inputs[1] {
-        FnArg::Typed(PatType { pat, .. }) => match &**pat {
-            Pat::Ident(pat_ident) => pat_ident.ident.clone(),
-            Pat::Wild(_) => proc_macro2::Ident::new("_args", proc_macro2::Span::call_site()),
-            _ => panic!("The argument pattern of the second argument is not a simple ident"),
-        },
-        FnArg::Receiver(_) => panic!("The second argument is a receiver"),
+    let inner_fn_inputs = &item_fn.sig.inputs;
+    let inner_fn_return = &item_fn.sig.output;
+    let inner_fn_block = &item_fn.block;
+
+    let (data_ty, data_arg) = expand_host_func_data(item_fn)?;
+    let (generics, data_ident, inner_fn_args) = match data_arg {
+        Some(data_arg) => (
+            quote!(<'data, 'inst, 'frame, 'fut>),
+            quote!(data),
+            quote!(frame, args, #data_arg),
+        ),
+        None => (
+            quote!(<'data, 'inst, 'frame, 'fut, #data_ty>),
+            quote!(_data),
+            quote!(frame, args),
+        ),
     };
-
-    // func body
-    let fn_block = item_fn.block.clone();
-
-    quote!(
-        #wrapper_visibility fn #wrapper_fn_name_ident (#wrapper_fn_inputs) -> Box<(dyn std::future::Future<Output = Result<Vec<WasmValue>, HostFuncError>> + Send)> {
-
-            // create a Caller instance
-            let #mutability #ident_first_arg = Caller::new(frame);
-
-            let #ident_second_arg = args;
-
-            Box::new(async move {
-                #fn_block
-            })
-        }
-    )
-}
-
-fn expand_async_host_func_with_three_args(item_fn: &syn::ItemFn) -> proc_macro2::TokenStream {
-    // * define the signature of wrapper function
-    // name of wrapper function
-    let wrapper_fn_name_ident = item_fn.sig.ident.clone();
-    // arguments of wrapper function
-    let wrapper_fn_inputs: syn::punctuated::Punctuated<FnArg, syn::token::Comma> = parse_quote!(
-        frame: wasmedge_sdk::CallingFrame,
-        args: Vec<wasmedge_sdk::WasmValue>,
-        data: *mut std::ffi::c_void
+    let returns = expand_host_func_return(
+        &item_fn.sig.output,
+        quote!(#inner_fn_name_ident(#inner_fn_args).await),
     );
-    // visibility of wrapper function
-    let wrapper_visibility = item_fn.vis.clone();
-
-    // get the name of the first argument
-    let (ident_first_arg, mutability) = match &item_fn.sig.inputs[0] {
-        FnArg::Typed(PatType { pat, .. }) => match &**pat {
-            Pat::Ident(pat_ident) => (pat_ident.ident.clone(), pat_ident.mutability),
-            Pat::Wild(_) => (
-                proc_macro2::Ident::new("_caller", proc_macro2::Span::call_site()),
-                None,
-            ),
-            _ => panic!("The argument pattern of the first argument is not a simple ident"),
-        },
-        FnArg::Receiver(_) => panic!("The first argument is a receiver"),
-    };
-
-    // get the name of the second argument
-    let ident_second_arg = match &item_fn.sig.inputs[1] {
-        FnArg::Typed(PatType { pat, .. }) => match &**pat {
-            Pat::Ident(pat_ident) => pat_ident.ident.clone(),
-            Pat::Wild(_) => proc_macro2::Ident::new("_args", proc_macro2::Span::call_site()),
-            _ => panic!("The argument pattern of the second argument is not a simple ident"),
-        },
-        FnArg::Receiver(_) => panic!("The second argument is a receiver"),
-    };
-
-    // get the name of the third argument
-    let ident_third_arg = match &item_fn.sig.inputs[2] {
-        FnArg::Typed(PatType { pat, .. }) => match &**pat {
-            Pat::Ident(pat_ident) => pat_ident.ident.clone(),
-            Pat::Wild(_) => proc_macro2::Ident::new("_data", proc_macro2::Span::call_site()),
-            _ => panic!("The argument pattern of the third argument is not a simple ident"),
-        },
-        FnArg::Receiver(_) => panic!("The third argument is a receiver"),
-    };
-
-    // get the type of the third argument
-    let data_arg = item_fn.sig.inputs.last().unwrap().clone();
-    let ty_third_arg = match &data_arg {
-        FnArg::Typed(PatType { ref ty, .. }) => match **ty {
-            syn::Type::Reference(syn::TypeReference { ref elem, .. }) => syn::TypePtr {
-                star_token: parse_quote!(*),
-                const_token: None,
-                mutability: Some(parse_quote!(mut)),
-                elem: elem.clone(),
-            },
-            syn::Type::Path(syn::TypePath { ref path, .. }) => match path.segments.last() {
-                Some(segment) => {
-                    let id = segment.ident.to_string();
-                    match id == "Option" {
-                        true => match segment.arguments {
-                            syn::PathArguments::AngleBracketed(
-                                syn::AngleBracketedGenericArguments { ref args, .. },
-                            ) => {
-                                let last_generic_arg = args.last();
-                                match last_generic_arg {
-                                    Some(arg) => match arg {
-                                        syn::GenericArgument::Type(ty) => match ty {
-                                            syn::Type::Reference(syn::TypeReference {
-                                                ref elem,
-                                                ..
-                                            }) => syn::TypePtr {
-                                                star_token: parse_quote!(*),
-                                                const_token: None,
-                                                mutability: Some(parse_quote!(mut)),
-                                                elem: elem.clone(),
-                                            },
-                                            _ => panic!("Not found syn::Type::Reference"),
-                                        },
-                                        _ => {
-                                            panic!("Not found syn::GenericArgument::Type")
-                                        }
-                                    },
-                                    None => panic!("Not found the last GenericArgument"),
-                                }
-                            }
-                            _ => panic!("Not found syn::PathArguments::AngleBracketed"),
-                        },
-                        false => panic!("Not found segment ident: Option"),
-                    }
-                }
-                None => panic!("Not found path segments"),
-            },
-            _ => panic!("Unsupported syn::Type type"),
-        },
-        _ => panic!("Unsupported syn::FnArg type"),
-    };
-
-    // func body
-    let fn_block = item_fn.block.clone();
-
-    quote!(
-        #wrapper_visibility fn #wrapper_fn_name_ident (#wrapper_fn_inputs) -> Box<(dyn std::future::Future<Output = Result<Vec<WasmValue>, HostFuncError>> + Send)> {
-
-            // create a Caller instance
-            let #mutability #ident_first_arg = Caller::new(frame);
 
-            let #ident_second_arg = args;
-
-            // host context data
-            let #ident_third_arg = unsafe { &mut *(data as #ty_third_arg) };
+    Ok(quote!(
+        #wrapper_visibility fn #wrapper_fn_name_ident #generics (
+            #data_ident: &'data mut #data_ty,
+            _inst: &'inst mut wasmedge_sdk::r#async::AsyncInstance,
+            frame: &'frame mut wasmedge_sdk::CallingFrame,
+            args: Vec<wasmedge_sdk::WasmValue>,
+        ) -> Box<
+            dyn std::future::Future<
+                    Output = Result<Vec<wasmedge_sdk::WasmValue>, wasmedge_sdk::error::CoreError>,
+                > + Send
+                + 'fut,
+        >
+        where
+            'data: 'fut,
+            'inst: 'fut,
+            'frame: 'fut,
+        {
+            // define inner function
+            async fn #inner_fn_name_ident (#inner_fn_inputs) #inner_fn_return #inner_fn_block
 
-            Box::new(async move {
-                #fn_block
-            })
+            Box::new(async move { #returns })
         }
-    )
+    ))
 }
 
 // ================== macros for wasmedge-sys ==================
@@ -508,6 +359,13 @@ pub fn sys_host_function(_attr: TokenStream, item: TokenStream) -> TokenStream {
 }
 
 fn sys_expand_host_func_new(item_fn: &syn::ItemFn) -> syn::Result<proc_macro2::TokenStream> {
+    if let Some(asyncness) = &item_fn.sig.asyncness {
+        return Err(syn::Error::new_spanned(
+            asyncness,
+            "use #[sys_async_host_function] for async functions; #[sys_host_function] only supports synchronous functions",
+        ));
+    }
+
     // * define the signature of wrapper function
     // name of wrapper function
     let wrapper_fn_name_ident = item_fn.sig.ident.clone();
@@ -771,3 +629,251 @@ fn sys_expand_async_wasi_host_func_with_three_args(
         }
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::ToTokens;
+
+    #[test]
+    fn test_expand_host_func() {
+        let item_fn: syn::ItemFn = parse_quote!(
+            pub fn add(_frame: &mut CallingFrame, args: Vec<WasmValue>) -> Result<i32, CoreError> {
+                Ok(args[0].to_i32() + args[1].to_i32())
+            }
+        );
+        let expected = quote! {
+            pub fn add<__Data>(
+                _data: &mut __Data,
+                _inst: &mut wasmedge_sdk::Instance,
+                frame: &mut wasmedge_sdk::CallingFrame,
+                args: Vec<wasmedge_sdk::WasmValue>,
+            ) -> Result<Vec<wasmedge_sdk::WasmValue>, wasmedge_sdk::error::CoreError> {
+                fn inner_add(_frame: &mut CallingFrame, args: Vec<WasmValue>) -> Result<i32, CoreError> {
+                    Ok(args[0].to_i32() + args[1].to_i32())
+                }
+
+                inner_add(frame, args)
+                    .map(wasmedge_sdk::WasmValList::to_wasm_values)
+                    .map_err(Into::into)
+            }
+        };
+        assert_eq!(
+            expand_host_func(&item_fn).unwrap().to_string(),
+            expected.to_string()
+        );
+    }
+
+    #[test]
+    fn test_expand_host_func_with_data() {
+        // the host context data is passed as is
+        let item_fn: syn::ItemFn = parse_quote!(
+            fn count(_: &mut CallingFrame, _: Vec<WasmValue>, data: &mut i64) -> i64 {
+                *data += 1;
+                *data
+            }
+        );
+        let expanded = expand_host_func(&item_fn).unwrap();
+        let wrapper: syn::ItemFn = syn::parse2(expanded.clone()).unwrap();
+        assert!(wrapper.sig.generics.params.is_empty());
+        assert_eq!(
+            wrapper.sig.inputs[0].to_token_stream().to_string(),
+            quote!(data: &mut i64).to_string()
+        );
+        assert!(expanded.to_string().contains(
+            &quote!(Ok(wasmedge_sdk::WasmValList::to_wasm_values(inner_count(
+                frame, args, data
+            ))))
+            .to_string()
+        ));
+
+        // the optional host context data is always passed
+        let item_fn: syn::ItemFn = parse_quote!(
+            fn count(_: &mut CallingFrame, _: Vec<WasmValue>, data: Option<&mut i64>) {}
+        );
+        let expanded = expand_host_func(&item_fn).unwrap().to_string();
+        assert!(expanded.contains(&quote!(data: &mut i64,).to_string()));
+        assert!(expanded.contains(&quote!(inner_count(frame, args, Some(data))).to_string()));
+
+        // the host context data must be a mutable reference
+        let item_fn: syn::ItemFn = parse_quote!(
+            fn count(_: &mut CallingFrame, _: Vec<WasmValue>, data: &i64) {}
+        );
+        let err = expand_host_func(&item_fn).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "the host context data must be taken as `&mut T` or `Option<&mut T>`"
+        );
+    }
+
+    #[test]
+    fn test_expand_host_func_rejects_async() {
+        let item_fn: syn::ItemFn = parse_quote!(
+            async fn add(_frame: &mut CallingFrame, args: Vec<WasmValue>) -> i32 {
+                args[0].to_i32() + args[1].to_i32()
+            }
+        );
+        let err = expand_host_func(&item_fn).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "use #[async_host_function] for async functions; #[host_function] only supports synchronous functions"
+        );
+    }
+
+    #[test]
+    fn test_expand_host_func_with_paren_patterns() {
+        // the patterns are kept by the inner function, which takes the calling frame from the wrapper function
+        let item_fn: syn::ItemFn = parse_quote!(
+            fn weird_pattern((caller): &mut CallingFrame, (args): Vec<WasmValue>) -> i32 {
+                caller.memory_mut(0).is_some() as i32 + args.len() as i32
+            }
+        );
+        let wrapper: syn::ItemFn = syn::parse2(expand_host_func(&item_fn).unwrap()).unwrap();
+        let syn::Stmt::Item(Item::Fn(inner_fn)) = &wrapper.block.stmts[0] else {
+            panic!("the inner function is not defined first");
+        };
+        assert_eq!(
+            inner_fn.sig.inputs.to_token_stream().to_string(),
+            item_fn.sig.inputs.to_token_stream().to_string()
+        );
+        assert!(wrapper
+            .to_token_stream()
+            .to_string()
+            .contains(&quote!(inner_weird_pattern(frame, args)).to_string()));
+
+        let item_fn: syn::ItemFn = parse_quote!(
+            fn weird_pattern((mut caller): &mut CallingFrame, _: Vec<WasmValue>, data: &mut i32) {
+                caller = caller;
+                *data += 1;
+            }
+        );
+        let wrapper: syn::ItemFn = syn::parse2(expand_host_func(&item_fn).unwrap()).unwrap();
+        let syn::Stmt::Item(Item::Fn(inner_fn)) = &wrapper.block.stmts[0] else {
+            panic!("the inner function is not defined first");
+        };
+        assert_eq!(
+            inner_fn.sig.inputs.to_token_stream().to_string(),
+            item_fn.sig.inputs.to_token_stream().to_string()
+        );
+        // the wrapper binds its own arguments, so no pattern is bound as mutable there
+        assert_eq!(
+            wrapper.sig.inputs.to_token_stream().to_string(),
+            quote! {
+                data: &mut i32,
+                _inst: &mut wasmedge_sdk::Instance,
+                frame: &mut wasmedge_sdk::CallingFrame,
+                args: Vec<wasmedge_sdk::WasmValue>,
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn test_expand_async_host_func_with_paren_patterns() {
+        for item_fn in [
+            parse_quote!(
+                async fn weird_pattern((caller): &mut CallingFrame, args: Vec<WasmValue>) -> i32 {
+                    caller.memory_mut(0).is_some() as i32 + args.len() as i32
+                }
+            ),
+            parse_quote!(
+                async fn weird_pattern((mut caller): &mut CallingFrame, (_): Vec<WasmValue>) {
+                    caller = caller;
+                }
+            ),
+        ] {
+            let item_fn: syn::ItemFn = item_fn;
+            let wrapper: syn::ItemFn =
+                syn::parse2(expand_async_host_func(&item_fn).unwrap()).unwrap();
+            let syn::Stmt::Item(Item::Fn(inner_fn)) = &wrapper.block.stmts[0] else {
+                panic!("the inner function is not defined first");
+            };
+            assert!(inner_fn.sig.asyncness.is_some());
+            assert_eq!(
+                inner_fn.sig.inputs.to_token_stream().to_string(),
+                item_fn.sig.inputs.to_token_stream().to_string()
+            );
+            assert!(wrapper
+                .to_token_stream()
+                .to_string()
+                .contains(&quote!(inner_weird_pattern(frame, args).await).to_string()));
+        }
+    }
+
+    #[test]
+    fn test_expand_async_host_func() {
+        let item_fn: syn::ItemFn = parse_quote!(
+            async fn count(
+                _frame: &mut CallingFrame,
+                _args: Vec<WasmValue>,
+                data: &mut i64,
+            ) -> Result<(i64, i32), CoreError> {
+                *data += 1;
+                Ok((*data, 0))
+            }
+        );
+        let expected = quote! {
+            fn count<'data, 'inst, 'frame, 'fut>(
+                data: &'data mut i64,
+                _inst: &'inst mut wasmedge_sdk::r#async::AsyncInstance,
+                frame: &'frame mut wasmedge_sdk::CallingFrame,
+                args: Vec<wasmedge_sdk::WasmValue>,
+            ) -> Box<
+                dyn std::future::Future<
+                        Output = Result<Vec<wasmedge_sdk::WasmValue>, wasmedge_sdk::error::CoreError>,
+                    > + Send
+                    + 'fut,
+            >
+            where
+                'data: 'fut,
+                'inst: 'fut,
+                'frame: 'fut,
+            {
+                async fn inner_count(
+                    _frame: &mut CallingFrame,
+                    _args: Vec<WasmValue>,
+                    data: &mut i64,
+                ) -> Result<(i64, i32), CoreError> {
+                    *data += 1;
+                    Ok((*data, 0))
+                }
+
+                Box::new(async move {
+                    inner_count(frame, args, data)
+                        .await
+                        .map(wasmedge_sdk::WasmValList::to_wasm_values)
+                        .map_err(Into::into)
+                })
+            }
+        };
+        assert_eq!(
+            expand_async_host_func(&item_fn).unwrap().to_string(),
+            expected.to_string()
+        );
+
+        // the wrapper is generic over the host context data not taken by the function
+        let item_fn: syn::ItemFn = parse_quote!(
+            async fn get(_frame: &mut CallingFrame, _args: Vec<WasmValue>) -> i32 {
+                1
+            }
+        );
+        let expanded = expand_async_host_func(&item_fn).unwrap();
+        let wrapper: syn::ItemFn = syn::parse2(expanded.clone()).unwrap();
+        assert_eq!(
+            wrapper.sig.generics.to_token_stream().to_string(),
+            quote!(<'data, 'inst, 'frame, 'fut, __Data>).to_string()
+        );
+        assert_eq!(
+            wrapper.sig.inputs[0].to_token_stream().to_string(),
+            quote!(_data: &'data mut __Data).to_string()
+        );
+        assert!(expanded.to_string().contains(
+            &quote! {
+                Box::new(async move {
+                    Ok(wasmedge_sdk::WasmValList::to_wasm_values(inner_get(frame, args).await))
+                })
+            }
+            .to_string()
+        ));
+    }
+}
@@ -8,11 +8,13 @@ use async_wasi::snapshots::{
         memory::WasmPtr,
         types::{__wasi_ciovec_t, __wasi_size_t},
     },
-    preview_1 as p, WasiCtx,
+    preview_1 as p, CpuTimeCounter, WasiCtx,
 };
 use std::{
     future::Future,
     ops::{Deref, DerefMut},
+    pin::Pin,
+    task::{Context, Poll},
 };
 use wasmedge_types::{
     error::{CoreCommonError, CoreError, CoreExecutionError},
@@ -137,6 +139,26 @@ impl AsyncWasiModule {
     ///
     /// If fail to create [AsyncWasiModule] instance, then an error is returned.
     pub fn create_from_wasi_context(wasi_ctx: WasiCtx) -> WasmEdgeResult<Self> {
+        Self::create_from_wasi_context_with_filter(wasi_ctx, |_| true)
+    }
+
+    /// Creates a [AsyncWasiModule] instance with the given wasi context, in which only the wasi functions accepted by the given filter are implemented.
+    ///
+    /// The rejected wasi functions are still exported by the module with the same signatures, but they return `ENOSYS` directly. Notice that `proc_exit` is always implemented, since it has no return value to report `ENOSYS`.
+    ///
+    /// # Arguments
+    ///
+    /// * `wasi_ctx` - The [WasiCtx](async_wasi::snapshots::WasiCtx) instance.
+    ///
+    /// * `filter` - Returns `true` if the wasi function with the given name is allowed.
+    ///
+    /// # Error
+    ///
+    /// If fail to create [AsyncWasiModule] instance, then an error is returned.
+    pub fn create_from_wasi_context_with_filter(
+        wasi_ctx: WasiCtx,
+        filter: impl Fn(&str) -> bool,
+    ) -> WasmEdgeResult<Self> {
         // create wasi module
         let name = "wasi_snapshot_preview1";
 
@@ -144,6 +166,16 @@ impl AsyncWasiModule {
 
         // add sync/async host functions to the module
         for wasi_func in wasi_impls() {
+            let wasi_func = match wasi_func {
+                WasiFunc::SyncFn(name, (ty_args, ty_rets), _)
+                | WasiFunc::AsyncFn(name, (ty_args, ty_rets), _)
+                    if !ty_rets.is_empty() && !filter(&name) =>
+                {
+                    WasiFunc::SyncFn(name, (ty_args, ty_rets), wasi_func_nosys)
+                }
+                wasi_func => wasi_func,
+            };
+
             match wasi_func {
                 WasiFunc::SyncFn(name, (ty_args, ty_rets), real_fn) => {
                     let func_ty = FuncType::new(ty_args, ty_rets);
@@ -192,6 +224,15 @@ impl AsyncWasiModule {
 
 // ============== wasi host functions ==============
 
+fn wasi_func_nosys(
+    _data: &mut WasiCtx,
+    _inst: &mut Instance,
+    _frame: &mut CallingFrame,
+    _args: Vec<WasmValue>,
+) -> Result<Vec<WasmValue>, CoreError> {
+    Ok(to_wasm_return(Err(Errno::__WASI_ERRNO_NOSYS)))
+}
+
 fn args_get(
     data: &mut WasiCtx,
     _inst: &mut Instance,
@@ -520,6 +561,58 @@ fn fd_close(
     }
 }
 
+fn fd_pipe(
+    data: &mut WasiCtx,
+    _inst: &mut Instance,
+    frame: &mut CallingFrame,
+    args: Vec<WasmValue>,
+) -> Result<Vec<WasmValue>, CoreError> {
+    let mut mem = frame
+        .memory_mut(0)
+        .ok_or(CoreError::Execution(CoreExecutionError::MemoryOutOfBounds))?;
+
+    if let Some([p1, p2]) = args.get(0..2) {
+        let ro_fd0 = p1.to_i32() as usize;
+        let ro_fd1 = p2.to_i32() as usize;
+
+        Ok(to_wasm_return(p::fd_pipe(
+            data,
+            &mut mem as &mut Memory,
+            WasmPtr::from(ro_fd0),
+            WasmPtr::from(ro_fd1),
+        )))
+    } else {
+        Err(CoreError::Execution(CoreExecutionError::FuncSigMismatch))
+    }
+}
+
+fn fd_pipe2(
+    data: &mut WasiCtx,
+    _inst: &mut Instance,
+    frame: &mut CallingFrame,
+    args: Vec<WasmValue>,
+) -> Result<Vec<WasmValue>, CoreError> {
+    let mut mem = frame
+        .memory_mut(0)
+        .ok_or(CoreError::Execution(CoreExecutionError::MemoryOutOfBounds))?;
+
+    if let Some([p1, p2, p3]) = args.get(0..3) {
+        let flags = p1.to_i32() as u16;
+        let ro_fd0 = p2.to_i32() as usize;
+        let ro_fd1 = p3.to_i32() as usize;
+
+        Ok(to_wasm_return(p::fd_pipe2(
+            data,
+            &mut mem as &mut Memory,
+            flags,
+            WasmPtr::from(ro_fd0),
+            WasmPtr::from(ro_fd1),
+        )))
+    } else {
+        Err(CoreError::Execution(CoreExecutionError::FuncSigMismatch))
+    }
+}
+
 fn fd_seek(
     data: &mut WasiCtx,
     _inst: &mut Instance,
@@ -776,9 +869,9 @@ fn fd_filestat_set_times(
     }
 }
 
-fn fd_read(
+async fn fd_read(
     data: &mut WasiCtx,
-    _inst: &mut Instance,
+    _inst: &mut AsyncInstance,
     frame: &mut CallingFrame,
     args: Vec<WasmValue>,
 ) -> Result<Vec<WasmValue>, CoreError> {
@@ -792,14 +885,17 @@ fn fd_read(
         let iovs_len = p3.to_i32() as u32;
         let nread = p4.to_i32() as usize;
 
-        Ok(to_wasm_return(p::fd_read(
-            data,
-            &mut mem as &mut Memory,
-            fd,
-            WasmPtr::from(iovs),
-            iovs_len,
-            WasmPtr::from(nread),
-        )))
+        Ok(to_wasm_return(
+            p::async_fd::fd_read(
+                data,
+                &mut mem as &mut Memory,
+                fd,
+                WasmPtr::from(iovs),
+                iovs_len,
+                WasmPtr::from(nread),
+            )
+            .await,
+        ))
     } else {
         Err(CoreError::Execution(CoreExecutionError::FuncSigMismatch))
     }
@@ -836,9 +932,9 @@ fn fd_pread(
     }
 }
 
-fn fd_write(
+async fn fd_write(
     data: &mut WasiCtx,
-    _inst: &mut Instance,
+    _inst: &mut AsyncInstance,
     frame: &mut CallingFrame,
     args: Vec<WasmValue>,
 ) -> Result<Vec<WasmValue>, CoreError> {
@@ -852,14 +948,17 @@ fn fd_write(
         let iovs_len = p3.to_i32() as u32;
         let nwritten = p4.to_i32() as usize;
 
-        Ok(to_wasm_return(p::fd_write(
-            data,
-            &mut mem as &mut Memory,
-            fd,
-            WasmPtr::from(iovs),
-            iovs_len,
-            WasmPtr::from(nwritten),
-        )))
+        Ok(to_wasm_return(
+            p::async_fd::fd_write(
+                data,
+                &mut mem as &mut Memory,
+                fd,
+                WasmPtr::from(iovs),
+                iovs_len,
+                WasmPtr::from(nwritten),
+            )
+            .await,
+        ))
     } else {
         Err(CoreError::Execution(CoreExecutionError::FuncSigMismatch))
     }
@@ -927,6 +1026,37 @@ fn fd_readdir(
     }
 }
 
+fn fd_readdir_stat(
+    data: &mut WasiCtx,
+    _inst: &mut Instance,
+    frame: &mut CallingFrame,
+    args: Vec<WasmValue>,
+) -> Result<Vec<WasmValue>, CoreError> {
+    let mut mem = frame
+        .memory_mut(0)
+        .ok_or(CoreError::Execution(CoreExecutionError::MemoryOutOfBounds))?;
+
+    if let Some([p1, p2, p3, p4, p5]) = args.get(0..5) {
+        let fd = p1.to_i32();
+        let buf = p2.to_i32() as usize;
+        let buf_len = p3.to_i32() as u32;
+        let cookie = p4.to_i64() as u64;
+        let bufused_ptr = p5.to_i32() as usize;
+
+        Ok(to_wasm_return(p::fd_readdir_stat(
+            data,
+            &mut mem as &mut Memory,
+            fd,
+            WasmPtr::from(buf),
+            buf_len,
+            cookie,
+            WasmPtr::from(bufused_ptr),
+        )))
+    } else {
+        Err(CoreError::Execution(CoreExecutionError::FuncSigMismatch))
+    }
+}
+
 fn path_create_directory(
     data: &mut WasiCtx,
     _inst: &mut Instance,
@@ -986,14 +1116,38 @@ fn path_filestat_get(
 }
 
 fn path_filestat_set_times(
-    _data: &mut WasiCtx,
+    data: &mut WasiCtx,
     _inst: &mut Instance,
-    _frame: &mut CallingFrame,
-    _args: Vec<WasmValue>,
+    frame: &mut CallingFrame,
+    args: Vec<WasmValue>,
 ) -> Result<Vec<WasmValue>, CoreError> {
-    Ok(vec![WasmValue::from_i32(
-        Errno::__WASI_ERRNO_NOSYS.0 as i32,
-    )])
+    let mut mem = frame
+        .memory_mut(0)
+        .ok_or(CoreError::Execution(CoreExecutionError::MemoryOutOfBounds))?;
+
+    if let Some([p1, p2, p3, p4, p5, p6, p7]) = args.get(0..7) {
+        let fd = p1.to_i32();
+        let flags = p2.to_i32() as u32;
+        let path_ptr = p3.to_i32() as usize;
+        let path_len = p4.to_i32() as u32;
+        let st_atim = p5.to_i64() as u64;
+        let st_mtim = p6.to_i64() as u64;
+        let fst_flags = p7.to_i32() as u16;
+
+        Ok(to_wasm_return(p::path_filestat_set_times(
+            data,
+            &mut mem as &mut Memory,
+            fd,
+            flags,
+            WasmPtr::from(path_ptr),
+            path_len,
+            st_atim,
+            st_mtim,
+            fst_flags,
+        )))
+    } else {
+        Err(CoreError::Execution(CoreExecutionError::FuncSigMismatch))
+    }
 }
 
 fn path_link(
@@ -1047,14 +1201,36 @@ fn path_open(
 }
 
 fn path_readlink(
-    _data: &mut WasiCtx,
+    data: &mut WasiCtx,
     _inst: &mut Instance,
-    _frame: &mut CallingFrame,
-    _args: Vec<WasmValue>,
+    frame: &mut CallingFrame,
+    args: Vec<WasmValue>,
 ) -> Result<Vec<WasmValue>, CoreError> {
-    Ok(vec![WasmValue::from_i32(
-        Errno::__WASI_ERRNO_NOSYS.0 as i32,
-    )])
+    let mut mem = frame
+        .memory_mut(0)
+        .ok_or(CoreError::Execution(CoreExecutionError::MemoryOutOfBounds))?;
+
+    if let Some([p1, p2, p3, p4, p5, p6]) = args.get(0..6) {
+        let dir_fd = p1.to_i32();
+        let path_ptr = p2.to_i32() as usize;
+        let path_len = p3.to_i32() as u32;
+        let buf = p4.to_i32() as usize;
+        let buf_len = p5.to_i32() as u32;
+        let buf_used = p6.to_i32() as usize;
+
+        Ok(to_wasm_return(p::path_readlink(
+            data,
+            &mut mem as &mut Memory,
+            dir_fd,
+            WasmPtr::from(path_ptr),
+            path_len,
+            WasmPtr::from(buf),
+            buf_len,
+            WasmPtr::from(buf_used),
+        )))
+    } else {
+        Err(CoreError::Execution(CoreExecutionError::FuncSigMismatch))
+    }
 }
 
 fn path_remove_directory(
@@ -1118,14 +1294,34 @@ fn path_rename(
 }
 
 fn path_symlink(
-    _data: &mut WasiCtx,
+    data: &mut WasiCtx,
     _inst: &mut Instance,
-    _frame: &mut CallingFrame,
-    _args: Vec<WasmValue>,
+    frame: &mut CallingFrame,
+    args: Vec<WasmValue>,
 ) -> Result<Vec<WasmValue>, CoreError> {
-    Ok(vec![WasmValue::from_i32(
-        Errno::__WASI_ERRNO_NOSYS.0 as i32,
-    )])
+    let mem = frame
+        .memory_mut(0)
+        .ok_or(CoreError::Execution(CoreExecutionError::MemoryOutOfBounds))?;
+
+    if let Some([p1, p2, p3, p4, p5]) = args.get(0..5) {
+        let old_path = p1.to_i32() as usize;
+        let old_path_len = p2.to_i32() as u32;
+        let fd = p3.to_i32();
+        let new_path = p4.to_i32() as usize;
+        let new_path_len = p5.to_i32() as u32;
+
+        Ok(to_wasm_return(p::path_symlink(
+            data,
+            &mem as &Memory,
+            WasmPtr::from(old_path),
+            old_path_len,
+            fd,
+            WasmPtr::from(new_path),
+            new_path_len,
+        )))
+    } else {
+        Err(CoreError::Execution(CoreExecutionError::FuncSigMismatch))
+    }
 }
 
 fn path_unlink_file(
@@ -1565,6 +1761,32 @@ fn sock_getlocaladdr(
     }
 }
 
+fn sock_getsockname(
+    data: &mut WasiCtx,
+    _inst: &mut Instance,
+    frame: &mut CallingFrame,
+    args: Vec<WasmValue>,
+) -> Result<Vec<WasmValue>, CoreError> {
+    let mut mem = frame
+        .memory_mut(0)
+        .ok_or(CoreError::Execution(CoreExecutionError::MemoryOutOfBounds))?;
+
+    if let Some([p1, p2, p3]) = args.get(0..3) {
+        let fd = p1.to_i32();
+        let wasi_addr_ptr = p2.to_i32() as usize;
+        let addr_len_ptr = p3.to_i32() as usize;
+        Ok(to_wasm_return(p::async_socket::sock_getsockname(
+            data,
+            &mut mem as &mut Memory,
+            fd,
+            WasmPtr::from(wasi_addr_ptr),
+            WasmPtr::from(addr_len_ptr),
+        )))
+    } else {
+        Err(CoreError::Execution(CoreExecutionError::FuncSigMismatch))
+    }
+}
+
 fn sock_getsockopt(
     data: &mut WasiCtx,
     _inst: &mut Instance,
@@ -1788,6 +2010,34 @@ where
     box_future::<Data, Fut, F>
 }
 
+/// Returns the CPU time consumed by the current thread in nanoseconds.
+fn thread_cpu_ns() -> u64 {
+    let mut ts = libc::timespec {
+        tv_sec: 0,
+        tv_nsec: 0,
+    };
+    unsafe { libc::clock_gettime(libc::CLOCK_THREAD_CPUTIME_ID, &mut ts) };
+    (ts.tv_sec as u64)
+        .saturating_mul(1_000_000_000)
+        .saturating_add(ts.tv_nsec as u64)
+}
+
+/// A future of an asynchronous host function, which records the CPU time consumed by each poll, so the time spent waiting for I/O is not counted.
+struct CpuTimed<'fut> {
+    inner: Pin<Box<dyn Future<Output = Result<Vec<WasmValue>, CoreError>> + Send + 'fut>>,
+    counter: CpuTimeCounter,
+}
+impl Future for CpuTimed<'_> {
+    type Output = Result<Vec<WasmValue>, CoreError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let start = thread_cpu_ns();
+        let result = self.inner.as_mut().poll(cx);
+        self.counter.add(thread_cpu_ns().saturating_sub(start));
+        result
+    }
+}
+
 enum WasiFunc<'data, 'inst, 'frame, 'fut, T: Sized>
 where
     'data: 'fut,
@@ -1803,15 +2053,45 @@ where
 }
 
 fn wasi_impls<'data, 'inst, 'frame, 'fut>() -> Vec<WasiFunc<'data, 'inst, 'frame, 'fut, WasiCtx>> {
+    // the wrappers record the CPU time consumed by the host functions into the wasi context
     macro_rules! sync_fn {
-        ($name:expr, $ty:expr, $f:ident) => {
-            WasiFunc::SyncFn($name.into(), $ty, $f)
-        };
+        ($name:expr, $ty:expr, $f:ident) => {{
+            fn timed(
+                data: &mut WasiCtx,
+                inst: &mut Instance,
+                frame: &mut CallingFrame,
+                args: Vec<WasmValue>,
+            ) -> Result<Vec<WasmValue>, CoreError> {
+                let start = thread_cpu_ns();
+                let result = $f(data, inst, frame, args);
+                data.add_consumed_cpu_ns(thread_cpu_ns().saturating_sub(start));
+                result
+            }
+            WasiFunc::SyncFn($name.into(), $ty, timed)
+        }};
     }
     macro_rules! async_fn {
-        ($name:expr, $ty:expr, $f:expr) => {
-            WasiFunc::AsyncFn($name.into(), $ty, $f)
-        };
+        ($name:expr, $ty:expr, $f:expr) => {{
+            fn timed<'data, 'inst, 'frame, 'fut>(
+                data: &'data mut WasiCtx,
+                inst: &'inst mut AsyncInstance,
+                frame: &'frame mut CallingFrame,
+                args: Vec<WasmValue>,
+            ) -> Box<dyn Future<Output = Result<Vec<WasmValue>, CoreError>> + Send + 'fut>
+            where
+                'data: 'fut,
+                'inst: 'fut,
+                'frame: 'fut,
+            {
+                let counter = data.cpu_time_counter();
+                let f: AsyncFn<'data, 'inst, 'frame, 'fut, WasiCtx> = $f;
+                Box::new(CpuTimed {
+                    inner: Box::into_pin(f(data, inst, frame, args)),
+                    counter,
+                })
+            }
+            WasiFunc::AsyncFn($name.into(), $ty, timed)
+        }};
     }
     vec![
         sync_fn!(
@@ -1891,6 +2171,19 @@ fn wasi_impls<'data, 'inst, 'frame, 'fut>() -> Vec<WasiFunc<'data, 'inst, 'frame
             (vec![ValType::I32], vec![ValType::I32]),
             fd_close
         ),
+        sync_fn!(
+            "__wasmedge_fd_pipe",
+            (vec![ValType::I32, ValType::I32], vec![ValType::I32]),
+            fd_pipe
+        ),
+        sync_fn!(
+            "__wasmedge_fd_pipe2",
+            (
+                vec![ValType::I32, ValType::I32, ValType::I32],
+                vec![ValType::I32],
+            ),
+            fd_pipe2
+        ),
         sync_fn!(
             "fd_seek",
             (
@@ -1946,13 +2239,13 @@ fn wasi_impls<'data, 'inst, 'frame, 'fut>() -> Vec<WasiFunc<'data, 'inst, 'frame
             ),
             fd_filestat_set_times
         ),
-        sync_fn!(
+        async_fn!(
             "fd_read",
             (
                 vec![ValType::I32, ValType::I32, ValType::I32, ValType::I32],
                 vec![ValType::I32],
             ),
-            fd_read
+            wrap_future(fd_read)
         ),
         sync_fn!(
             "fd_pread",
@@ -1968,13 +2261,13 @@ fn wasi_impls<'data, 'inst, 'frame, 'fut>() -> Vec<WasiFunc<'data, 'inst, 'frame
             ),
             fd_pread
         ),
-        sync_fn!(
+        async_fn!(
             "fd_write",
             (
                 vec![ValType::I32, ValType::I32, ValType::I32, ValType::I32],
                 vec![ValType::I32],
             ),
-            fd_write
+            wrap_future(fd_write)
         ),
         sync_fn!(
             "fd_pwrite",
@@ -2004,6 +2297,20 @@ fn wasi_impls<'data, 'inst, 'frame, 'fut>() -> Vec<WasiFunc<'data, 'inst, 'frame
             ),
             fd_readdir
         ),
+        sync_fn!(
+            "__wasmedge_fd_readdir_stat",
+            (
+                vec![
+                    ValType::I32,
+                    ValType::I32,
+                    ValType::I32,
+                    ValType::I64,
+                    ValType::I32,
+                ],
+                vec![ValType::I32],
+            ),
+            fd_readdir_stat
+        ),
         sync_fn!(
             "path_create_directory",
             (
@@ -2260,6 +2567,14 @@ fn wasi_impls<'data, 'inst, 'frame, 'fut>() -> Vec<WasiFunc<'data, 'inst, 'frame
             ),
             sock_getlocaladdr
         ),
+        sync_fn!(
+            "sock_getsockname",
+            (
+                vec![ValType::I32, ValType::I32, ValType::I32],
+                vec![ValType::I32],
+            ),
+            sock_getsockname
+        ),
         sync_fn!(
             "sock_getsockopt",
             (
@@ -2487,6 +2802,9 @@ mod tests {
             .call_func_async(&async_state, &mut fn_start, [])
             .await?;
 
+        // the program writes to the stdout by the asynchronous fd_write
+        assert!(async_wasi_module.0.get_host_data().consumed_cpu_ns() > 0);
+
         Ok(())
     }
 }
@@ -1,7 +1,7 @@
 //! Defines WasmEdge Config struct.
 
 use crate::{ffi, WasmEdgeResult};
-use wasmedge_types::error::WasmEdgeError;
+use wasmedge_types::{error::WasmEdgeError, ProposalName};
 #[cfg(feature = "aot")]
 use wasmedge_types::{CompilerOptimizationLevel, CompilerOutputFormat};
 
@@ -311,6 +311,24 @@ impl Config {
         }
     }
 
+    /// Enables a WebAssembly proposal.
+    ///
+    /// # Argument
+    ///
+    /// * `proposal` - The proposal to enable.
+    pub fn add_proposal(&mut self, proposal: ProposalName) {
+        unsafe { ffi::WasmEdge_ConfigureAddProposal(self.inner.0, proposal_raw(proposal)) }
+    }
+
+    /// Checks if a WebAssembly proposal is enabled or not.
+    ///
+    /// # Argument
+    ///
+    /// * `proposal` - The proposal to check.
+    pub fn has_proposal(&self, proposal: ProposalName) -> bool {
+        unsafe { ffi::WasmEdge_ConfigureHasProposal(self.inner.0, proposal_raw(proposal)) }
+    }
+
     /// Enables or disables the ReferenceTypes option. By default, the option is enabled.
     ///
     /// # Argument
@@ -556,6 +574,8 @@ impl Config {
 
     /// Enables or disables the `ForceInterpreter` option. By default, the option is disabled.
     ///
+    /// If the option turns on, the loaded AOT-compiled native code is ignored and the functions are executed by the interpreter.
+    ///
     /// # Argument
     ///
     /// * `enable` - Whether the option turns on or not.
@@ -744,6 +764,31 @@ impl Config {
     }
 }
 
+fn proposal_raw(proposal: ProposalName) -> ffi::WasmEdge_Proposal {
+    match proposal {
+        ProposalName::ImportExportMutGlobals => ffi::WasmEdge_Proposal_ImportExportMutGlobals,
+        ProposalName::NonTrapFloatToIntConversions => {
+            ffi::WasmEdge_Proposal_NonTrapFloatToIntConversions
+        }
+        ProposalName::SignExtensionOperators => ffi::WasmEdge_Proposal_SignExtensionOperators,
+        ProposalName::MultiValue => ffi::WasmEdge_Proposal_MultiValue,
+        ProposalName::BulkMemoryOperations => ffi::WasmEdge_Proposal_BulkMemoryOperations,
+        ProposalName::ReferenceTypes => ffi::WasmEdge_Proposal_ReferenceTypes,
+        ProposalName::Simd => ffi::WasmEdge_Proposal_SIMD,
+        ProposalName::TailCall => ffi::WasmEdge_Proposal_TailCall,
+        ProposalName::ExtendedConst => ffi::WasmEdge_Proposal_ExtendedConst,
+        ProposalName::FunctionReferences => ffi::WasmEdge_Proposal_FunctionReferences,
+        ProposalName::GC => ffi::WasmEdge_Proposal_GC,
+        ProposalName::MultiMemories => ffi::WasmEdge_Proposal_MultiMemories,
+        ProposalName::Threads => ffi::WasmEdge_Proposal_Threads,
+        ProposalName::RelaxedSimd => ffi::WasmEdge_Proposal_RelaxSIMD,
+        ProposalName::Annotations => ffi::WasmEdge_Proposal_Annotations,
+        ProposalName::Memory64 => ffi::WasmEdge_Proposal_Memory64,
+        ProposalName::ExceptionHandling => ffi::WasmEdge_Proposal_ExceptionHandling,
+        ProposalName::Component => ffi::WasmEdge_Proposal_Component,
+    }
+}
+
 #[derive(Debug)]
 pub(crate) struct InnerConfig(pub(crate) *mut ffi::WasmEdge_ConfigureContext);
 unsafe impl Send for InnerConfig {}
@@ -886,6 +931,12 @@ mod tests {
             config.get_aot_compiler_output_format(),
             CompilerOutputFormat::Native,
         );
+
+        // enable a proposal by its name
+        assert!(!config.has_proposal(ProposalName::ExtendedConst));
+        config.add_proposal(ProposalName::ExtendedConst);
+        assert!(config.has_proposal(ProposalName::ExtendedConst));
+        assert!(config.has_proposal(ProposalName::Memory64));
     }
 
     #[test]
@@ -16,6 +16,10 @@ use crate::{
 };
 #[cfg(all(target_os = "linux", not(target_env = "musl")))]
 use std::os::raw::c_void;
+use std::sync::{
+    atomic::{AtomicBool, AtomicU64, Ordering},
+    Arc,
+};
 use wasmedge_types::error::WasmEdgeError;
 
 #[cfg(all(target_os = "linux", not(target_env = "musl")))]
@@ -86,9 +90,18 @@ pub(crate) unsafe fn init_signal_listen() {
 }
 
 /// Defines an execution environment for both pure WASM and compiled WASM.
+///
+/// # Thread Safety
+///
+/// [Executor] is `Send` and `Sync`, so it can be moved to another thread or shared across threads and async tasks, for example, by `Arc<Executor>`. The methods running wasm functions take `&mut self`, therefore, running functions concurrently on a shared [Executor] still requires a synchronization primitive, such as `Arc<Mutex<Executor>>`.
 #[derive(Debug)]
 pub struct Executor {
     pub(crate) inner: InnerExecutor,
+    stat: Option<Statistics>,
+    // the instruction count of the statistics before the last call
+    instr_count_before_call: AtomicU64,
+    // the total cost of the statistics before the last call
+    cost_before_call: AtomicU64,
 }
 
 impl Drop for Executor {
@@ -114,6 +127,7 @@ impl Executor {
             .map(|cfg| cfg.inner.0)
             .unwrap_or(std::ptr::null_mut());
         let stat_ctx = stat
+            .as_ref()
             .map(|stat| stat.inner.0)
             .unwrap_or(std::ptr::null_mut());
 
@@ -138,12 +152,54 @@ impl Executor {
 
             Ok(Executor {
                 inner: InnerExecutor(ctx),
+                stat,
+                instr_count_before_call: AtomicU64::new(0),
+                cost_before_call: AtomicU64::new(0),
             })
         }
     }
 }
 
 impl Executor {
+    /// Returns the count of the instructions executed by the last call of this [executor](crate::Executor), which is read from the [statistics](crate::Statistics) given when the executor is created.
+    ///
+    /// Notice that the instructions are counted only if the instruction counting option of the [config](crate::Config) is enabled.
+    ///
+    /// Returns `None` if the executor is created without [statistics](crate::Statistics).
+    pub fn fuel_consumed(&self) -> Option<u64> {
+        let stat = self.stat.as_ref()?;
+        let before = self.instr_count_before_call.load(Ordering::Relaxed);
+        Some(stat.instr_count().saturating_sub(before))
+    }
+
+    /// Returns the cost charged by the last call of this [executor](crate::Executor), which is read from the [statistics](crate::Statistics) given when the executor is created. The cost sums the costs of the executed instructions and the costs of the called [host functions](crate::Function), which are assigned when the host functions are created.
+    ///
+    /// Notice that the costs are measured only if the cost measuring option of the [config](crate::Config) is enabled.
+    ///
+    /// Returns `None` if the executor is created without [statistics](crate::Statistics).
+    pub fn last_call_cost(&self) -> Option<u64> {
+        let stat = self.stat.as_ref()?;
+        let before = self.cost_before_call.load(Ordering::Relaxed);
+        Some(stat.cost_in_total().saturating_sub(before))
+    }
+
+    /// Returns a mutable reference to the [statistics](crate::Statistics) given when the executor is created, by which the cost table and the cost limit can be updated between calls.
+    ///
+    /// Returns `None` if the executor is created without [statistics](crate::Statistics).
+    pub fn statistics_mut(&mut self) -> Option<&mut Statistics> {
+        self.stat.as_mut()
+    }
+
+    /// Records the instruction count and the total cost of the statistics before a call.
+    fn start_call(&self) {
+        if let Some(stat) = self.stat.as_ref() {
+            self.instr_count_before_call
+                .store(stat.instr_count(), Ordering::Relaxed);
+            self.cost_before_call
+                .store(stat.cost_in_total(), Ordering::Relaxed);
+        }
+    }
+
     /// Runs a host function instance and returns the results.
     ///
     /// # Arguments
@@ -159,6 +215,16 @@ impl Executor {
         &mut self,
         func: &mut Function,
         params: impl IntoIterator<Item = WasmValue>,
+    ) -> WasmEdgeResult<Vec<WasmValue>> {
+        self.start_call();
+        self.invoke_func(func, params)
+    }
+
+    // invokes the function without resetting the counters of the last call
+    fn invoke_func(
+        &mut self,
+        func: &mut Function,
+        params: impl IntoIterator<Item = WasmValue>,
     ) -> WasmEdgeResult<Vec<WasmValue>> {
         let raw_params = params.into_iter().map(|x| x.as_raw()).collect::<Vec<_>>();
 
@@ -187,6 +253,8 @@ impl Executor {
 
     /// Run a host function instance and return the results or timeout.
     ///
+    /// The timeout is signal-based on Linux with glibc. On the other targets, such as musl, the function is run on a separate thread, which is cancelled when the timeout expires.
+    ///
     /// # Arguments
     ///
     /// * `func` - The function instance to run.
@@ -197,11 +265,10 @@ impl Executor {
     ///
     /// # Errors
     ///
-    /// If fail to run the host function, then an error is returned.
+    /// If the timeout expires before the function returns, then [WasmEdgeError::ExecuteTimeout](wasmedge_types::error::WasmEdgeError) is returned. If fail to run the host function, then an error is returned.
     #[cfg(all(target_os = "linux", not(target_env = "musl")))]
-    #[cfg_attr(docsrs, doc(cfg(all(target_os = "linux", not(target_env = "musl")))))]
     pub fn call_func_with_timeout(
-        &self,
+        &mut self,
         func: &mut Function,
         params: impl IntoIterator<Item = WasmValue>,
         timeout: std::time::Duration,
@@ -216,6 +283,7 @@ impl Executor {
         let returns_len = func_ty.returns_len();
         let mut returns = Vec::with_capacity(returns_len);
 
+        self.start_call();
         unsafe {
             init_signal_listen();
             let mut self_thread = libc::pthread_self();
@@ -267,6 +335,128 @@ impl Executor {
         }
     }
 
+    /// Run a host function instance and return the results or timeout.
+    ///
+    /// The timeout is signal-based on Linux with glibc. On the other targets, such as musl, the function is run on a separate thread, which is cancelled when the timeout expires.
+    ///
+    /// # Arguments
+    ///
+    /// * `func` - The function instance to run.
+    ///
+    /// * `params` - The arguments to pass to the function.
+    ///
+    /// * `timeout` - The maximum execution time of the function to be run.
+    ///
+    /// # Errors
+    ///
+    /// If the timeout expires before the function returns, then [WasmEdgeError::ExecuteTimeout](wasmedge_types::error::WasmEdgeError) is returned. If fail to run the host function, then an error is returned.
+    #[cfg(not(all(target_os = "linux", not(target_env = "musl"))))]
+    pub fn call_func_with_timeout(
+        &mut self,
+        func: &mut Function,
+        params: impl IntoIterator<Item = WasmValue>,
+        timeout: std::time::Duration,
+    ) -> WasmEdgeResult<Vec<WasmValue>> {
+        let raw_params = params.into_iter().map(|x| x.as_raw()).collect::<Vec<_>>();
+
+        self.start_call();
+        unsafe {
+            let async_ctx = ffi::WasmEdge_ExecutorAsyncInvoke(
+                self.inner.0,
+                func.get_func_raw(),
+                raw_params.as_ptr(),
+                raw_params.len() as u32,
+            );
+            if async_ctx.is_null() {
+                return Err(Box::new(WasmEdgeError::Operation(
+                    "fail to invoke the function asynchronously".into(),
+                )));
+            }
+
+            let millis = u64::try_from(timeout.as_millis()).unwrap_or(u64::MAX);
+            if !ffi::WasmEdge_AsyncWaitFor(async_ctx, millis) {
+                ffi::WasmEdge_AsyncCancel(async_ctx);
+                ffi::WasmEdge_AsyncWait(async_ctx);
+                ffi::WasmEdge_AsyncDelete(async_ctx);
+                return Err(Box::new(WasmEdgeError::ExecuteTimeout));
+            }
+
+            let returns_len = ffi::WasmEdge_AsyncGetReturnsLength(async_ctx) as usize;
+            let mut returns = Vec::with_capacity(returns_len);
+            let result = check(ffi::WasmEdge_AsyncGet(
+                async_ctx,
+                returns.as_mut_ptr(),
+                returns_len as u32,
+            ));
+            ffi::WasmEdge_AsyncDelete(async_ctx);
+            result?;
+
+            returns.set_len(returns_len);
+            Ok(returns.into_iter().map(Into::into).collect::<Vec<_>>())
+        }
+    }
+
+    /// Runs a host function instance, which can be cancelled from another thread by the given [CancellationToken], and returns the results.
+    ///
+    /// # Arguments
+    ///
+    /// * `func` - The function instance to run.
+    ///
+    /// * `params` - The arguments to pass to the function.
+    ///
+    /// * `token` - The token to cancel the call.
+    ///
+    /// # Errors
+    ///
+    /// If the token is cancelled before the function returns, then [WasmEdgeError::Cancelled](wasmedge_types::error::WasmEdgeError) is returned. If fail to run the host function, then an error is returned.
+    pub fn call_func_with_cancellation(
+        &mut self,
+        func: &mut Function,
+        params: impl IntoIterator<Item = WasmValue>,
+        token: &CancellationToken,
+    ) -> WasmEdgeResult<Vec<WasmValue>> {
+        if token.is_cancelled() {
+            return Err(Box::new(WasmEdgeError::Cancelled));
+        }
+        let raw_params = params.into_iter().map(|x| x.as_raw()).collect::<Vec<_>>();
+
+        self.start_call();
+        unsafe {
+            let async_ctx = ffi::WasmEdge_ExecutorAsyncInvoke(
+                self.inner.0,
+                func.get_func_raw(),
+                raw_params.as_ptr(),
+                raw_params.len() as u32,
+            );
+            if async_ctx.is_null() {
+                return Err(Box::new(WasmEdgeError::Operation(
+                    "fail to invoke the function asynchronously".into(),
+                )));
+            }
+
+            token.track(async_ctx);
+            ffi::WasmEdge_AsyncWait(async_ctx);
+            token.untrack(async_ctx);
+
+            let returns_len = ffi::WasmEdge_AsyncGetReturnsLength(async_ctx) as usize;
+            let mut returns = Vec::with_capacity(returns_len);
+            let result = check(ffi::WasmEdge_AsyncGet(
+                async_ctx,
+                returns.as_mut_ptr(),
+                returns_len as u32,
+            ));
+            ffi::WasmEdge_AsyncDelete(async_ctx);
+            match result {
+                Err(_) if token.is_cancelled() => Err(Box::new(WasmEdgeError::Cancelled)),
+                Err(e) => Err(e),
+                Ok(()) => {
+                    returns.set_len(returns_len);
+                    Ok(returns.into_iter().map(Into::into).collect::<Vec<_>>())
+                }
+            }
+        }
+    }
+
     /// Asynchronously runs a host function instance and returns the results.
     ///
     /// # Arguments
@@ -288,7 +478,8 @@ impl Executor {
         func: &mut Function,
         params: impl IntoIterator<Item = WasmValue> + Send,
     ) -> WasmEdgeResult<Vec<WasmValue>> {
-        FiberFuture::on_fiber(async_state, || self.call_func(func, params))
+        self.start_call();
+        FiberFuture::on_fiber(async_state, || self.invoke_func(func, params))
             .await
             .unwrap()
     }
@@ -322,7 +513,8 @@ impl Executor {
     ) -> WasmEdgeResult<Vec<WasmValue>> {
         use wasmedge_types::error;
         let ldd = std::time::SystemTime::now() + timeout;
-        TimeoutFiberFuture::on_fiber(async_state, || self.call_func(func, params), ldd)
+        self.start_call();
+        TimeoutFiberFuture::on_fiber(async_state, || self.invoke_func(func, params), ldd)
             .await
             .map_err(|_| Box::new(error::WasmEdgeError::ExecuteTimeout))?
     }
@@ -342,6 +534,16 @@ impl Executor {
         &mut self,
         func_ref: &mut FuncRef,
         params: impl IntoIterator<Item = WasmValue>,
+    ) -> WasmEdgeResult<Vec<WasmValue>> {
+        self.start_call();
+        self.invoke_func_ref(func_ref, params)
+    }
+
+    // invokes the function reference without resetting the counters of the last call
+    fn invoke_func_ref<FuncRef: AsFunc>(
+        &mut self,
+        func_ref: &mut FuncRef,
+        params: impl IntoIterator<Item = WasmValue>,
     ) -> WasmEdgeResult<Vec<WasmValue>> {
         let raw_params = params.into_iter().map(|x| x.as_raw()).collect::<Vec<_>>();
 
@@ -386,7 +588,8 @@ impl Executor {
         func_ref: &mut FuncRef,
         params: impl IntoIterator<Item = WasmValue> + Send,
     ) -> WasmEdgeResult<Vec<WasmValue>> {
-        FiberFuture::on_fiber(async_state, || self.call_func_ref(func_ref, params))
+        self.start_call();
+        FiberFuture::on_fiber(async_state, || self.invoke_func_ref(func_ref, params))
             .await
             .unwrap()
     }
@@ -464,6 +667,7 @@ impl Executor {
     ///
     /// Notice that when a new module is instantiated into the [store](crate::Store), the old instantiated module is removed; in addition, ensure that the [imports](crate::ImportModule) the module depends on are already registered into the [store](crate::Store).
     ///
+    /// The names and the types of the exported functions of the returned instance can be fetched at once by [export_types](crate::AsInstance::export_types).
     ///
     /// # Arguments
     ///
@@ -497,7 +701,201 @@ impl Executor {
     }
 }
 
+/// A [CancellationToken] cancels the function calls run with it by [Executor::call_func_with_cancellation], for example, from another thread when a deadline expires.
+///
+/// The clones of a token share the same state. Once cancelled, a token stays cancelled, and the later calls run with it fail immediately, so a new token is needed for each cancellable task.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    inner: Arc<CancellationState>,
+}
+impl CancellationToken {
+    /// Creates a new [CancellationToken].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels the running calls and the later calls run with this token.
+    pub fn cancel(&self) {
+        let running = self.inner.running.lock();
+        self.inner.cancelled.store(true, Ordering::SeqCst);
+        for call in running.iter() {
+            unsafe { ffi::WasmEdge_AsyncCancel(call.0) };
+        }
+    }
+
+    /// Checks if this token is cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Tracks a running call, which is cancelled at once if the token is already cancelled.
+    fn track(&self, async_ctx: *mut ffi::WasmEdge_Async) {
+        let mut running = self.inner.running.lock();
+        if self.is_cancelled() {
+            unsafe { ffi::WasmEdge_AsyncCancel(async_ctx) };
+        }
+        running.push(AsyncCall(async_ctx));
+    }
+
+    /// Stops tracking a finished call before it is deleted.
+    fn untrack(&self, async_ctx: *mut ffi::WasmEdge_Async) {
+        self.inner.running.lock().retain(|call| call.0 != async_ctx);
+    }
+}
+
+#[derive(Debug, Default)]
+struct CancellationState {
+    cancelled: AtomicBool,
+    running: parking_lot::Mutex<Vec<AsyncCall>>,
+}
+
+#[derive(Debug)]
+struct AsyncCall(*mut ffi::WasmEdge_Async);
+unsafe impl Send for AsyncCall {}
+
 #[derive(Debug, Clone)]
 pub(crate) struct InnerExecutor(pub(crate) *mut ffi::WasmEdge_ExecutorContext);
+// SAFETY: The executor context of WasmEdge is not bound to the thread creating it. Every method
+// of `Executor` passing the context to the C API, including all the methods invoking functions
+// and registering modules, takes `&mut self`, so the borrow checker serializes the uses of the
+// context. The methods taking `&self` only read the statistics and the atomic counters.
 unsafe impl Send for InnerExecutor {}
 unsafe impl Sync for InnerExecutor {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        sync::{Arc, Mutex},
+        thread,
+    };
+
+    #[test]
+    #[allow(clippy::assertions_on_result_states)]
+    fn test_executor_send() {
+        let result = Executor::create(None, None);
+        assert!(result.is_ok());
+        let executor = result.unwrap();
+
+        let handle = thread::spawn(move || {
+            assert!(!executor.inner.0.is_null());
+            println!("{:?}", executor.inner);
+        });
+
+        handle.join().unwrap();
+    }
+
+    #[test]
+    #[allow(clippy::assertions_on_result_states)]
+    fn test_executor_sync() {
+        let result = Executor::create(None, None);
+        assert!(result.is_ok());
+        let executor = Arc::new(result.unwrap());
+
+        // share the executor without a lock
+        let executor_cloned = Arc::clone(&executor);
+        let handle = thread::spawn(move || {
+            assert!(!executor_cloned.inner.0.is_null());
+        });
+        handle.join().unwrap();
+
+        // share the executor with a lock for the mutable accesses
+        let executor = Arc::new(Mutex::new(Executor::create(None, None).unwrap()));
+        let executor_cloned = Arc::clone(&executor);
+        let handle = thread::spawn(move || {
+            let result = executor_cloned.lock();
+            assert!(result.is_ok());
+            let executor = result.unwrap();
+            assert!(!executor.inner.0.is_null());
+        });
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn test_executor_fuel_consumed() {
+        // create an executor without statistics
+        let executor = Executor::create(None, None).unwrap();
+        assert_eq!(executor.fuel_consumed(), None);
+
+        // create an executor with statistics
+        let mut config = Config::create().unwrap();
+        config.count_instructions(true);
+        let stat = Statistics::create().unwrap();
+        let executor = Executor::create(Some(&config), Some(stat)).unwrap();
+        assert_eq!(executor.fuel_consumed(), Some(0));
+    }
+
+    #[cfg(all(feature = "async", target_os = "linux"))]
+    #[tokio::test]
+    async fn test_executor_fuel_consumed_async() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::{AsInstance, Loader, Store, Validator};
+
+        let mut config = Config::create()?;
+        config.count_instructions(true);
+        let stat = Statistics::create()?;
+        let mut executor = Executor::create(Some(&config), Some(stat))?;
+        let mut store = Store::create()?;
+
+        let wasm = wat::parse_str(
+            r#"
+            (module
+                (func (export "add") (param i32 i32) (result i32)
+                    (i32.add (local.get 0) (local.get 1))))
+            "#,
+        )?;
+        let module = Loader::create(Some(&config))?.from_bytes(&wasm)?;
+        Validator::create(Some(&config))?.validate(&module)?;
+        let mut instance = executor.register_active_module(&mut store, &module)?;
+        let mut add = instance.get_func_mut("add")?;
+        let async_state = AsyncState::new();
+        let params = || [WasmValue::from_i32(1), WasmValue::from_i32(2)];
+
+        // each async call counts its own instructions only
+        executor.call_func(&mut add, params())?;
+        let fuel = executor.fuel_consumed().unwrap();
+        assert!(fuel > 0);
+        executor
+            .call_func_async(&async_state, &mut add, params())
+            .await?;
+        assert_eq!(executor.fuel_consumed(), Some(fuel));
+        executor
+            .call_func_ref_async(&async_state, &mut *add, params())
+            .await?;
+        assert_eq!(executor.fuel_consumed(), Some(fuel));
+        #[cfg(not(target_env = "musl"))]
+        {
+            let timeout = std::time::Duration::from_secs(10);
+            executor
+                .call_func_async_with_timeout(&async_state, &mut add, params(), timeout)
+                .await?;
+            assert_eq!(executor.fuel_consumed(), Some(fuel));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_executor_last_call_cost() {
+        // create an executor without statistics
+        let executor = Executor::create(None, None).unwrap();
+        assert_eq!(executor.last_call_cost(), None);
+
+        // create an executor with statistics
+        let mut config = Config::create().unwrap();
+        config.measure_cost(true);
+        let stat = Statistics::create().unwrap();
+        let executor = Executor::create(Some(&config), Some(stat)).unwrap();
+        assert_eq!(executor.last_call_cost(), Some(0));
+    }
+
+    #[test]
+    fn test_executor_cancellation_token() {
+        let token = CancellationToken::new();
+        assert!(!token.is_cancelled());
+
+        // the clones share the same state
+        let cloned = token.clone();
+        cloned.cancel();
+        assert!(token.is_cancelled());
+    }
+}
@@ -40,6 +40,8 @@ impl CallingFrame {
             } else {
                 let mem = Memory {
                     inner: InnerMemory(ctx),
+                    max_pages: None,
+                    owned: false,
                 };
                 Some(InnerRef::create_from_ref(
                     std::mem::ManuallyDrop::new(mem),
@@ -69,6 +71,8 @@ impl CallingFrame {
             } else {
                 let mem = Memory {
                     inner: InnerMemory(ctx),
+                    max_pages: None,
+                    owned: false,
                 };
                 Some(InnerRef::create_from_mut(
                     std::mem::ManuallyDrop::new(mem),
@@ -30,8 +30,39 @@ pub type CustomFnWrapper = unsafe extern "C" fn(
     return_len: u32,
 ) -> ffi::WasmEdge_Result;
 
+/// Defines how the wrapper of a host function calls the native function stored as an opaque key, which allows the native functions of other signatures than [SyncFn] to be used by [Function::create_sync_func_with_key].
+pub trait HostFnKey<Data> {
+    /// Calls the native function stored as `key`.
+    ///
+    /// # Safety
+    ///
+    /// The `key` must be the same one passed to [Function::create_sync_func_with_key] together with this type.
+    unsafe fn call(
+        key: *mut c_void,
+        data: &mut Data,
+        inst: &mut Instance,
+        frame: &mut CallingFrame,
+        args: Vec<WasmValue>,
+    ) -> Result<Vec<WasmValue>, CoreError>;
+}
+
+// The key of a host function created from a SyncFn.
+struct SyncFnKey;
+impl<Data> HostFnKey<Data> for SyncFnKey {
+    unsafe fn call(
+        key: *mut c_void,
+        data: &mut Data,
+        inst: &mut Instance,
+        frame: &mut CallingFrame,
+        args: Vec<WasmValue>,
+    ) -> Result<Vec<WasmValue>, CoreError> {
+        let real_fn: SyncFn<Data> = std::mem::transmute(key);
+        real_fn(data, inst, frame, args)
+    }
+}
+
 // Wrapper function for thread-safe scenarios.
-unsafe extern "C" fn wrap_fn<Data>(
+unsafe extern "C" fn wrap_fn<Data, K: HostFnKey<Data>>(
     key_ptr: *mut c_void,
     data: *mut std::os::raw::c_void,
     call_frame_ctx: *const ffi::WasmEdge_CallingFrameContext,
@@ -64,9 +95,7 @@ unsafe extern "C" fn wrap_fn<Data>(
         unsafe { std::slice::from_raw_parts_mut(returns, return_len) }
     };
 
-    let real_fn: SyncFn<Data> = std::mem::transmute(key_ptr);
-
-    match real_fn(data, &mut inst, &mut frame, input) {
+    match K::call(key_ptr, data, &mut inst, &mut frame, input) {
         Ok(returns) => {
             assert!(returns.len() == return_len, "[wasmedge-sys] check the number of returns of host function. Expected: {}, actual: {}", return_len, returns.len());
             for (idx, wasm_value) in returns.into_iter().enumerate() {
@@ -141,7 +170,37 @@ impl Function {
         data: *mut T,
         cost: u64,
     ) -> WasmEdgeResult<Self> {
-        Self::create_with_custom_wrapper(ty, wrap_fn::<T>, real_fn as _, data as _, cost)
+        Self::create_with_custom_wrapper(ty, wrap_fn::<T, SyncFnKey>, real_fn as _, data as _, cost)
+    }
+
+    /// Creates a [host function](crate::Function) with the given function type, of which the native function is stored as an opaque key and called by `K`.
+    ///
+    /// N.B. that this function is used for thread-safe scenarios.
+    ///
+    /// # Arguments
+    ///
+    /// * `ty` - The types of the arguments and returns of the target function.
+    ///
+    /// * `key` - The opaque key of the native function, which is passed to [HostFnKey::call] when the host function is called.
+    ///
+    /// * `data` - The pointer to the host context data used in this function.
+    ///
+    /// * `cost` - The function cost in the [Statistics](crate::Statistics). Pass 0 if the calculation is not needed.
+    ///
+    /// # Error
+    ///
+    /// * If fail to create a [Function], then [WasmEdgeError::Func(FuncError::Create)](crate::error::FuncError) is returned.
+    ///
+    /// # Safety
+    ///
+    /// The caller should guarantee that `key` is valid for `K` and outlives the function, and that the life cycle of the `data` object exceeds that of the function.
+    pub unsafe fn create_sync_func_with_key<T, K: HostFnKey<T>>(
+        ty: &wasmedge_types::FuncType,
+        key: *mut c_void,
+        data: *mut T,
+        cost: u64,
+    ) -> WasmEdgeResult<Self> {
+        Self::create_with_custom_wrapper(ty, wrap_fn::<T, K>, key, data as _, cost)
     }
 
     /// Creates a [host function](crate::Function) with the given function type and the custom function wrapper.
@@ -215,6 +274,11 @@ impl Drop for Function {
 
 /// Defines a reference to a [host function](crate::Function).
 pub type FuncRef<Ref> = InnerRef<Function, Ref>;
+impl<Ref: ?Sized> AsRef<Function> for FuncRef<&Ref> {
+    fn as_ref(&self) -> &Function {
+        &self.value
+    }
+}
 
 pub trait AsFunc {
     /// # Safety
@@ -222,6 +286,9 @@ pub trait AsFunc {
     /// The lifetime of the returned pointer must not exceed that of the object itself.
     unsafe fn get_func_raw(&self) -> *mut ffi::WasmEdge_FunctionInstanceContext;
 
+    /// Returns the type of the [function](crate::Function), from which the parameter and return types can be inspected before calling it.
+    ///
+    /// If fail to get the type, then `None` is returned.
     fn ty(&self) -> Option<wasmedge_types::FuncType>
     where
         Self: Sized,
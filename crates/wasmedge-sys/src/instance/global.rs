@@ -61,6 +61,23 @@ impl Global {
         val.into()
     }
 
+    /// Returns the value of the [Global] instance as the given Rust type.
+    ///
+    /// Unlike [get_value](Global::get_value), the value type of the [Global] instance is checked against `T` before conversion, so that an `i64` global is never silently truncated to an `i32`.
+    ///
+    /// # Errors
+    ///
+    /// If the value type of the [Global] instance does not match `T`, then [GlobalError::UnmatchedValType](wasmedge_types::error::GlobalError) is returned.
+    pub fn get_value_as<T: GlobalValue>(&self) -> WasmEdgeResult<T> {
+        let ty = self.ty()?;
+        if ty.value_ty() != T::VAL_TYPE {
+            return Err(Box::new(WasmEdgeError::Global(
+                GlobalError::UnmatchedValType,
+            )));
+        }
+        Ok(T::from_wasm_value(self.get_value()))
+    }
+
     /// Sets the value of the [Global] instance.
     ///
     /// Notice that only the [Global] instance of [Mutability::Var](wasmedge_types::Mutability::Var) type can be set a new value. Setting a new value for a [Global] of [Mutability::Const](wasmedge_types::Mutability::Const) causes a failure.
@@ -112,6 +129,32 @@ impl Drop for Global {
     }
 }
 
+/// Defines the Rust types that the value of a [Global] instance can be read as via [Global::get_value_as].
+pub trait GlobalValue: Sized {
+    /// The wasm value type corresponding to the Rust type.
+    const VAL_TYPE: ValType;
+
+    /// Converts the given [WasmValue](crate::WasmValue) to the Rust type.
+    fn from_wasm_value(value: WasmValue) -> Self;
+}
+
+macro_rules! impl_global_value {
+    ($t:ty, $val_ty:ident, $f:ident) => {
+        impl GlobalValue for $t {
+            const VAL_TYPE: ValType = ValType::$val_ty;
+
+            fn from_wasm_value(value: WasmValue) -> Self {
+                value.$f()
+            }
+        }
+    };
+}
+
+impl_global_value!(i32, I32, to_i32);
+impl_global_value!(i64, I64, to_i64);
+impl_global_value!(f32, F32, to_f32);
+impl_global_value!(f64, F64, to_f64);
+
 #[derive(Debug)]
 pub(crate) struct InnerGlobal(pub(crate) *mut ffi::WasmEdge_GlobalInstanceContext);
 unsafe impl Send for InnerGlobal {}
@@ -223,6 +266,13 @@ mod tests {
         let result = global_const.set_value(WasmValue::from_i32(0));
         assert!(result.is_err());
 
+        // access the value with the checked value type
+        assert_eq!(global_const.get_value_as::<i32>().unwrap(), 99);
+        assert_eq!(
+            global_const.get_value_as::<i64>().unwrap_err(),
+            Box::new(WasmEdgeError::Global(GlobalError::UnmatchedValType))
+        );
+
         // access the global type
         let result = global_const.ty();
         assert!(result.is_ok());
@@ -8,10 +8,16 @@
 use crate::{ffi, types::WasmEdgeLimit, utils::check, WasmEdgeResult};
 use wasmedge_types::error::{MemError, WasmEdgeError};
 
+// each wasm page is 64 KiB
+const PAGE_SIZE: usize = 65536;
+
 /// Defines a WebAssembly memory instance, which is a linear memory described by its [type](crate::MemType). Each memory instance consists of a vector of bytes and an optional maximum size, and its size is a multiple of the WebAssembly page size (*64KiB* of each page).
 #[derive(Debug)]
 pub struct Memory {
     pub(crate) inner: InnerMemory,
+    pub(crate) max_pages: Option<u32>,
+    // false for the handles borrowed from a module instance or a calling frame, whose memory instance cannot be replaced
+    pub(crate) owned: bool,
 }
 impl Memory {
     /// Create a new [Memory] to be associated with the given capacity limit.
@@ -32,6 +38,8 @@ impl Memory {
             true => Err(Box::new(WasmEdgeError::Mem(MemError::Create))),
             false => Ok(Memory {
                 inner: InnerMemory(ctx),
+                max_pages: None,
+                owned: true,
             }),
         }
     }
@@ -168,12 +176,69 @@ impl Memory {
     ///
     /// # Errors
     ///
-    /// If fail to grow the page count, then an error is returned.
+    /// * If the page count after growing exceeds the maximum page count set by [set_max_pages](crate::Memory::set_max_pages), then [WasmEdgeError::Mem(MemError::MaxPagesExceeded)](wasmedge_types::error::MemError) is returned.
+    ///
+    /// * If fail to grow the page count, then an error is returned.
     ///
     pub fn grow(&mut self, count: u32) -> WasmEdgeResult<()> {
+        if let Some(max) = self.max_pages {
+            if self.size() as u64 + count as u64 > max as u64 {
+                return Err(Box::new(WasmEdgeError::Mem(MemError::MaxPagesExceeded)));
+            }
+        }
         unsafe { check(ffi::WasmEdge_MemoryInstanceGrowPage(self.inner.0, count)) }
     }
 
+    /// Sets the maximum page count, to which this [Memory] can grow. The limit is applied to the [memory type](crate::MemType) of the memory instance, so it also caps the `memory.grow` instructions of the wasm modules importing this memory from an [ImportModule](crate::ImportModule).
+    ///
+    /// The memory instance is re-created with the capped memory type and its data is copied, so the limit should be set before the memory is added to an import module. If the memory type already has a lower maximum size, the memory instance is kept.
+    ///
+    /// # Argument
+    ///
+    /// * `max` - The maximum page count.
+    ///
+    /// # Errors
+    ///
+    /// * If the current page count exceeds `max`, then [WasmEdgeError::Mem(MemError::MaxPagesExceeded)](wasmedge_types::error::MemError) is returned.
+    ///
+    /// * If this [Memory] is borrowed from a module instance or a calling frame, then [WasmEdgeError::Mem(MemError::Borrowed)](wasmedge_types::error::MemError) is returned.
+    ///
+    /// * If fail to re-create the memory instance, then an error is returned.
+    pub fn set_max_pages(&mut self, max: u32) -> WasmEdgeResult<()> {
+        let size = self.size();
+        if size > max {
+            return Err(Box::new(WasmEdgeError::Mem(MemError::MaxPagesExceeded)));
+        }
+        let ty = self.ty()?;
+        if ty.maximum().is_some_and(|ty_max| ty_max <= max) {
+            self.max_pages = Some(max);
+            return Ok(());
+        }
+        if !self.owned {
+            return Err(Box::new(WasmEdgeError::Mem(MemError::Borrowed)));
+        }
+
+        let capped_ty = wasmedge_types::MemoryType::new(ty.minimum(), Some(max), ty.shared())?;
+        let mut capped = Memory::create(&capped_ty)?;
+        if size > ty.minimum() {
+            capped.grow(size - ty.minimum())?;
+        }
+        // the pages of the new memory instance are zeroed, so only the non-zero pages are copied
+        for (i, page) in self.as_ref().chunks(PAGE_SIZE).enumerate() {
+            if page.iter().any(|b| *b != 0) {
+                capped.set_data(page, (i * PAGE_SIZE) as u32)?;
+            }
+        }
+        capped.max_pages = Some(max);
+        *self = capped;
+        Ok(())
+    }
+
+    /// Returns the maximum page count set by [set_max_pages](crate::Memory::set_max_pages).
+    pub fn max_pages(&self) -> Option<u32> {
+        self.max_pages
+    }
+
     /// # Safety
     ///
     /// Provides a raw pointer to the inner memory context.
@@ -182,6 +247,22 @@ impl Memory {
         self.inner.0
     }
 }
+impl AsRef<[u8]> for Memory {
+    /// Returns a zero-copy view of the whole linear memory.
+    ///
+    /// The view is borrowed from this [Memory], so the memory cannot be grown or written through this handle while the view is alive.
+    fn as_ref(&self) -> &[u8] {
+        let len = self.size() as usize * PAGE_SIZE;
+        if len == 0 {
+            return &[];
+        }
+        let ptr_len = u32::try_from(len).unwrap_or(u32::MAX);
+        match unsafe { self.data_pointer(0, ptr_len) } {
+            Ok(ptr) => unsafe { std::slice::from_raw_parts(ptr, len) },
+            Err(_) => &[],
+        }
+    }
+}
 impl Drop for Memory {
     fn drop(&mut self) {
         unsafe { ffi::WasmEdge_MemoryInstanceDelete(self.inner.0) };
@@ -383,6 +464,85 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_memory_grow_with_max_pages() {
+        // create a Memory with a limit range [1, 10]
+        let ty = wasmedge_types::MemoryType::new(1, Some(10), false).unwrap();
+        let mut mem = Memory::create(&ty).unwrap();
+        assert_eq!(mem.max_pages(), None);
+        mem.set_data([1, 2, 3], 0).unwrap();
+
+        // limit the memory to 3 pages, which caps the memory type
+        assert!(mem.set_max_pages(3).is_ok());
+        assert_eq!(mem.max_pages(), Some(3));
+        assert_eq!(mem.ty().unwrap().maximum(), Some(3));
+        assert_eq!(mem.get_data(0, 3).unwrap(), vec![1, 2, 3]);
+        assert!(mem.grow(2).is_ok());
+        assert_eq!(mem.size(), 3);
+
+        // grow beyond the limit
+        let result = mem.grow(1);
+        assert_eq!(
+            result.unwrap_err(),
+            Box::new(WasmEdgeError::Mem(MemError::MaxPagesExceeded))
+        );
+        assert_eq!(mem.size(), 3);
+
+        // the limit cannot be lower than the current size
+        let result = mem.set_max_pages(2);
+        assert_eq!(
+            result.unwrap_err(),
+            Box::new(WasmEdgeError::Mem(MemError::MaxPagesExceeded))
+        );
+    }
+
+    #[test]
+    fn test_memory_max_pages_caps_wasm_grow() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::{AsInstance, Executor, ImportModule, Loader, Store, Validator, WasmValue};
+
+        // limit an imported memory to 2 pages
+        let ty = wasmedge_types::MemoryType::new(1, None, false)?;
+        let mut memory = Memory::create(&ty)?;
+        memory.set_max_pages(2)?;
+        let mut import = ImportModule::create("env", Box::new(()))?;
+        import.add_memory("memory", memory);
+        import.add_memory("other", Memory::create(&ty)?);
+
+        let wasm = wat::parse_str(
+            r#"
+            (module
+                (import "env" "memory" (memory 1))
+                (func (export "grow") (param i32) (result i32)
+                    (memory.grow (local.get 0))))
+            "#,
+        )?;
+        let mut executor = Executor::create(None, None)?;
+        let mut store = Store::create()?;
+        executor.register_import_module(&mut store, &import)?;
+        let module = Loader::create(None)?.from_bytes(&wasm)?;
+        Validator::create(None)?.validate(&module)?;
+        let mut instance = executor.register_active_module(&mut store, &module)?;
+
+        // the wasm module can grow the memory up to the limit, but not beyond it
+        let mut grow = instance.get_func_mut("grow")?;
+        let returns = executor.call_func(&mut grow, [WasmValue::from_i32(1)])?;
+        assert_eq!(returns[0].to_i32(), 1);
+        let returns = executor.call_func(&mut grow, [WasmValue::from_i32(1)])?;
+        assert_eq!(returns[0].to_i32(), -1);
+
+        // the memory type of a borrowed memory instance cannot be capped
+        let memory = import.get_memory_ref("memory")?;
+        assert_eq!(memory.size(), 2);
+        assert_eq!(memory.ty()?.maximum(), Some(2));
+        let mut other = import.get_memory_mut("other")?;
+        assert_eq!(
+            other.set_max_pages(1).unwrap_err(),
+            Box::new(WasmEdgeError::Mem(MemError::Borrowed))
+        );
+
+        Ok(())
+    }
+
     #[test]
     #[allow(clippy::assertions_on_result_states)]
     fn test_memory_data() {
@@ -430,6 +590,12 @@ mod tests {
         assert_eq!(mem.size(), 2);
         let result = mem.set_data(vec![1; 10], u32::pow(2, 16) - 9);
         assert!(result.is_ok());
+
+        // view the whole memory as a byte slice
+        let bytes: &[u8] = mem.as_ref();
+        assert_eq!(bytes.len(), 2 * u32::pow(2, 16) as usize);
+        assert_eq!(&bytes[10..20], &[1; 10]);
+        assert_eq!(&bytes[20..30], &[0; 10]);
     }
 
     #[test]
@@ -2,11 +2,11 @@
 use crate::{
     ffi::{self},
     instance::{global::InnerGlobal, memory::InnerMemory, table::InnerTable},
-    types::WasmEdgeString,
+    types::{WasmEdgeString, WasmValue},
     FuncRef, Function, Global, Memory, Table, WasmEdgeResult,
 };
 
-use wasmedge_types::error::{InstanceError, WasmEdgeError};
+use wasmedge_types::error::{FuncError, InstanceError, WasmEdgeError};
 
 use super::{function::AsFunc, InnerRef};
 
@@ -111,6 +111,8 @@ pub trait AsInstance {
             } else {
                 let mem = Memory {
                     inner: InnerMemory(ctx),
+                    max_pages: None,
+                    owned: false,
                 };
 
                 Ok(InnerRef::create_from_ref(
@@ -139,6 +141,8 @@ pub trait AsInstance {
             } else {
                 let mem = Memory {
                     inner: InnerMemory(ctx),
+                    max_pages: None,
+                    owned: false,
                 };
 
                 Ok(InnerRef::create_from_mut(
@@ -201,6 +205,42 @@ pub trait AsInstance {
         }
     }
 
+    /// Returns the value of the exported [global instance](crate::Global) by name.
+    ///
+    /// # Argument
+    ///
+    /// * `name` - The name of the target exported [global instance](crate::Global).
+    ///
+    /// # Error
+    ///
+    /// If fail to find the target [global instance](crate::Global), then an error is returned.
+    fn get_global_value(&self, name: impl AsRef<str>) -> WasmEdgeResult<WasmValue>
+    where
+        Self: Sized,
+    {
+        let global = self.get_global(name)?;
+        Ok(global.get_value())
+    }
+
+    /// Sets a new value to the exported [global instance](crate::Global) by name.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the target exported [global instance](crate::Global).
+    ///
+    /// * `value` - The new wasm value to be set.
+    ///
+    /// # Error
+    ///
+    /// If fail to find the target [global instance](crate::Global), or the global instance is immutable, or the type of `value` does not match, then an error is returned.
+    fn set_global_value(&mut self, name: impl AsRef<str>, value: WasmValue) -> WasmEdgeResult<()>
+    where
+        Self: Sized,
+    {
+        let mut global = self.get_global_mut(name)?;
+        global.set_value(value)
+    }
+
     /// Returns the length of the exported [function instances](crate::Function) in this module instance.
     fn func_len(&self) -> u32 {
         unsafe { ffi::WasmEdge_ModuleInstanceListFunctionLength(self.as_ptr()) }
@@ -231,6 +271,46 @@ pub trait AsInstance {
         }
     }
 
+    /// Returns the names and the types of the exported [function instances](crate::Function) in this module instance, in the order of [func_names](AsInstance::func_names).
+    ///
+    /// Unlike calling [get_func](AsInstance::get_func) for each name, the functions are looked up by the listed names directly, without converting the names back and forth.
+    ///
+    /// # Error
+    ///
+    /// If fail to find any exported [function](crate::Function) or get its type, then an error is returned.
+    fn export_types(&self) -> WasmEdgeResult<Vec<(String, wasmedge_types::FuncType)>> {
+        let len_func_names = self.func_len();
+        let mut raw_names = Vec::with_capacity(len_func_names as usize);
+        unsafe {
+            ffi::WasmEdge_ModuleInstanceListFunction(
+                self.as_ptr(),
+                raw_names.as_mut_ptr(),
+                len_func_names,
+            );
+            raw_names.set_len(len_func_names as usize);
+        }
+
+        raw_names
+            .into_iter()
+            .map(|raw_name| {
+                let name: String = raw_name.into();
+                let func_ctx =
+                    unsafe { ffi::WasmEdge_ModuleInstanceFindFunction(self.as_ptr(), raw_name) };
+                if func_ctx.is_null() {
+                    return Err(Box::new(WasmEdgeError::Instance(
+                        InstanceError::NotFoundFunc(name),
+                    )));
+                }
+
+                let func = std::mem::ManuallyDrop::new(unsafe { Function::from_raw(func_ctx) });
+                let ty = func
+                    .ty()
+                    .ok_or_else(|| Box::new(WasmEdgeError::Func(FuncError::Type)))?;
+                Ok((name, ty))
+            })
+            .collect()
+    }
+
     /// Returns the exported [function instance](crate::Function) by name.
     ///
     /// # Argument
@@ -240,6 +320,8 @@ pub trait AsInstance {
     /// # Error
     ///
     /// If fail to find the target [function](crate::Function), then an error is returned.
+    ///
+    /// Unlike the other `get_*` methods, `name` is a `&str` rather than `impl AsRef<str>`, since this method is also called on trait objects, such as `dyn AsInstance`, which cannot have generic methods.
     fn get_func(&self, name: &str) -> WasmEdgeResult<FuncRef<&Instance>> {
         unsafe {
             let func_name: WasmEdgeString = name.into();
@@ -266,6 +348,8 @@ pub trait AsInstance {
     /// # Error
     ///
     /// If fail to find the target [function](crate::Function), then an error is returned.
+    ///
+    /// Unlike the other `get_*` methods, `name` is a `&str` rather than `impl AsRef<str>`, since this method is also called on trait objects, such as `dyn AsInstance`, which cannot have generic methods.
     fn get_func_mut(&mut self, name: &str) -> WasmEdgeResult<FuncRef<&mut Instance>> {
         unsafe {
             let func_name: WasmEdgeString = name.into();
@@ -462,6 +546,18 @@ impl<T: Sized> ImportModule<T> {
     pub fn get_host_data_mut(&mut self) -> &mut T {
         unsafe { &mut *(ffi::WasmEdge_ModuleInstanceGetHostData(self.as_ptr()) as *mut T) }
     }
+
+    /// Replaces the host data of this import module, and returns the old one.
+    ///
+    /// The host data is swapped in place, so the host functions created with [get_host_data_mut](ImportModule::get_host_data_mut) see the new data in the following calls.
+    ///
+    /// # Argument
+    ///
+    /// * `new_data` - The new host context data.
+    pub fn replace_host_data(&mut self, mut new_data: Box<T>) -> Box<T> {
+        std::mem::swap(self.get_host_data_mut(), new_data.as_mut());
+        new_data
+    }
 }
 impl<T: Sized> AsInstance for ImportModule<T> {
     unsafe fn as_ptr(&self) -> *const ffi::WasmEdge_ModuleInstanceContext {
@@ -712,7 +808,7 @@ mod tests {
     use crate::{CallingFrame, Executor, GlobalType, ImportModule, Store, TableType, WasmValue};
 
     use wasmedge_types::{
-        error::{CoreError, CoreExecutionError},
+        error::{CoreError, CoreExecutionError, GlobalError},
         FuncType, MemoryType, Mutability, RefType, ValType,
     };
 
@@ -769,6 +865,16 @@ mod tests {
     }
 
     #[cfg(target_family = "unix")]
+    #[test]
+    fn test_instance_replace_host_data() {
+        let mut import = ImportModule::create("extern", Box::new(1)).unwrap();
+        assert_eq!(*import.get_host_data(), 1);
+
+        let old = import.replace_host_data(Box::new(2));
+        assert_eq!(*old, 1);
+        assert_eq!(*import.get_host_data(), 2);
+    }
+
     #[test]
     fn test_instance_wasi() {
         // create a wasi module instance
@@ -921,6 +1027,52 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_instance_global_value() -> Result<(), Box<dyn std::error::Error>> {
+        let mut import = ImportModule::create("extern_module", Box::new(()))?;
+
+        // add a const global and a mutable global
+        let ty = GlobalType::new(ValType::F32, Mutability::Const);
+        let global = Global::create(&ty, WasmValue::from_f32(3.5))?;
+        import.add_global("const_global", global);
+        let ty = GlobalType::new(ValType::I32, Mutability::Var);
+        let global = Global::create(&ty, WasmValue::from_i32(1))?;
+        import.add_global("var_global", global);
+
+        // get the values
+        assert_eq!(import.get_global_value("const_global")?.to_f32(), 3.5);
+        assert_eq!(import.get_global_value("var_global")?.to_i32(), 1);
+
+        // set a new value to the mutable global
+        import.set_global_value("var_global", WasmValue::from_i32(2))?;
+        assert_eq!(import.get_global_value("var_global")?.to_i32(), 2);
+
+        // set a new value to the const global
+        let result = import.set_global_value("const_global", WasmValue::from_f32(1.0));
+        assert_eq!(
+            result.unwrap_err(),
+            Box::new(WasmEdgeError::Global(GlobalError::ModifyConst))
+        );
+
+        // set a value of mismatched type
+        let result = import.set_global_value("var_global", WasmValue::from_i64(2));
+        assert_eq!(
+            result.unwrap_err(),
+            Box::new(WasmEdgeError::Global(GlobalError::UnmatchedValType))
+        );
+
+        // get the value of a missing global
+        let result = import.get_global_value("missing");
+        assert_eq!(
+            result.unwrap_err(),
+            Box::new(WasmEdgeError::Instance(InstanceError::NotFoundGlobal(
+                "missing".to_string()
+            )))
+        );
+
+        Ok(())
+    }
+
     #[test]
     #[allow(clippy::assertions_on_result_states)]
     fn test_instance_find_names() -> Result<(), Box<dyn std::error::Error>> {
@@ -984,6 +1136,9 @@ mod tests {
         let result = instance.func_names();
         assert!(result.is_some());
         assert_eq!(result.unwrap(), ["add"]);
+        let result = instance.export_types();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), [("add".to_string(), func_ty)]);
 
         assert_eq!(instance.table_len(), 1);
         let result = instance.table_names();
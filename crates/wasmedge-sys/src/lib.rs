@@ -99,15 +99,15 @@ pub use compiler::Compiler;
 #[doc(inline)]
 pub use config::Config;
 #[doc(inline)]
-pub use executor::Executor;
+pub use executor::{CancellationToken, Executor};
 #[doc(inline)]
 pub use frame::CallingFrame;
 #[doc(inline)]
 pub use instance::module::WasiModule;
 #[doc(inline)]
 pub use instance::{
-    function::{FuncRef, Function, SyncFn},
-    global::Global,
+    function::{AsFunc, FuncRef, Function, HostFnKey, SyncFn},
+    global::{Global, GlobalValue},
     memory::Memory,
     module::{AsInstance, ImportModule, Instance},
     table::Table,
@@ -1,7 +1,7 @@
 //! Defines WasmEdge Statistics struct.
 
 use crate::{ffi, WasmEdgeResult};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use wasmedge_types::error::WasmEdgeError;
 
 #[derive(Debug, Clone)]
@@ -20,7 +20,7 @@ impl Statistics {
         match ctx.is_null() {
             true => Err(Box::new(WasmEdgeError::StatisticsCreate)),
             false => Ok(Statistics {
-                inner: Arc::new(InnerStat(ctx)),
+                inner: Arc::new(InnerStat(ctx, Mutex::new(CostSettings::default()))),
             }),
         }
     }
@@ -66,21 +66,67 @@ impl Statistics {
     ///
     /// * `cost_table` - The slice of cost table.
     pub fn set_cost_table(&mut self, cost_table: impl AsRef<[u64]>) {
+        let mut settings = self.inner.1.lock().expect("fail to lock the cost settings");
+        // WasmEdge fills the costs missing from a short table with `0`
+        settings.table.clear();
+        settings.table.extend_from_slice(cost_table.as_ref());
+        settings.table.resize(COST_TABLE_LEN, 0);
+        self.load_cost_table(&settings.table);
+    }
+
+    /// Returns the cost of the instruction at the given index of the cost table, which is `1` unless it is changed by [set_cost_table](Statistics::set_cost_table) or [set_costs](Statistics::set_costs).
+    ///
+    /// # Argument
+    ///
+    /// * `index` - The index of the instruction in the cost table.
+    pub fn cost(&self, index: u16) -> u64 {
+        let settings = self.inner.1.lock().expect("fail to lock the cost settings");
+        settings.table.get(index as usize).copied().unwrap_or(1)
+    }
+
+    /// Sets the costs of the instructions at the given indexes of the cost table, while the costs of the other instructions are kept.
+    ///
+    /// # Argument
+    ///
+    /// * `costs` - The pairs of the indexes of the instructions and their costs.
+    pub fn set_costs(&mut self, costs: impl IntoIterator<Item = (u16, u64)>) {
+        let mut settings = self.inner.1.lock().expect("fail to lock the cost settings");
+        if settings.table.is_empty() {
+            settings.table = vec![1; COST_TABLE_LEN];
+        }
+        for (index, cost) in costs {
+            settings.table[index as usize] = cost;
+        }
+        self.load_cost_table(&settings.table);
+    }
+
+    fn load_cost_table(&self, cost_table: &[u64]) {
         unsafe {
             ffi::WasmEdge_StatisticsSetCostTable(
                 self.inner.0,
-                cost_table.as_ref().as_ptr() as *mut _,
-                cost_table.as_ref().len() as u32,
+                cost_table.as_ptr() as *mut _,
+                cost_table.len() as u32,
             )
         }
     }
 
+    /// Returns the cost limit in execution, which is `u64::MAX` unless it is changed by [set_cost_limit](Statistics::set_cost_limit).
+    pub fn cost_limit(&self) -> u64 {
+        self.inner
+            .1
+            .lock()
+            .expect("fail to lock the cost settings")
+            .limit
+    }
+
     /// Sets the cost limit in execution.
     ///
     /// # Arguments
     ///
     /// * `limit` - The cost limit.
     pub fn set_cost_limit(&mut self, limit: u64) {
+        let mut settings = self.inner.1.lock().expect("fail to lock the cost settings");
+        settings.limit = limit;
         unsafe { ffi::WasmEdge_StatisticsSetCostLimit(self.inner.0, limit) }
     }
 
@@ -102,7 +148,29 @@ impl Drop for Statistics {
     }
 }
 
+// The length of the cost table of WasmEdge, which is indexed by the `u16` opcodes.
+const COST_TABLE_LEN: usize = u16::MAX as usize + 1;
+
+// The cost settings loaded into a statistics context, which cannot be read back by the WasmEdge C API.
+#[derive(Debug)]
+pub(crate) struct CostSettings {
+    // the cost table, which is empty until it is changed, as all the costs are `1` by default
+    table: Vec<u64>,
+    limit: u64,
+}
+impl Default for CostSettings {
+    fn default() -> Self {
+        CostSettings {
+            table: Vec::new(),
+            limit: u64::MAX,
+        }
+    }
+}
+
 #[derive(Debug)]
-pub(crate) struct InnerStat(pub(crate) *mut ffi::WasmEdge_StatisticsContext);
+pub(crate) struct InnerStat(
+    pub(crate) *mut ffi::WasmEdge_StatisticsContext,
+    Mutex<CostSettings>,
+);
 unsafe impl Send for InnerStat {}
 unsafe impl Sync for InnerStat {}
@@ -3,7 +3,7 @@
 use crate::{
     ffi,
     instance::{
-        module::{InnerInstance, Instance},
+        module::{AsInstance, InnerInstance, Instance},
         InnerRef,
     },
     types::WasmEdgeString,
@@ -13,6 +13,10 @@ use crate::{
 use wasmedge_types::error::{StoreError, WasmEdgeError};
 
 /// The [Store] is a collection of registered modules and assists wasm modules in finding the import modules they need.
+///
+/// # Ownership
+///
+/// A [Store] only links the registered [module instances](crate::Instance) by name, and does not own them. The module instances returned by the [executor](crate::Executor) are owned by the caller and deleted when they are dropped, so they remain valid after the [Store] is dropped. In contrast, the module instances returned by [Store::module] are borrowed from the [Store], and the borrow checker prevents them from being used after the [Store] is dropped.
 #[derive(Debug)]
 pub struct Store {
     pub(crate) inner: InnerStore,
@@ -93,6 +97,23 @@ impl Store {
         }
     }
 
+    /// Returns the names of all the functions exported by the registered [module instances](crate::Instance) as `(module_name, func_name)` pairs, which are ordered by the module names and then by the function names.
+    ///
+    /// The WasmEdge C API lists the functions per module instance, so the module instances in this [Store] are walked one by one.
+    pub fn list_functions(&self) -> Vec<(String, String)> {
+        let mut funcs = Vec::new();
+        for mod_name in self.module_names().unwrap_or_default() {
+            let Ok(inst) = self.module(&mod_name) else {
+                continue;
+            };
+            for func_name in inst.func_names().unwrap_or_default() {
+                funcs.push((mod_name.clone(), func_name));
+            }
+        }
+        funcs.sort();
+        funcs
+    }
+
     /// Checks if the [Store] contains a module of which the name matches the given name.
     ///
     /// # Argument
@@ -112,6 +133,7 @@ impl Store {
 }
 impl Drop for Store {
     fn drop(&mut self) {
+        // only the links to the registered module instances are removed, while the module instances are deleted by their owners
         unsafe { ffi::WasmEdge_StoreDelete(self.inner.0) }
     }
 }
@@ -120,3 +142,47 @@ impl Drop for Store {
 pub(crate) struct InnerStore(pub(crate) *mut ffi::WasmEdge_StoreContext);
 unsafe impl Send for InnerStore {}
 unsafe impl Sync for InnerStore {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Executor, Loader, Validator};
+    use wasmedge_types::wat2wasm;
+
+    #[test]
+    fn test_store_list_functions() {
+        let mut store = Store::create().unwrap();
+        assert!(store.list_functions().is_empty());
+
+        let mut executor = Executor::create(None, None).unwrap();
+        let wasm_bytes = wat2wasm(
+            br#"(module
+              (func (export "sub"))
+              (func (export "add"))
+              (memory (export "memory") 1)
+            )"#,
+        )
+        .unwrap();
+        let module = Loader::create(None)
+            .unwrap()
+            .from_bytes(&wasm_bytes)
+            .unwrap();
+        Validator::create(None).unwrap().validate(&module).unwrap();
+        let _math = executor
+            .register_named_module(&mut store, &module, "math")
+            .unwrap();
+        let _extern = executor
+            .register_named_module(&mut store, &module, "extern")
+            .unwrap();
+
+        assert_eq!(
+            store.list_functions(),
+            vec![
+                ("extern".to_string(), "add".to_string()),
+                ("extern".to_string(), "sub".to_string()),
+                ("math".to_string(), "add".to_string()),
+                ("math".to_string(), "sub".to_string()),
+            ]
+        );
+    }
+}
@@ -1,12 +1,19 @@
 //! Defines WasmEdge Validator struct.
 
 use crate::{ffi, utils::check, Config, Module, WasmEdgeResult};
-use wasmedge_types::error::WasmEdgeError;
+use wasmedge_types::{
+    error::{CoreError, CoreLoadError, CoreValidationError, WasmEdgeError},
+    FuncType, ValType,
+};
 
 /// Struct of WasmEdge Validator.
 #[derive(Debug)]
 pub struct Validator {
     pub(crate) inner: InnerValidator,
+    // the proposals checked by `validate_func_type`
+    multi_value: bool,
+    simd: bool,
+    reference_types: bool,
 }
 impl Validator {
     /// Creates a new [Validator] to be associated with the given global configuration.
@@ -27,6 +34,9 @@ impl Validator {
             true => Err(Box::new(WasmEdgeError::CompilerCreate)),
             false => Ok(Self {
                 inner: InnerValidator(ctx),
+                multi_value: config.map_or(true, |config| config.multi_value_enabled()),
+                simd: config.map_or(true, |config| config.simd_enabled()),
+                reference_types: config.map_or(true, |config| config.reference_types_enabled()),
             }),
         }
     }
@@ -51,6 +61,41 @@ impl Validator {
         }
     }
 
+    /// Validates a given [FuncType](wasmedge_types::FuncType) against the proposals enabled in the configuration of this [Validator], which is useful to catch the invalid function types before registering the host functions.
+    ///
+    /// # Arguments
+    ///
+    /// * `ty` - The [FuncType](wasmedge_types::FuncType) to be validated.
+    ///
+    /// # Error
+    ///
+    /// * If the function type has more than one return value and the `MultiValue` proposal is disabled, then [CoreValidationError::InvalidResultArity](wasmedge_types::error::CoreValidationError) is returned.
+    ///
+    /// * If any argument or return value has a value type that is unsupported or requires a disabled proposal, such as `SIMD` and `ReferenceTypes`, then [CoreLoadError::MalformedValType](wasmedge_types::error::CoreLoadError) is returned.
+    pub fn validate_func_type(&self, ty: &FuncType) -> WasmEdgeResult<()> {
+        if !self.multi_value && ty.returns_len() > 1 {
+            return Err(Box::new(WasmEdgeError::Core(CoreError::Validation(
+                CoreValidationError::InvalidResultArity,
+            ))));
+        }
+
+        for val_ty in ty.args().iter().chain(ty.returns()) {
+            let valid = match val_ty {
+                ValType::I32 | ValType::I64 | ValType::F32 | ValType::F64 => true,
+                ValType::V128 => self.simd,
+                ValType::FuncRef | ValType::ExternRef => self.reference_types,
+                ValType::UnsupportedRef => false,
+            };
+            if !valid {
+                return Err(Box::new(WasmEdgeError::Core(CoreError::Load(
+                    CoreLoadError::MalformedValType,
+                ))));
+            }
+        }
+
+        Ok(())
+    }
+
     /// Provides a raw pointer to the inner Validator context.
     #[cfg(feature = "ffi")]
     #[cfg_attr(docsrs, doc(cfg(feature = "ffi")))]
@@ -78,6 +123,40 @@ mod tests {
         thread,
     };
 
+    #[test]
+    fn test_validator_validate_func_type() {
+        let ty = FuncType::new(
+            vec![ValType::I32, ValType::V128],
+            vec![ValType::I32, ValType::I64],
+        );
+
+        // all the proposals are enabled by default
+        let validator = Validator::create(None).unwrap();
+        assert!(validator.validate_func_type(&ty).is_ok());
+
+        // disable the multi-value proposal
+        let mut config = Config::create().unwrap();
+        config.multi_value(false);
+        let validator = Validator::create(Some(&config)).unwrap();
+        assert_eq!(
+            validator.validate_func_type(&ty).unwrap_err(),
+            Box::new(WasmEdgeError::Core(CoreError::Validation(
+                CoreValidationError::InvalidResultArity
+            )))
+        );
+
+        // disable the simd proposal
+        let mut config = Config::create().unwrap();
+        config.simd(false);
+        let validator = Validator::create(Some(&config)).unwrap();
+        assert_eq!(
+            validator.validate_func_type(&ty).unwrap_err(),
+            Box::new(WasmEdgeError::Core(CoreError::Load(
+                CoreLoadError::MalformedValType
+            )))
+        );
+    }
+
     #[test]
     #[allow(clippy::assertions_on_result_states)]
     fn test_validator() {
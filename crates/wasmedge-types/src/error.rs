@@ -34,6 +34,8 @@ pub enum WasmEdgeError {
     ConfigCreate,
     #[error("Fail to create AOT Compiler")]
     CompilerCreate,
+    #[error("{0}")]
+    Compile(#[from] CompileError),
     #[error("Fail to create Validator")]
     ValidatorCreate,
     #[error("{0}")]
@@ -46,6 +48,8 @@ pub enum WasmEdgeError {
     FuncTypeCreate,
     #[error("Execution Timed Out")]
     ExecuteTimeout,
+    #[error("Execution Cancelled")]
+    Cancelled,
     #[error("{0}")]
     Mem(#[from] MemError),
     #[error("Fail to create MemType")]
@@ -66,6 +70,20 @@ pub enum WasmEdgeError {
     Instance(#[from] InstanceError),
     #[error("{0}")]
     Plugin(#[from] PluginError),
+    #[error("{0}")]
+    Hotreload(#[from] HotreloadError),
+    #[error("Found circular dependency among modules")]
+    CircularDependency,
+    #[error("Fail to run the start functions of modules: {}", format_start_errors(.0))]
+    StartFailed(Vec<(String, WasmEdgeError)>),
+    #[error("Fuel exhausted: {0} of fuel is spent")]
+    FuelExhausted(u64),
+    #[error("Unsatisfied import: no registered module exports `{name}` in module `{module}`")]
+    ImportNotSatisfied {
+        module: String,
+        name: String,
+        ty: ExternalInstanceType,
+    },
 
     // std
     #[error("Found an internal 0 byte")]
@@ -82,6 +100,14 @@ pub enum WasmEdgeError {
     WindowsPathConversion(String),
 }
 
+fn format_start_errors(errors: &[(String, WasmEdgeError)]) -> String {
+    errors
+        .iter()
+        .map(|(name, e)| format!("`{name}`: {e}"))
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
 /// The error types for WasmEdge Function.
 #[derive(Error, Clone, Debug, PartialEq, Eq)]
 pub enum FuncError {
@@ -108,6 +134,10 @@ pub enum MemError {
     MutPtr,
     #[error("Fail to convert a raw pointer to a reference")]
     Ptr2Ref,
+    #[error("Fail to grow the memory beyond its maximum page count")]
+    MaxPagesExceeded,
+    #[error("Fail to change the type of a memory instance borrowed from a module instance or a calling frame")]
+    Borrowed,
 }
 
 /// The error types for WasmEdge Global.
@@ -130,6 +160,8 @@ pub enum TableError {
     Create,
     #[error("Fail to get the table type")]
     Type,
+    #[error("The index {index} is out of the bounds of the table with size {size}")]
+    OutOfBounds { index: u32, size: u32 },
 }
 
 /// The error types for WasmEdge ImportType.
@@ -200,6 +232,26 @@ pub enum PluginError {
     NotFound(String),
 }
 
+/// The error types for the AOT compilation.
+#[derive(Error, Clone, Debug, PartialEq, Eq)]
+pub enum CompileError {
+    #[error("Fail to compile the function {index} at offset {offset:#x}: {message}")]
+    FailedFunction {
+        /// The index of the failed function, including the imported functions.
+        index: u32,
+        /// The byte offset of the failed instruction in the wasm binary.
+        offset: u32,
+        message: String,
+    },
+}
+
+/// The error types for hot reloading the modules.
+#[derive(Error, Clone, Debug, PartialEq, Eq)]
+pub enum HotreloadError {
+    #[error("The exports of the new module do not match the ones of the module to be reloaded")]
+    SignatureMismatch,
+}
+
 /// The error types for WasmEdge Store.
 #[derive(Error, Clone, Debug, PartialEq, Eq)]
 pub enum StoreError {
@@ -272,6 +272,29 @@ impl From<HostRegistration> for u32 {
     }
 }
 
+/// Defines the WebAssembly proposals known by WasmEdge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProposalName {
+    ImportExportMutGlobals,
+    NonTrapFloatToIntConversions,
+    SignExtensionOperators,
+    MultiValue,
+    BulkMemoryOperations,
+    ReferenceTypes,
+    Simd,
+    TailCall,
+    ExtendedConst,
+    FunctionReferences,
+    GC,
+    MultiMemories,
+    Threads,
+    RelaxedSimd,
+    Annotations,
+    Memory64,
+    ExceptionHandling,
+    Component,
+}
+
 /// Defines the type of external WasmEdge instances.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ExternalInstanceType {
@@ -2,9 +2,11 @@
 use crate::{
     error::{VmError, WasmEdgeError},
     vm::SyncInst,
-    Instance, Module, Store, WasmEdgeResult, WasmValue,
+    FuncType, Instance, Module, Store, ValType, WasmEdgeResult, WasmValue,
 };
-use sys::{r#async::fiber::AsyncState, AsInstance};
+use futures::{stream::LocalBoxStream, StreamExt};
+use sys::{r#async::fiber::AsyncState, AsFunc, AsInstance};
+use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader};
 use wasmedge_sys as sys;
 
 use super::import::ImportObject;
@@ -78,12 +80,22 @@ impl<T: Send + SyncInst> AsyncInst for T {}
 ///     Ok(())
 /// }
 /// ```
-#[derive(Debug)]
 pub struct Vm<'inst, T: ?Sized + Send + AsyncInst> {
     store: Store<'inst, T>,
     active_instance: Option<sys::Instance>,
     async_state: AsyncState,
 }
+impl<T: ?Sized + Send + AsyncInst> std::fmt::Debug for Vm<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut modules = self.instance_names();
+        modules.sort();
+
+        f.debug_struct("Vm")
+            .field("modules", &modules)
+            .field("store", &self.store)
+            .finish()
+    }
+}
 impl<'inst, T: ?Sized + Send + AsyncInst> Vm<'inst, T> {
     pub fn new(store: Store<'inst, T>) -> Self {
         // create a Vm instance
@@ -227,6 +239,121 @@ impl<'inst, T: ?Sized + Send + AsyncInst> Vm<'inst, T> {
             .await
     }
 
+    /// Runs the function calls read from an input stream one by one in a (named or active) [module instance](crate::Instance), and returns a stream of the results.
+    ///
+    /// Each line of the input is a serialized call in the form of `<func_name> [<arg>...]`, where the arguments are separated by whitespaces and parsed as the parameter types of the function, for example, `add 1 2`. Only the number types are supported, and the empty lines are skipped. The next line is read only when the next result is polled, so a slow consumer slows down the reading of the input.
+    ///
+    /// # Arguments
+    ///
+    /// * `mod_name` - The exported name of the module instance, which holds the target functions. If `None`, then the active module is used.
+    ///
+    /// * `input` - The input stream of the serialized calls.
+    ///
+    /// # Error
+    ///
+    /// If fail to parse or run a call, then the error is yielded as the result of the call, and the following calls are still run. If fail to read the input stream, then the error is yielded and the stream ends.
+    pub fn run_func_stream<'a, R>(
+        &'a mut self,
+        mod_name: Option<&'a str>,
+        input: R,
+    ) -> LocalBoxStream<'a, WasmEdgeResult<Vec<WasmValue>>>
+    where
+        R: AsyncRead + Unpin + 'a,
+    {
+        let lines = BufReader::new(input).lines();
+        futures::stream::unfold(Some((self, lines)), move |state| async move {
+            let (vm, mut lines) = state?;
+            let line = loop {
+                match lines.next_line().await {
+                    Ok(Some(line)) if line.trim().is_empty() => continue,
+                    Ok(Some(line)) => break line,
+                    Ok(None) => return None,
+                    Err(e) => {
+                        let err = Box::new(WasmEdgeError::Operation(format!(
+                            "failed to read the input stream: {e}"
+                        )));
+                        return Some((Err(err), None));
+                    }
+                }
+            };
+
+            let result = match vm.parse_call(mod_name, &line) {
+                Ok((func_name, args)) => vm.run_func(mod_name, func_name, args).await,
+                Err(e) => Err(e),
+            };
+            Some((result, Some((vm, lines))))
+        })
+        .boxed_local()
+    }
+
+    // Parses a serialized call in the form of `<func_name> [<arg>...]` by the type of the target function.
+    fn parse_call(
+        &self,
+        mod_name: Option<&str>,
+        line: &str,
+    ) -> WasmEdgeResult<(String, Vec<WasmValue>)> {
+        let mut tokens = line.split_whitespace();
+        let func_name = tokens.next().unwrap_or_default();
+        let tokens: Vec<&str> = tokens.collect();
+
+        let ty = self.func_type(mod_name, func_name)?;
+        if tokens.len() != ty.args_len() {
+            return Err(Box::new(WasmEdgeError::Operation(format!(
+                "failed to parse the call `{line}`: expected {} arguments, found {}",
+                ty.args_len(),
+                tokens.len()
+            ))));
+        }
+
+        let args = ty
+            .args()
+            .iter()
+            .zip(tokens)
+            .map(|(ty, token)| {
+                let value = match ty {
+                    ValType::I32 => token.parse().ok().map(WasmValue::from_i32),
+                    ValType::I64 => token.parse().ok().map(WasmValue::from_i64),
+                    ValType::F32 => token.parse().ok().map(WasmValue::from_f32),
+                    ValType::F64 => token.parse().ok().map(WasmValue::from_f64),
+                    _ => None,
+                };
+                value.ok_or_else(|| {
+                    Box::new(WasmEdgeError::Operation(format!(
+                        "failed to parse the call `{line}`: invalid {ty:?} argument `{token}`"
+                    )))
+                })
+            })
+            .collect::<WasmEdgeResult<Vec<_>>>()?;
+
+        Ok((func_name.to_string(), args))
+    }
+
+    fn func_type(&self, mod_name: Option<&str>, func_name: &str) -> WasmEdgeResult<FuncType> {
+        let func = match mod_name {
+            Some(mod_name) => match self.store.instances.get(mod_name) {
+                Some(inst) => inst.get_func(func_name)?,
+                None => self
+                    .store
+                    .wasm_instance_map
+                    .get(mod_name)
+                    .ok_or_else(|| {
+                        Box::new(WasmEdgeError::Vm(VmError::NotFoundModule(mod_name.into())))
+                    })?
+                    .get_func(func_name)?,
+            },
+            None => self
+                .active_instance
+                .as_ref()
+                .ok_or(Box::new(WasmEdgeError::Vm(VmError::NotFoundActiveModule)))?
+                .get_func(func_name)?,
+        };
+        func.ty().ok_or_else(|| {
+            Box::new(WasmEdgeError::Vm(VmError::NotFoundFuncType(
+                func_name.into(),
+            )))
+        })
+    }
+
     /// Returns a reference to the internal [store](crate::Store) from this vm.
     pub fn store(&self) -> &Store<'inst, T> {
         &self.store
@@ -362,6 +489,34 @@ mod tests {
         assert_eq!(returns[0].to_i32(), 89);
     }
 
+    #[tokio::test]
+    async fn test_vm_run_func_stream() {
+        use futures::StreamExt;
+
+        let mut vm = Vm::new(
+            Store::new(None, HashMap::<String, &mut (dyn AsyncInst + Send)>::new()).unwrap(),
+        );
+        let wasm_bytes = wat2wasm(
+            br#"(module
+              (func (export "add") (param i32 i32) (result i32)
+                (i32.add (local.get 0) (local.get 1)))
+            )"#,
+        )
+        .unwrap();
+        let module = Module::from_bytes(None, wasm_bytes).unwrap();
+        vm.register_module(Some("math"), module).unwrap();
+
+        let input: &[u8] = b"add 1 2\n\nadd 3 x\nmissing\nadd 40 2\n";
+        let results: Vec<_> = vm.run_func_stream(Some("math"), input).collect().await;
+        assert_eq!(results.len(), 4);
+        assert_eq!(results[0].as_ref().unwrap()[0].to_i32(), 3);
+        // the invalid argument
+        assert!(results[1].is_err());
+        // the function is not found
+        assert!(results[2].is_err());
+        assert_eq!(results[3].as_ref().unwrap()[0].to_i32(), 42);
+    }
+
     #[tokio::test]
     async fn test_vm_run_func_in_named_module_instance() {
         // create a Vm context
@@ -0,0 +1,183 @@
+//! Defines the helpers for benchmarking the calls of WebAssembly functions.
+
+use std::time::{Duration, Instant};
+
+use crate::WasmEdgeResult;
+
+/// A [Timer] runs a function call repeatedly, and collects the latency of each iteration, which is useful for the performance regression tests.
+///
+/// # Example
+///
+/// ```rust
+/// use wasmedge_sdk::{bench::Timer, params, testing::WasmTestHarness, WasmVal};
+///
+/// let mut harness = WasmTestHarness::from_wat(
+///     br#"(module
+///       (func (export "add") (param i32 i32) (result i32)
+///         (i32.add (local.get 0) (local.get 1)))
+///     )"#,
+/// );
+///
+/// let mut timer = Timer::new(100);
+/// timer.measure(|| harness.try_call("add", params!(1, 2))).unwrap();
+///
+/// let report = timer.report();
+/// assert!(report.p50 <= report.p99);
+/// println!("{report}");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Timer {
+    iterations: u32,
+    samples: Vec<Duration>,
+}
+impl Timer {
+    /// Creates a new [Timer].
+    ///
+    /// # Argument
+    ///
+    /// * `iterations` - The number of the iterations to run in each [measure](Timer::measure).
+    pub fn new(iterations: u32) -> Self {
+        Self {
+            iterations,
+            samples: Vec::with_capacity(iterations as usize),
+        }
+    }
+
+    /// Runs the given function `iterations` times, and collects the elapsed time of each iteration. The samples are accumulated across the calls of this method.
+    ///
+    /// # Argument
+    ///
+    /// * `f` - The function to measure, for example, a closure calling [Vm::run_func](crate::vm::Vm::run_func).
+    ///
+    /// # Error
+    ///
+    /// If any iteration fails, then the error is returned and the remaining iterations are skipped. The samples of the succeeded iterations are kept.
+    pub fn measure<F, R>(&mut self, mut f: F) -> WasmEdgeResult<()>
+    where
+        F: FnMut() -> WasmEdgeResult<R>,
+    {
+        for _ in 0..self.iterations {
+            let start = Instant::now();
+            f()?;
+            self.samples.push(start.elapsed());
+        }
+        Ok(())
+    }
+
+    /// Returns the collected samples.
+    pub fn samples(&self) -> &[Duration] {
+        &self.samples
+    }
+
+    /// Removes all the collected samples.
+    pub fn reset(&mut self) {
+        self.samples.clear();
+    }
+
+    /// Computes the statistics over the collected samples. If no sample is collected, then all the statistics are zero.
+    pub fn report(&self) -> TimerReport {
+        TimerReport::from_samples(&self.samples)
+    }
+}
+
+/// The statistics of the samples collected by a [Timer].
+///
+/// The percentiles are computed with the nearest-rank method, and the standard deviation is the population standard deviation.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TimerReport {
+    /// The number of the samples.
+    pub samples: usize,
+    /// The 50th percentile (median) latency.
+    pub p50: Duration,
+    /// The 90th percentile latency.
+    pub p90: Duration,
+    /// The 99th percentile latency.
+    pub p99: Duration,
+    /// The mean latency.
+    pub mean: Duration,
+    /// The standard deviation of the latencies.
+    pub stddev: Duration,
+}
+impl TimerReport {
+    fn from_samples(samples: &[Duration]) -> Self {
+        if samples.is_empty() {
+            return Self::default();
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+        let percentile = |p: usize| {
+            let rank = (p * sorted.len()).div_ceil(100).max(1);
+            sorted[rank - 1]
+        };
+
+        let n = samples.len() as f64;
+        let mean = samples.iter().map(Duration::as_secs_f64).sum::<f64>() / n;
+        let variance = samples
+            .iter()
+            .map(|d| (d.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / n;
+
+        Self {
+            samples: samples.len(),
+            p50: percentile(50),
+            p90: percentile(90),
+            p99: percentile(99),
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+impl std::fmt::Display for TimerReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "samples: {}, p50: {:?}, p90: {:?}, p99: {:?}, mean: {:?}, stddev: {:?}",
+            self.samples, self.p50, self.p90, self.p99, self.mean, self.stddev
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::WasmEdgeError;
+
+    #[test]
+    fn test_timer_report() {
+        // no sample is collected
+        assert_eq!(Timer::new(10).report(), TimerReport::default());
+
+        let samples: Vec<Duration> = (1..=100).rev().map(Duration::from_millis).collect();
+        let report = TimerReport::from_samples(&samples);
+        assert_eq!(report.samples, 100);
+        assert_eq!(report.p50, Duration::from_millis(50));
+        assert_eq!(report.p90, Duration::from_millis(90));
+        assert_eq!(report.p99, Duration::from_millis(99));
+        assert_eq!(report.mean.as_micros(), 50_500);
+        assert_eq!(report.stddev.as_micros(), 28_866);
+    }
+
+    #[test]
+    fn test_timer_measure() {
+        let mut timer = Timer::new(5);
+        timer.measure(|| Ok(())).unwrap();
+        timer.measure(|| Ok(())).unwrap();
+        assert_eq!(timer.samples().len(), 10);
+        assert_eq!(timer.report().samples, 10);
+
+        // the failed iteration is not sampled
+        timer.reset();
+        let mut count = 0;
+        let result = timer.measure(|| {
+            count += 1;
+            match count {
+                3 => Err(Box::new(WasmEdgeError::Operation("failed".into()))),
+                _ => Ok(()),
+            }
+        });
+        assert!(result.is_err());
+        assert_eq!(timer.samples().len(), 2);
+    }
+}
@@ -0,0 +1,184 @@
+//! Defines the cache of the AOT-compiled WebAssembly modules.
+
+use crate::{
+    compiler::{aot_file_path, Compiler},
+    config::Config,
+    error::WasmEdgeError,
+    utils::CoreVersion,
+    Module, WasmEdgeResult,
+};
+use sha2::{Digest, Sha256};
+use std::{
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+// the counter to name the temporary artifacts uniquely in this process
+static TEMP_FILE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// A [ModuleCache] stores the AOT-compiled artifacts of WebAssembly modules in a directory, so that loading the same module again skips the compilation.
+///
+/// The artifacts are keyed on the SHA-256 hash of the input bytes, the options of the compiler which change the artifacts, and the version of WasmEdge, so that the artifacts compiled differently are not mixed up. An artifact is compiled into a temporary file and then renamed, so that a concurrent [load](ModuleCache::load) never sees a partially written artifact.
+#[derive(Debug)]
+pub struct ModuleCache {
+    cache_dir: PathBuf,
+    compiler: Compiler,
+}
+impl ModuleCache {
+    /// Creates a new [ModuleCache].
+    ///
+    /// # Arguments
+    ///
+    /// * `cache_dir` - The directory to store the AOT-compiled artifacts. It is created on the first cache miss if it does not exist.
+    ///
+    /// * `compiler` - The AOT compiler used to compile the modules on cache misses.
+    pub fn new(cache_dir: &Path, compiler: Compiler) -> Self {
+        Self {
+            cache_dir: cache_dir.to_path_buf(),
+            compiler,
+        }
+    }
+
+    /// Returns the directory of the AOT-compiled artifacts.
+    pub fn cache_dir(&self) -> &Path {
+        &self.cache_dir
+    }
+
+    /// Loads a [module](crate::Module) from the given wasm bytes. If the AOT-compiled artifact of the bytes is in the cache, then the module is loaded from the artifact; otherwise, the bytes are compiled and the artifact is stored in the cache first.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The global configuration used to load the module.
+    ///
+    /// * `bytes` - The in-memory wasm bytes.
+    ///
+    /// # Error
+    ///
+    /// If fail to create the cache directory, compile the bytes, store the artifact, or load the module, then an error is returned.
+    pub fn load(&self, config: Option<&Config>, bytes: impl AsRef<[u8]>) -> WasmEdgeResult<Module> {
+        let bytes = bytes.as_ref();
+        let key = self.key(bytes);
+
+        let aot_file = aot_file_path(&self.cache_dir, &key);
+        if !aot_file.is_file() {
+            std::fs::create_dir_all(&self.cache_dir)
+                .map_err(|err| Box::new(WasmEdgeError::Operation(err.to_string())))?;
+
+            let temp_name = format!(
+                "{key}.{}-{}.tmp",
+                std::process::id(),
+                TEMP_FILE_COUNTER.fetch_add(1, Ordering::Relaxed)
+            );
+            let temp_file = aot_file_path(&self.cache_dir, &temp_name);
+            let result = self
+                .compiler
+                .compile_from_bytes(bytes, &temp_name, &self.cache_dir)
+                .and_then(|_| {
+                    std::fs::rename(&temp_file, &aot_file)
+                        .map_err(|err| Box::new(WasmEdgeError::Operation(err.to_string())))
+                });
+            if let Err(err) = result {
+                let _ = std::fs::remove_file(&temp_file);
+                return Err(err);
+            }
+        }
+
+        Module::from_file(config, aot_file)
+    }
+
+    /// Returns the path of the cached AOT-compiled artifact of the given wasm bytes, if any.
+    ///
+    /// # Argument
+    ///
+    /// * `bytes` - The in-memory wasm bytes.
+    pub fn cached_file(&self, bytes: impl AsRef<[u8]>) -> Option<PathBuf> {
+        let aot_file = aot_file_path(&self.cache_dir, self.key(bytes.as_ref()));
+        aot_file.is_file().then_some(aot_file)
+    }
+
+    // the lowercase hex string of the SHA-256 hash of the bytes, the compiler options and the WasmEdge version
+    fn key(&self, bytes: &[u8]) -> String {
+        let settings = format!(
+            "{:?}:{:?}:{}:{}:{}",
+            self.compiler.optimization_level(),
+            self.compiler.out_format(),
+            self.compiler.generic_binary_enabled(),
+            self.compiler.interruptible_enabled(),
+            CoreVersion::version_string(),
+        );
+        Sha256::new()
+            .chain_update(bytes)
+            .chain_update(settings)
+            .finalize()
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect()
+    }
+}
+
+#[cfg(not(feature = "async"))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{params, vm::SyncInst, wat2wasm, Store, Vm, WasmVal};
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_module_cache() -> Result<(), Box<dyn std::error::Error>> {
+        let wasm_bytes = wat2wasm(
+            br#"(module
+              (func (export "add") (param i32 i32) (result i32)
+                (i32.add (local.get 0) (local.get 1)))
+            )"#,
+        )?;
+
+        let cache_dir = std::env::temp_dir().join(format!(
+            "wasmedge_sdk_test_module_cache_{}_{}",
+            std::process::id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)?
+                .as_nanos()
+        ));
+        let cache = ModuleCache::new(&cache_dir, Compiler::new(None)?);
+        assert_eq!(cache.cache_dir(), cache_dir.as_path());
+        assert!(cache.cached_file(&wasm_bytes).is_none());
+
+        // cache miss
+        let module = cache.load(None, &wasm_bytes)?;
+        let aot_file = cache.cached_file(&wasm_bytes);
+        assert!(aot_file.is_some());
+        let aot_file = aot_file.unwrap();
+        assert!(aot_file.starts_with(&cache_dir));
+
+        // cache hit
+        let modified = std::fs::metadata(&aot_file)?.modified()?;
+        let cached_module = cache.load(None, &wasm_bytes)?;
+        assert_eq!(std::fs::metadata(&aot_file)?.modified()?, modified);
+
+        // no temporary artifact is left
+        assert_eq!(std::fs::read_dir(&cache_dir)?.count(), 1);
+
+        // the artifacts compiled with other options are keyed differently
+        let config =
+            crate::config::ConfigBuilder::new(crate::config::CommonConfigOptions::default())
+                .with_compiler_config(
+                    crate::config::CompilerConfigOptions::default()
+                        .optimization_level(crate::CompilerOptimizationLevel::O0),
+                )
+                .build()?;
+        let o0_cache = ModuleCache::new(&cache_dir, Compiler::new(Some(&config))?);
+        assert!(o0_cache.cached_file(&wasm_bytes).is_none());
+
+        for module in [module, cached_module] {
+            let mut vm =
+                Vm::new(Store::new(None, HashMap::<String, &mut dyn SyncInst>::new()).unwrap());
+            vm.register_module(None, module)?;
+            let returns = vm.run_func(None, "add", params!(1, 2))?;
+            assert_eq!(returns[0].to_i32(), 3);
+        }
+
+        std::fs::remove_dir_all(&cache_dir)?;
+
+        Ok(())
+    }
+}
@@ -1,6 +1,8 @@
 //! Defines WasmEdge ahead-of-time compiler.
 
-use crate::{config::Config, WasmEdgeResult};
+#[cfg(feature = "wasm-tools")]
+use crate::error::{CompileError, WasmEdgeError};
+use crate::{config::Config, CompilerOptimizationLevel, CompilerOutputFormat, WasmEdgeResult};
 use std::path::{Path, PathBuf};
 use wasmedge_sys as sys;
 
@@ -8,6 +10,10 @@ use wasmedge_sys as sys;
 #[derive(Debug)]
 pub struct Compiler {
     pub(crate) inner: sys::Compiler,
+    optimization_level: CompilerOptimizationLevel,
+    out_format: CompilerOutputFormat,
+    generic_binary: bool,
+    interruptible: bool,
 }
 impl Compiler {
     /// Creates a new AOT compiler.
@@ -21,7 +27,43 @@ impl Compiler {
             None => sys::Compiler::create(None)?,
         };
 
-        Ok(Self { inner })
+        // the options are kept, since they cannot be read back from the compiler context
+        let default_config;
+        let config = match config {
+            Some(cfg) => &cfg.inner,
+            None => {
+                default_config = sys::Config::create()?;
+                &default_config
+            }
+        };
+
+        Ok(Self {
+            inner,
+            optimization_level: config.get_aot_optimization_level(),
+            out_format: config.get_aot_compiler_output_format(),
+            generic_binary: config.generic_binary_enabled(),
+            interruptible: config.interruptible_enabled(),
+        })
+    }
+
+    /// Returns the optimization level of this compiler.
+    pub fn optimization_level(&self) -> CompilerOptimizationLevel {
+        self.optimization_level
+    }
+
+    /// Returns the output binary format of this compiler.
+    pub fn out_format(&self) -> CompilerOutputFormat {
+        self.out_format
+    }
+
+    /// Checks if this compiler generates the generic binary or not.
+    pub fn generic_binary_enabled(&self) -> bool {
+        self.generic_binary
+    }
+
+    /// Checks if this compiler generates the interruptible binary or not.
+    pub fn interruptible_enabled(&self) -> bool {
+        self.interruptible
     }
 
     /// Compiles the given wasm file into a shared library file (*.so in Linux, *.dylib in macOS, or *.dll in Windows). The file path of the generated shared library file will be returned if the method works successfully.
@@ -36,23 +78,24 @@ impl Compiler {
     ///
     /// # Error
     ///
-    /// If fail to compile, then an error is returned.
+    /// If fail to compile, then an error is returned. With the `wasm-tools` feature, if the failure is caused by an invalid function body, then [CompileError::FailedFunction](crate::error::CompileError) is returned with the location of the failed instruction.
     pub fn compile_from_file(
         &self,
         wasm_file: impl AsRef<Path>,
         filename: impl AsRef<str>,
         out_dir: impl AsRef<Path>,
     ) -> WasmEdgeResult<PathBuf> {
-        #[cfg(target_os = "linux")]
-        let extension = "so";
-        #[cfg(target_os = "macos")]
-        let extension = "dylib";
-        #[cfg(target_os = "windows")]
-        let extension = "dll";
-        let aot_file = out_dir
-            .as_ref()
-            .join(format!("{}.{}", filename.as_ref(), extension));
-        self.inner.compile_from_file(wasm_file, &aot_file)?;
+        let aot_file = aot_file_path(out_dir, filename);
+        if let Err(e) = self.inner.compile_from_file(wasm_file.as_ref(), &aot_file) {
+            #[cfg(feature = "wasm-tools")]
+            if let Some(err) = std::fs::read(wasm_file.as_ref())
+                .ok()
+                .and_then(|bytes| locate_failed_function(&bytes))
+            {
+                return Err(Box::new(WasmEdgeError::Compile(err)));
+            }
+            return Err(e);
+        }
 
         Ok(aot_file)
     }
@@ -69,28 +112,61 @@ impl Compiler {
     ///
     /// # Error
     ///
-    /// If fail to compile, then an error is returned.
+    /// If fail to compile, then an error is returned. With the `wasm-tools` feature, if the failure is caused by an invalid function body, then [CompileError::FailedFunction](crate::error::CompileError) is returned with the location of the failed instruction.
     pub fn compile_from_bytes(
         &self,
         bytes: impl AsRef<[u8]>,
         filename: impl AsRef<str>,
         out_dir: impl AsRef<Path>,
     ) -> WasmEdgeResult<PathBuf> {
-        #[cfg(target_os = "linux")]
-        let extension = "so";
-        #[cfg(target_os = "macos")]
-        let extension = "dylib";
-        #[cfg(target_os = "windows")]
-        let extension = "dll";
-        let aot_file = out_dir
-            .as_ref()
-            .join(format!("{}.{}", filename.as_ref(), extension));
-        self.inner.compile_from_bytes(bytes, &aot_file)?;
+        let aot_file = aot_file_path(out_dir, filename);
+        if let Err(e) = self.inner.compile_from_bytes(bytes.as_ref(), &aot_file) {
+            #[cfg(feature = "wasm-tools")]
+            if let Some(err) = locate_failed_function(bytes.as_ref()) {
+                return Err(Box::new(WasmEdgeError::Compile(err)));
+            }
+            return Err(e);
+        }
 
         Ok(aot_file)
     }
 }
 
+// The WasmEdge C API only reports the error code of a failed compilation, so the function bodies are validated again to find the location of the failure.
+#[cfg(feature = "wasm-tools")]
+fn locate_failed_function(bytes: &[u8]) -> Option<CompileError> {
+    use wasmparser::{Parser, ValidPayload, Validator};
+
+    let mut validator = Validator::new();
+    for payload in Parser::new(0).parse_all(bytes) {
+        let payload = payload.ok()?;
+        if let ValidPayload::Func(func, body) = validator.payload(&payload).ok()? {
+            let mut func_validator = func.into_validator(Default::default());
+            if let Err(e) = func_validator.validate(&body) {
+                return Some(CompileError::FailedFunction {
+                    index: func_validator.index(),
+                    offset: e.offset() as u32,
+                    message: e.message().to_string(),
+                });
+            }
+        }
+    }
+    None
+}
+
+/// Returns the path of the shared library file (*.so in Linux, *.dylib in macOS, or *.dll in Windows) with the given filename in the given directory.
+pub(crate) fn aot_file_path(out_dir: impl AsRef<Path>, filename: impl AsRef<str>) -> PathBuf {
+    #[cfg(target_os = "linux")]
+    let extension = "so";
+    #[cfg(target_os = "macos")]
+    let extension = "dylib";
+    #[cfg(target_os = "windows")]
+    let extension = "dll";
+    out_dir
+        .as_ref()
+        .join(format!("{}.{}", filename.as_ref(), extension))
+}
+
 #[cfg(not(feature = "async"))]
 #[cfg(test)]
 mod tests {
@@ -218,4 +294,29 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    #[cfg(feature = "wasm-tools")]
+    fn test_compiler_failed_function() {
+        use crate::error::{CompileError, WasmEdgeError};
+
+        // the body of the second function returns a mismatched type
+        let wasm_bytes = wat2wasm(
+            br#"(module
+              (func (result i32) (i32.const 0))
+              (func (result i32) (i64.const 0))
+            )"#,
+        )
+        .unwrap();
+
+        let compiler = Compiler::new(None).unwrap();
+        let out_dir = std::env::current_dir().unwrap();
+        let result = compiler.compile_from_bytes(wasm_bytes, "aot_failed_function", out_dir);
+        match *result.unwrap_err() {
+            WasmEdgeError::Compile(CompileError::FailedFunction { index, .. }) => {
+                assert_eq!(index, 1)
+            }
+            e => panic!("unexpected error: {e}"),
+        }
+    }
 }
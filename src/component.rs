@@ -0,0 +1,139 @@
+//! Defines the helpers for assembling [Component Model](https://component-model.bytecodealliance.org/) components from core WebAssembly modules.
+
+use crate::{error::WasmEdgeError, WasmEdgeResult};
+use wit_component::{ComponentEncoder, Linker};
+
+/// A [ComponentComposer] wraps core WebAssembly modules into a component binary, which lifts and lowers the functions of the modules with the canonical ABI.
+///
+/// The core modules must carry the `component-type` custom sections describing their WIT worlds, which are usually embedded by the guest toolchains, such as `wit-bindgen`. The adapters, such as the WASI preview1 adapter, implement the imports of the core modules in terms of the component interfaces.
+///
+/// If a single core module is added, it becomes the main module of the component. If more than one core module is added, they are linked as shared-everything dynamic libraries, which must be built with the `dylink.0` custom sections.
+#[derive(Debug, Default)]
+pub struct ComponentComposer {
+    modules: Vec<(String, Vec<u8>)>,
+    adapters: Vec<(String, Vec<u8>)>,
+}
+impl ComponentComposer {
+    /// Creates a new empty [ComponentComposer].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a core module to be wrapped into the component.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the core module.
+    ///
+    /// * `bytes` - The in-memory bytes of the core module.
+    pub fn add_module(mut self, name: impl AsRef<str>, bytes: impl AsRef<[u8]>) -> Self {
+        self.modules
+            .push((name.as_ref().to_string(), bytes.as_ref().to_vec()));
+        self
+    }
+
+    /// Adds an adapter module, which provides the imports of the core modules from the module `name`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The module name the adapter is imported as by the core modules, such as `wasi_snapshot_preview1`.
+    ///
+    /// * `bytes` - The in-memory bytes of the adapter module.
+    pub fn add_adapter(mut self, name: impl AsRef<str>, bytes: impl AsRef<[u8]>) -> Self {
+        self.adapters
+            .push((name.as_ref().to_string(), bytes.as_ref().to_vec()));
+        self
+    }
+
+    /// Composes the added core modules and adapters, and returns the bytes of the component binary.
+    ///
+    /// # Error
+    ///
+    /// If no core module is added, or fail to encode the component, then an error is returned.
+    pub fn compose(&self) -> WasmEdgeResult<Vec<u8>> {
+        let compose_error = |e: anyhow::Error| {
+            Box::new(WasmEdgeError::Operation(format!(
+                "failed to compose the component: {e:#}"
+            )))
+        };
+
+        match self.modules.as_slice() {
+            [] => Err(Box::new(WasmEdgeError::Operation(
+                "failed to compose the component: no core module is added".into(),
+            ))),
+            [(_, module)] => {
+                let mut encoder = ComponentEncoder::default();
+                encoder
+                    .validate(true)
+                    .module(module)
+                    .map_err(compose_error)?;
+                for (name, adapter) in &self.adapters {
+                    encoder.adapter(name, adapter).map_err(compose_error)?;
+                }
+                encoder.encode().map_err(compose_error)
+            }
+            modules => {
+                let mut linker = Linker::default();
+                linker.encoder().validate(true);
+                for (name, module) in modules {
+                    linker.library(name, module, false).map_err(compose_error)?;
+                }
+                for (name, adapter) in &self.adapters {
+                    linker
+                        .encoder()
+                        .adapter(name, adapter)
+                        .map_err(compose_error)?;
+                }
+                linker.encode().map_err(compose_error)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wat2wasm;
+    use wit_component::{embed_component_metadata, StringEncoding};
+    use wit_parser::Resolve;
+
+    #[test]
+    fn test_component_composer() {
+        // a core module carrying the WIT world it implements
+        let mut resolve = Resolve::new();
+        let pkg = resolve
+            .push_str(
+                "<inline>.wit",
+                r#"
+                package example:math;
+
+                world math {
+                    export add: func(a: s32, b: s32) -> s32;
+                }
+                "#,
+            )
+            .unwrap();
+        let world = resolve.select_world(&[pkg], Some("math")).unwrap();
+        let mut module = wat2wasm(
+            br#"(module
+              (func (export "add") (param i32 i32) (result i32)
+                (i32.add (local.get 0) (local.get 1)))
+            )"#,
+        )
+        .unwrap()
+        .into_owned();
+        embed_component_metadata(&mut module, &resolve, world, StringEncoding::UTF8, false)
+            .unwrap();
+
+        let component = ComponentComposer::new()
+            .add_module("math", &module)
+            .compose()
+            .unwrap();
+        // the component binary has the layer field set to 1
+        assert_eq!(&component[..4], b"\0asm");
+        assert_eq!(&component[6..8], &[0x01, 0x00]);
+
+        // no core module is added
+        assert!(ComponentComposer::new().compose().is_err());
+    }
+}
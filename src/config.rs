@@ -52,6 +52,19 @@ impl ConfigBuilder {
         }
     }
 
+    /// Sets the maximum number of the memory pages available. This is a shortcut of setting [RuntimeConfigOptions::max_memory_pages] via [with_runtime_config](ConfigBuilder::with_runtime_config).
+    ///
+    /// # Argument
+    ///
+    /// - `count` specifies the page count (64KB per page).
+    pub fn with_max_memory_pages(self, count: u32) -> Self {
+        let runtime_config = self.runtime_config.unwrap_or_default();
+        Self {
+            runtime_config: Some(runtime_config.max_memory_pages(count)),
+            ..self
+        }
+    }
+
     /// Sets the [CompilerConfigOptions] for the [ConfigBuilder].
     ///
     /// # Argument
@@ -81,10 +94,12 @@ impl ConfigBuilder {
         inner.reference_types(self.common_config.reference_types);
         inner.simd(self.common_config.simd);
         inner.multi_memories(self.common_config.multi_memories);
+        inner.memory64(self.common_config.memory64);
         inner.threads(self.common_config.threads);
-        inner.gc(self.common_config.gc);
         inner.tail_call(self.common_config.tail_call);
+        // GC depends on FunctionReferences, and enabling GC also enables it, so GC must be set last
         inner.function_references(self.common_config.function_references);
+        inner.gc(self.common_config.gc);
         inner.interpreter_mode(self.common_config.interpreter_mode);
 
         if let Some(stat_config) = self.stat_config {
@@ -195,11 +210,21 @@ impl Config {
         self.inner.multi_memories_enabled()
     }
 
+    /// Checks if the Memory64 option turns on or not.
+    pub fn memory64_enabled(&self) -> bool {
+        self.inner.memory64_enabled()
+    }
+
     /// Checks if the Threads option turns on or not.
     pub fn threads_enabled(&self) -> bool {
         self.inner.threads_enabled()
     }
 
+    /// Checks if the GC option turns on or not.
+    pub fn gc_enabled(&self) -> bool {
+        self.inner.gc_enabled()
+    }
+
     /// Checks if the TailCall option turns on or not.
     pub fn tail_call_enabled(&self) -> bool {
         self.inner.tail_call_enabled()
@@ -264,6 +289,45 @@ impl Config {
     pub fn time_measuring_enabled(&self) -> bool {
         self.inner.is_time_measuring()
     }
+
+    /// Returns a copy of the underlying configuration with the `Threads` option turned on.
+    #[cfg(feature = "threads")]
+    pub(crate) fn copy_with_threads(&self) -> WasmEdgeResult<sys::Config> {
+        let src = self.inner.as_ref();
+        let mut inner = sys::Config::create()?;
+        inner.mutable_globals(src.mutable_globals_enabled());
+        inner.non_trap_conversions(src.non_trap_conversions_enabled());
+        inner.sign_extension_operators(src.sign_extension_operators_enabled());
+        inner.multi_value(src.multi_value_enabled());
+        inner.bulk_memory_operations(src.bulk_memory_operations_enabled());
+        inner.reference_types(src.reference_types_enabled());
+        inner.simd(src.simd_enabled());
+        inner.multi_memories(src.multi_memories_enabled());
+        inner.memory64(src.memory64_enabled());
+        inner.threads(true);
+        inner.tail_call(src.tail_call_enabled());
+        inner.exception_handling(src.exception_handling_enabled());
+        inner.annotations(src.annotations_enabled());
+        // GC must be set last, see `ConfigBuilder::build`
+        inner.function_references(src.function_references_enabled());
+        inner.gc(src.gc_enabled());
+        inner.interpreter_mode(src.interpreter_mode_enabled());
+
+        inner.count_instructions(src.is_instruction_counting());
+        inner.measure_cost(src.is_cost_measuring());
+        inner.measure_time(src.is_time_measuring());
+        #[cfg(feature = "aot")]
+        {
+            inner.set_aot_compiler_output_format(src.get_aot_compiler_output_format());
+            inner.set_aot_optimization_level(src.get_aot_optimization_level());
+            inner.dump_ir(src.dump_ir_enabled());
+            inner.generic_binary(src.generic_binary_enabled());
+            inner.interruptible(src.interruptible_enabled());
+        }
+        inner.set_max_memory_pages(src.get_max_memory_pages());
+
+        Ok(inner)
+    }
 }
 
 /// Defines the common configuration options.
@@ -307,6 +371,7 @@ pub struct CommonConfigOptions {
     reference_types: bool,
     simd: bool,
     multi_memories: bool,
+    memory64: bool,
     threads: bool,
     gc: bool,
     tail_call: bool,
@@ -325,6 +390,7 @@ impl CommonConfigOptions {
     /// * reference_types: true,
     /// * simd: true,
     /// * multi_memories: false,
+    /// * memory64: false,
     /// * threads: false,
     /// * gc: false,
     /// * tail_call: false,
@@ -340,6 +406,7 @@ impl CommonConfigOptions {
             reference_types: true,
             simd: true,
             multi_memories: false,
+            memory64: false,
             threads: false,
             gc: false,
             tail_call: false,
@@ -444,6 +511,20 @@ impl CommonConfigOptions {
         }
     }
 
+    /// Enables or disables the Memory64 option.
+    ///
+    /// The [Memory64 proposal](https://github.com/WebAssembly/memory64/blob/main/proposals/memory64/Overview.md) allows the modules to declare the memories indexed with `i64`, such as `(memory i64 1)`. Notice that the option only affects the loading and validation of the modules: the memory types of the WasmEdge C API carry the 32-bit limits, so a [MemoryType](crate::MemoryType) always describes a memory indexed with `i32`.
+    ///
+    /// # Argument
+    ///
+    /// - `enable` specifies if the option turns on or not.
+    pub fn memory64(self, enable: bool) -> Self {
+        Self {
+            memory64: enable,
+            ..self
+        }
+    }
+
     /// Enables or disables the Threads option.
     ///
     /// # Argument
@@ -458,6 +539,8 @@ impl CommonConfigOptions {
 
     /// Enables or disables the GC option.
     ///
+    /// The GC types reference each other with the typed references, therefore, enabling the option also enables the ReferenceTypes and FunctionReferences options, no matter how they are set.
+    ///
     /// # Argument
     ///
     /// - `enable` specifies if the option turns on or not.
@@ -479,6 +562,8 @@ impl CommonConfigOptions {
 
     /// Enables or disables the FunctionReferences option.
     ///
+    /// The [Typed Function References proposal](https://github.com/WebAssembly/function-references/blob/main/proposals/function-references/Overview.md) adds the typed references, such as `(ref $t)`, and the instructions, such as `call_ref`, which are not covered by the ReferenceTypes option. Enabling the option also enables the ReferenceTypes option. The state of the option can be checked with [Config::function_references_enabled].
+    ///
     /// # Argument
     ///
     /// * `enable` - Whether the option turns on or not.
@@ -491,6 +576,8 @@ impl CommonConfigOptions {
 
     /// Enables or disables the `ForceInterpreter` option.
     ///
+    /// If the option turns on, the functions are always executed by the interpreter, even if the loaded module carries the AOT-compiled native code, such as the universal wasm format and the shared library format. This is useful to debug the behavioural discrepancies between the interpreted and the AOT-compiled execution. The option takes effect on the [modules](crate::Module) loaded and the [stores](crate::Store) created with the built [Config].
+    ///
     /// # Argument
     ///
     /// * `enable` - Whether the option turns on or not.
@@ -513,6 +600,7 @@ impl Default for CommonConfigOptions {
     /// * reference_types: true,
     /// * simd: true,
     /// * multi_memories: false,
+    /// * memory64: false,
     /// * threads: false,
     /// * tail_call: false,
     /// * function_references: false,
@@ -748,6 +836,7 @@ mod tests {
             .sign_extension_operators(true)
             .simd(true)
             .multi_memories(true)
+            .memory64(true)
             .interpreter_mode(true);
 
         let compiler_options = CompilerConfigOptions::default()
@@ -781,6 +870,7 @@ mod tests {
         assert!(config.sign_extension_operators_enabled());
         assert!(config.simd_enabled());
         assert!(config.multi_memories_enabled());
+        assert!(config.memory64_enabled());
         assert!(config.interpreter_mode_enabled());
 
         // check compiler config options
@@ -833,4 +923,24 @@ mod tests {
         assert!(!config.time_measuring_enabled());
         assert_eq!(config_copied.max_memory_pages(), 1024);
     }
+
+    #[test]
+    fn test_config_max_memory_pages() {
+        let result = ConfigBuilder::new(CommonConfigOptions::default())
+            .with_max_memory_pages(10)
+            .build();
+        assert!(result.is_ok());
+        let config = result.unwrap();
+        assert_eq!(config.max_memory_pages(), 10);
+
+        // the shortcut overrides the page count of the runtime config options
+        let runtime_config = RuntimeConfigOptions::default().max_memory_pages(1024);
+        let result = ConfigBuilder::new(CommonConfigOptions::default())
+            .with_runtime_config(runtime_config)
+            .with_max_memory_pages(20)
+            .build();
+        assert!(result.is_ok());
+        let config = result.unwrap();
+        assert_eq!(config.max_memory_pages(), 20);
+    }
 }
@@ -1,10 +1,16 @@
-use crate::{io::WasmValTypeList, FuncType, WasmEdgeResult};
-pub use sys::AsInstance;
+use crate::{
+    error::{CoreError, CoreExecutionError},
+    io::{FromWasmValList, WasmValList, WasmValTypeList},
+    CallingFrame, FuncType, GlobalType, MemoryType, Mutability, WasmEdgeResult, WasmValue,
+};
 use sys::Function;
+pub use sys::{AsFunc, AsInstance};
 use wasmedge_sys::{self as sys};
 
 /// Creates a [import object](crate::ImportObject).
 ///
+/// The host functions added by this builder are synchronous. To build an import object with the async host functions for the async `Vm`, use `r#async::import::ImportObjectBuilder` instead, which is available with the `async` feature on Linux, and whose `build` returns an `AsyncImportObject`.
+///
 #[derive(Debug)]
 pub struct ImportObjectBuilder<Data> {
     import_object: ImportObject<Data>,
@@ -81,6 +87,83 @@ impl<Data> ImportObjectBuilder<Data> {
         Ok(self)
     }
 
+    /// Adds a typed [host function](crate::Func) to the [ImportObject] to create. The [function type](crate::FuncType) is inferred from the Rust signature of the native function, so neither an explicit [FuncType] nor type annotations are required.
+    ///
+    /// N.B. that this function can be used in thread-safe scenarios.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The exported name of the [host function](crate::Func) to add.
+    ///
+    /// * `real_func` - The native function, which takes the arguments as a value or a tuple of values and returns the same.
+    ///
+    /// # error
+    ///
+    /// If fail to create or add the [host function](crate::Func), then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use wasmedge_sdk::{
+    ///     error::CoreError, CallingFrame, ImportObjectBuilder, Instance,
+    /// };
+    ///
+    /// fn add(
+    ///     _data: &mut (),
+    ///     _inst: &mut Instance,
+    ///     _frame: &mut CallingFrame,
+    ///     (a, b): (i32, i32),
+    /// ) -> Result<i32, CoreError> {
+    ///     Ok(a + b)
+    /// }
+    ///
+    /// let mut builder = ImportObjectBuilder::new("extern", ()).unwrap();
+    /// builder.with_typed_func("add", add).unwrap();
+    /// ```
+    pub fn with_typed_func<Args, Rets>(
+        &mut self,
+        name: impl AsRef<str>,
+        real_func: TypedImportFn<Data, Args, Rets>,
+    ) -> WasmEdgeResult<&mut Self>
+    where
+        Args: WasmValTypeList + FromWasmValList,
+        Rets: WasmValTypeList + WasmValList,
+    {
+        let ty = FuncType::new(Args::wasm_types().to_vec(), Rets::wasm_types().to_vec());
+        let func = unsafe {
+            Function::create_sync_func_with_key::<Data, TypedFnKey<Args, Rets>>(
+                &ty,
+                real_func as _,
+                self.import_object.get_host_data_mut(),
+                0,
+            )
+        }?;
+        self.import_object.add_func(name, func);
+
+        Ok(self)
+    }
+
+    /// Adds a batch of [host functions](crate::Func) to the [ImportObject] to create, which is convenient for table-driven registration from a static array.
+    ///
+    /// N.B. that this function can be used in thread-safe scenarios.
+    ///
+    /// # Argument
+    ///
+    /// * `funcs` - The host functions to add. Each item is a tuple of the exported name, the function type and the native function.
+    ///
+    /// # error
+    ///
+    /// If fail to create or add any of the [host functions](crate::Func), then an error is returned. The functions before the failed one have already been added.
+    pub fn with_funcs_from_slice(
+        &mut self,
+        funcs: &[(&str, FuncType, HostFn<Data>)],
+    ) -> WasmEdgeResult<&mut Self> {
+        for (name, ty, real_func) in funcs {
+            self.with_func_by_type(name, ty.clone(), *real_func)?;
+        }
+        Ok(self)
+    }
+
     /// Adds a [global](crate::Global) to the [ImportObject] to create.
     ///
     /// # Arguments
@@ -94,6 +177,55 @@ impl<Data> ImportObjectBuilder<Data> {
         self
     }
 
+    /// Creates a new immutable [global](crate::Global) holding the given value and adds it to the [ImportObject] to create. The value type of the global is inferred from the given value.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The exported name of the [global](crate::Global) to add.
+    ///
+    /// * `value` - The value of the [global](crate::Global).
+    ///
+    /// # Error
+    ///
+    /// If fail to create the [global](crate::Global), then an error is returned.
+    pub fn with_const_global(
+        self,
+        name: impl AsRef<str>,
+        value: impl Into<WasmValue>,
+    ) -> WasmEdgeResult<Self> {
+        self.with_global_value(name, value.into(), Mutability::Const)
+    }
+
+    /// Creates a new mutable [global](crate::Global) holding the given initial value and adds it to the [ImportObject] to create. The value type of the global is inferred from the given value.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The exported name of the [global](crate::Global) to add.
+    ///
+    /// * `value` - The initial value of the [global](crate::Global).
+    ///
+    /// # Error
+    ///
+    /// If fail to create the [global](crate::Global), then an error is returned.
+    pub fn with_mutable_global(
+        self,
+        name: impl AsRef<str>,
+        value: impl Into<WasmValue>,
+    ) -> WasmEdgeResult<Self> {
+        self.with_global_value(name, value.into(), Mutability::Var)
+    }
+
+    fn with_global_value(
+        self,
+        name: impl AsRef<str>,
+        value: WasmValue,
+        mutability: Mutability,
+    ) -> WasmEdgeResult<Self> {
+        let ty = GlobalType::new(value.ty(), mutability);
+        let global = sys::Global::create(&ty, value)?;
+        Ok(self.with_global(name, global))
+    }
+
     /// Adds a [memory](crate::Memory) to the [ImportObject] to create.
     ///
     /// # Arguments
@@ -107,6 +239,56 @@ impl<Data> ImportObjectBuilder<Data> {
         self
     }
 
+    /// Creates a new [memory](crate::Memory) with the given limits and adds it to the [ImportObject] to create, which allows the imported memories to have their own limits.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The exported name of the [memory](crate::Memory) to add.
+    ///
+    /// * `initial_pages` - The initial size of the memory in pages.
+    ///
+    /// * `max_pages` - The maximum size of the memory in pages. If `None`, then the memory has no maximum limit.
+    ///
+    /// # Error
+    ///
+    /// If fail to create the [memory](crate::Memory), for example, `initial_pages` is larger than `max_pages`, then an error is returned.
+    pub fn with_memory_limit(
+        self,
+        name: impl AsRef<str>,
+        initial_pages: u32,
+        max_pages: Option<u32>,
+    ) -> WasmEdgeResult<Self> {
+        let ty = MemoryType::new(initial_pages, max_pages, false)?;
+        let memory = sys::Memory::create(&ty)?;
+        Ok(self.with_memory(name, memory))
+    }
+
+    /// Creates a new shared [memory](crate::Memory) and adds it to the [ImportObject] to create. All the wasm modules importing the memory map the same linear memory pages, which the host can also access through the built [ImportObject] without copying.
+    ///
+    /// Notice that importing a shared memory requires the [threads](crate::config::CommonConfigOptions::threads) proposal to be enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The exported name of the [memory](crate::Memory) to add.
+    ///
+    /// * `initial_pages` - The initial size of the memory in pages.
+    ///
+    /// * `max_pages` - The maximum size of the memory in pages, which is required by shared memories.
+    ///
+    /// # Error
+    ///
+    /// If fail to create the [memory](crate::Memory), for example, `initial_pages` is larger than `max_pages`, then an error is returned.
+    pub fn with_shared_memory(
+        self,
+        name: impl AsRef<str>,
+        initial_pages: u32,
+        max_pages: u32,
+    ) -> WasmEdgeResult<Self> {
+        let ty = MemoryType::new(initial_pages, Some(max_pages), true)?;
+        let memory = sys::Memory::create(&ty)?;
+        Ok(self.with_memory(name, memory))
+    }
+
     /// Adds a [table](crate::Table) to the [ImportObject] to create.
     ///
     /// # Arguments
@@ -136,6 +318,38 @@ impl<Data> ImportObjectBuilder<Data> {
     }
 }
 
+/// Defines the type of the native function used to create a [host function](crate::Func) in an [ImportObject].
+pub type HostFn<Data> = sys::SyncFn<Data>;
+
+/// Defines the type of the native function used to create a typed [host function](crate::Func) by [ImportObjectBuilder::with_typed_func]. The arguments and the returns are a value or a tuple of values of Rust types, from which the [function type](crate::FuncType) is inferred.
+pub type TypedImportFn<Data, Args, Rets> = for<'a, 'b, 'c> fn(
+    &'a mut Data,
+    &'b mut sys::Instance,
+    &'c mut CallingFrame,
+    Args,
+) -> Result<Rets, CoreError>;
+
+// Calls a TypedImportFn stored as the key of a host function.
+struct TypedFnKey<Args, Rets>(std::marker::PhantomData<fn(Args) -> Rets>);
+impl<Data, Args, Rets> sys::HostFnKey<Data> for TypedFnKey<Args, Rets>
+where
+    Args: FromWasmValList,
+    Rets: WasmValList,
+{
+    unsafe fn call(
+        key: *mut std::ffi::c_void,
+        data: &mut Data,
+        inst: &mut sys::Instance,
+        frame: &mut CallingFrame,
+        args: Vec<WasmValue>,
+    ) -> Result<Vec<WasmValue>, CoreError> {
+        let real_func: TypedImportFn<Data, Args, Rets> = std::mem::transmute(key);
+        let args = Args::from_wasm_values(&args)
+            .ok_or(CoreError::Execution(CoreExecutionError::FuncSigMismatch))?;
+        real_func(data, inst, frame, args).map(WasmValList::to_wasm_values)
+    }
+}
+
 /// Defines an import object that contains the required import data used when instantiating a [module](crate::Module).
 ///
 /// An [ImportObject] instance is created with [ImportObjectBuilder](crate::ImportObjectBuilder).
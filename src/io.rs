@@ -291,15 +291,203 @@ impl WasmVal for ExternRef {
     }
 }
 
+/// Defines the function converting a value or a tuple of values of Rust types to a list of values of Wasm types.
+///
+/// This trait is used by the [host_function](crate::host_function) macro to convert the values returned by a host function.
+///
+/// ```rust
+/// use wasmedge_sdk::{WasmValList, WasmValue};
+///
+/// let returns = (1i32, 2i64).to_wasm_values();
+/// assert_eq!(returns.len(), 2);
+/// assert_eq!(returns[0].to_i32(), 1);
+/// assert_eq!(returns[1].to_i64(), 2);
+/// ```
+pub trait WasmValList {
+    fn to_wasm_values(self) -> Vec<WasmValue>;
+}
+impl WasmValList for Vec<WasmValue> {
+    fn to_wasm_values(self) -> Vec<WasmValue> {
+        self
+    }
+}
+impl<T: WasmVal> WasmValList for T {
+    fn to_wasm_values(self) -> Vec<WasmValue> {
+        vec![self.to_wasm_value()]
+    }
+}
+
+macro_rules! impl_wasm_val_list {
+    () => {
+        impl WasmValList for () {
+            fn to_wasm_values(self) -> Vec<WasmValue> {
+                vec![]
+            }
+        }
+    };
+    ( $o:ident $(, $os:ident)* ) => {
+        #[allow(non_snake_case)]
+        impl< $o $(, $os)* > WasmValList for ( $o, $( $os, )* )
+        where
+            $o: WasmVal,
+            $( $os: WasmVal ),*
+        {
+            fn to_wasm_values(self) -> Vec<WasmValue> {
+                let ( $o, $( $os, )* ) = self;
+                vec![$o.to_wasm_value() $(, $os.to_wasm_value())*]
+            }
+        }
+
+        impl_wasm_val_list!( $( $os ),* );
+    };
+}
+
+impl_wasm_val_list!(
+    A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12, A13, A14, A15, A16, A17, A18, A19, A20, A21,
+    A22, A23, A24, A25, A26, A27, A28, A29, A30, A31, A32
+);
+
+/// Defines the function converting a value of Wasm type to the one of Rust type, which is the reverse of [WasmVal].
+pub trait FromWasmVal: WasmValType + Sized {
+    fn from_wasm_value(value: &WasmValue) -> Self;
+}
+
+/// The `impl_from_wasm_val` macro is used to generate the following struct
+///
+/// ```ignore
+/// impl FromWasmVal for i32 {
+///     fn from_wasm_value(value: &WasmValue) -> Self {
+///         value.to_i32() as i32
+///     }
+/// }
+/// ```
+macro_rules! impl_from_wasm_val {
+    ($t:ty, $f:ident) => {
+        impl FromWasmVal for $t {
+            fn from_wasm_value(value: &WasmValue) -> Self {
+                value.$f() as $t
+            }
+        }
+    };
+}
+
+impl_from_wasm_val!(i8, to_i32);
+impl_from_wasm_val!(u8, to_i32);
+impl_from_wasm_val!(i16, to_i32);
+impl_from_wasm_val!(u16, to_i32);
+impl_from_wasm_val!(i32, to_i32);
+impl_from_wasm_val!(u32, to_i64);
+impl_from_wasm_val!(i64, to_i64);
+impl_from_wasm_val!(f32, to_f32);
+impl_from_wasm_val!(f64, to_f64);
+impl_from_wasm_val!(i128, to_v128);
+
+/// Defines the function converting a list of values of Wasm types to a value or a tuple of values of Rust types, which is the reverse of [WasmValList].
+///
+/// ```rust
+/// use wasmedge_sdk::{params, FromWasmValList, WasmVal};
+///
+/// let (a, b) = <(i32, i64)>::from_wasm_values(&params!(1i32, 2i64)).unwrap();
+/// assert_eq!(a, 1);
+/// assert_eq!(b, 2);
+/// ```
+pub trait FromWasmValList: Sized {
+    /// Returns `None` if the number or the types of the given values do not match.
+    fn from_wasm_values(values: &[WasmValue]) -> Option<Self>;
+}
+impl<T: FromWasmVal> FromWasmValList for T {
+    fn from_wasm_values(values: &[WasmValue]) -> Option<Self> {
+        match values {
+            [value] if value.ty() == T::WASM_TYPE => Some(T::from_wasm_value(value)),
+            _ => None,
+        }
+    }
+}
+
+macro_rules! impl_from_wasm_val_list {
+    () => {
+        impl FromWasmValList for () {
+            fn from_wasm_values(values: &[WasmValue]) -> Option<Self> {
+                values.is_empty().then_some(())
+            }
+        }
+    };
+    ( $o:ident $(, $os:ident)* ) => {
+        #[allow(non_snake_case)]
+        impl< $o $(, $os)* > FromWasmValList for ( $o, $( $os, )* )
+        where
+            $o: FromWasmVal,
+            $( $os: FromWasmVal ),*
+        {
+            fn from_wasm_values(values: &[WasmValue]) -> Option<Self> {
+                match values {
+                    [$o $(, $os)*]
+                        if $o.ty() == <$o as WasmValType>::WASM_TYPE
+                            $(&& $os.ty() == <$os as WasmValType>::WASM_TYPE)* =>
+                    {
+                        Some(($o::from_wasm_value($o), $( $os::from_wasm_value($os), )*))
+                    }
+                    _ => None,
+                }
+            }
+        }
+
+        impl_from_wasm_val_list!( $( $os ),* );
+    };
+}
+
+impl_from_wasm_val_list!(A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12, A13, A14, A15, A16);
+
+#[cfg(test)]
+mod test_wasm_val_list {
+    use super::*;
+
+    #[test]
+    fn test_to_wasm_values() {
+        assert!(().to_wasm_values().is_empty());
+
+        let returns = 1i32.to_wasm_values();
+        assert_eq!(returns.len(), 1);
+        assert_eq!(returns[0].to_i32(), 1);
+
+        let returns = (1i32,).to_wasm_values();
+        assert_eq!(returns.len(), 1);
+        assert_eq!(returns[0].to_i32(), 1);
+
+        let returns = (1i32, 2i64, 3.0f32, 4.0f64).to_wasm_values();
+        assert_eq!(returns.len(), 4);
+        assert_eq!(returns[0].to_i32(), 1);
+        assert_eq!(returns[1].to_i64(), 2);
+        assert_eq!(returns[2].to_f32(), 3.0);
+        assert_eq!(returns[3].to_f64(), 4.0);
+
+        let returns = vec![WasmValue::from_i32(1)].to_wasm_values();
+        assert_eq!(returns.len(), 1);
+        assert_eq!(returns[0].to_i32(), 1);
+    }
+}
+
 /// Generates arguments of [WasmValue](crate::WasmValue) types.
 ///
 /// Notice that to use the macro, it is required to use `WasmVal` trait.
+///
+/// The arguments can be optionally named for readability at the call site. The names are discarded, since WebAssembly does not use parameter names. Mixing named and unnamed arguments is not allowed.
+///
+/// ```rust
+/// use wasmedge_sdk::{params, WasmVal};
+///
+/// let args = params!(42i32, 1.5f64);
+/// let named_args = params!(x = 42i32, rate = 1.5f64);
+/// assert_eq!(args.len(), named_args.len());
+/// assert_eq!(named_args[0].to_i32(), 42);
+/// assert_eq!(named_args[1].to_f64(), 1.5);
+/// ```
 #[macro_export]
 macro_rules! params {
-    ( $( $x:expr ),* ) => {
-        #[allow(unused_mut)]
+    (@unnamed [$( $x:expr ),*]) => {
         {
-            let mut temp_vec = vec![];
+            #[allow(unused_mut)]
+            let mut temp_vec: Vec<$crate::WasmValue> = vec![];
             $(
                 temp_vec.push($x.to_wasm_value());
 
@@ -307,4 +495,42 @@ macro_rules! params {
             temp_vec
         }
     };
+    (@unnamed [$( $done:expr ),*] $name:ident = $x:expr $(, $( $rest:tt )* )?) => {
+        compile_error!("`params!` does not allow mixing named and unnamed arguments")
+    };
+    (@unnamed [$( $done:expr ),*] $x:expr $(, $( $rest:tt )* )?) => {
+        $crate::params!(@unnamed [$( $done, )* $x] $( $( $rest )* )?)
+    };
+    ( $( $name:ident = $x:expr ),+ $(,)? ) => {
+        $crate::params!(@unnamed [$( $x ),+])
+    };
+    ( $( $x:tt )* ) => {
+        $crate::params!(@unnamed [] $( $x )*)
+    };
+}
+
+#[cfg(test)]
+mod test_params {
+    use super::*;
+
+    #[test]
+    fn test_params_named() {
+        assert!(params!().is_empty());
+
+        let args = params!(42i32, 1.5f64);
+        let named_args = params!(x = 42i32, rate = 1.5f64);
+        assert_eq!(args.len(), 2);
+        assert_eq!(named_args.len(), 2);
+        assert_eq!(args[0].to_i32(), named_args[0].to_i32());
+        assert_eq!(args[1].to_f64(), named_args[1].to_f64());
+
+        // trailing commas and expressions
+        let a = 1;
+        let args = params!(a + 1, 2i64,);
+        assert_eq!(args[0].to_i32(), 2);
+        assert_eq!(args[1].to_i64(), 2);
+        let named_args = params!(x = a + 1, y = 2i64,);
+        assert_eq!(named_args[0].to_i32(), 2);
+        assert_eq!(named_args[1].to_i64(), 2);
+    }
 }
@@ -93,10 +93,17 @@
 //! This project is licensed under the terms of the [Apache 2.0 license](https://github.com/tensorflow/rust/blob/HEAD/LICENSE).
 //!
 
+pub mod bench;
+#[cfg(feature = "aot")]
+#[cfg_attr(docsrs, doc(cfg(feature = "aot")))]
+pub mod cache;
 #[doc(hidden)]
 #[cfg(feature = "aot")]
 #[cfg_attr(docsrs, doc(cfg(feature = "aot")))]
 mod compiler;
+#[cfg(feature = "component-model")]
+#[cfg_attr(docsrs, doc(cfg(feature = "component-model")))]
+pub mod component;
 pub mod config;
 
 // #[cfg(feature = "dock")]
@@ -108,14 +115,27 @@ mod instance;
 pub mod io;
 #[doc(hidden)]
 pub mod log;
+mod memory;
 mod module;
 pub mod plugin;
+#[cfg(all(feature = "async", target_os = "linux"))]
+pub mod sandbox;
 mod statistics;
 mod store;
+#[cfg(feature = "wasm-tools")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wasm-tools")))]
+pub mod streaming;
+pub mod testing;
+#[cfg(feature = "threads")]
+#[cfg_attr(docsrs, doc(cfg(feature = "threads")))]
+pub mod threads;
 pub mod types;
 pub mod utils;
 #[doc(hidden)]
 pub mod vm;
+#[cfg(feature = "wit")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wit")))]
+pub mod wit;
 
 #[cfg(all(feature = "async", target_os = "linux"))]
 pub mod r#async;
@@ -126,25 +146,32 @@ pub mod r#async;
 pub use compiler::Compiler;
 
 #[doc(inline)]
-pub use import::{AsInstance, ImportObject, ImportObjectBuilder};
+pub use import::{AsFunc, AsInstance, HostFn, ImportObject, ImportObjectBuilder, TypedImportFn};
 pub use instance::Instance;
 #[doc(inline)]
-pub use io::{WasmVal, WasmValType, WasmValTypeList};
+pub use io::{FromWasmVal, FromWasmValList, WasmVal, WasmValList, WasmValType, WasmValTypeList};
 #[doc(inline)]
 pub use log::LogManager;
 #[doc(inline)]
-pub use module::{ExportType, ImportType, Module};
+pub use memory::MemoryView;
+#[cfg(feature = "wasm-tools")]
+pub use module::ModuleNames;
+#[doc(inline)]
+pub use module::{ExportType, ImportDescription, ImportType, Module};
 #[doc(inline)]
 pub use statistics::Statistics;
 #[doc(inline)]
 pub use store::Store;
+#[cfg(feature = "profiling")]
+#[doc(inline)]
+pub use vm::ProfilingData;
 #[doc(inline)]
-pub use vm::Vm;
+pub use vm::{ExecutionMetrics, FuelPolicy, NamedInstance, TypedFunc, Vm, WasmOpcode};
 
 pub use wasmedge_types::{
     error, wat2wasm, CompilerOptimizationLevel, CompilerOutputFormat, ExternalInstanceType,
-    FuncType, GlobalType, HostRegistration, MemoryType, Mutability, RefType, TableType, ValType,
-    WasmEdgeResult,
+    FuncType, GlobalType, HostRegistration, MemoryType, Mutability, ProposalName, RefType,
+    TableType, ValType, WasmEdgeResult,
 };
 
 #[cfg(all(feature = "async", target_os = "linux"))]
@@ -155,6 +182,9 @@ pub use wasmedge_macro::host_function;
 /// WebAssembly value type.
 pub type WasmValue = wasmedge_sys::types::WasmValue;
 
+/// A token to cancel the running function calls from another thread, see [Vm::cancellation_token].
+pub type CancellationToken = wasmedge_sys::CancellationToken;
+
 /// This is a workaround solution to the [`never`](https://doc.rust-lang.org/std/primitive.never.html) type in Rust. It will be replaced by `!` once it is stable.
 pub type NeverType = wasmedge_types::NeverType;
 
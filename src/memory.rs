@@ -0,0 +1,129 @@
+//! Defines WasmEdge MemoryView struct.
+
+use crate::WasmEdgeResult;
+use wasmedge_sys as sys;
+
+/// A [MemoryView] is a read-only view of an exported [memory](crate::Memory) of a [module instance](crate::Instance), which is convenient for reading data from the linear memory on the host side.
+///
+/// A [MemoryView] borrows the [Vm](crate::Vm) or [module instance](crate::Instance) it is created from, so that the underlying memory instance cannot be dropped while the view is alive.
+///
+/// All the integers are read in little-endian byte order, which is the byte order of WebAssembly linear memories.
+#[derive(Debug)]
+pub struct MemoryView<'inst> {
+    inner: sys::instance::InnerRef<sys::Memory, &'inst sys::Instance>,
+}
+impl<'inst> MemoryView<'inst> {
+    pub(crate) fn new(inner: sys::instance::InnerRef<sys::Memory, &'inst sys::Instance>) -> Self {
+        Self { inner }
+    }
+
+    /// Returns the size, in WebAssembly pages (64 KiB of each page), of the viewed memory.
+    pub fn page_count(&self) -> u32 {
+        self.inner.size()
+    }
+
+    /// Returns the size, in bytes, of the viewed memory.
+    pub fn data_size(&self) -> u64 {
+        self.inner.size() as u64 * 65536
+    }
+
+    /// Reads `len` bytes starting from `offset`.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - The data start offset in the viewed memory.
+    ///
+    /// * `len` - The requested data length.
+    ///
+    /// # Error
+    ///
+    /// If the `offset + len` is larger than the data size of the viewed memory, then an error is returned.
+    pub fn read_bytes(&self, offset: u32, len: u32) -> WasmEdgeResult<Vec<u8>> {
+        self.inner.get_data(offset, len)
+    }
+
+    /// Reads a `u8` value at `offset`.
+    ///
+    /// # Argument
+    ///
+    /// * `offset` - The data offset in the viewed memory.
+    ///
+    /// # Error
+    ///
+    /// If the `offset` is out of the bounds of the viewed memory, then an error is returned.
+    pub fn read_u8(&self, offset: u32) -> WasmEdgeResult<u8> {
+        Ok(self.read_array::<1>(offset)?[0])
+    }
+
+    /// Reads a little-endian `u16` value at `offset`.
+    ///
+    /// # Argument
+    ///
+    /// * `offset` - The data offset in the viewed memory.
+    ///
+    /// # Error
+    ///
+    /// If the value is out of the bounds of the viewed memory, then an error is returned.
+    pub fn read_u16_le(&self, offset: u32) -> WasmEdgeResult<u16> {
+        Ok(u16::from_le_bytes(self.read_array(offset)?))
+    }
+
+    /// Reads a little-endian `u32` value at `offset`.
+    ///
+    /// # Argument
+    ///
+    /// * `offset` - The data offset in the viewed memory.
+    ///
+    /// # Error
+    ///
+    /// If the value is out of the bounds of the viewed memory, then an error is returned.
+    pub fn read_u32_le(&self, offset: u32) -> WasmEdgeResult<u32> {
+        Ok(u32::from_le_bytes(self.read_array(offset)?))
+    }
+
+    /// Reads a little-endian `u64` value at `offset`.
+    ///
+    /// # Argument
+    ///
+    /// * `offset` - The data offset in the viewed memory.
+    ///
+    /// # Error
+    ///
+    /// If the value is out of the bounds of the viewed memory, then an error is returned.
+    pub fn read_u64_le(&self, offset: u32) -> WasmEdgeResult<u64> {
+        Ok(u64::from_le_bytes(self.read_array(offset)?))
+    }
+
+    /// Reads a little-endian `i32` value at `offset`.
+    ///
+    /// # Argument
+    ///
+    /// * `offset` - The data offset in the viewed memory.
+    ///
+    /// # Error
+    ///
+    /// If the value is out of the bounds of the viewed memory, then an error is returned.
+    pub fn read_i32_le(&self, offset: u32) -> WasmEdgeResult<i32> {
+        Ok(i32::from_le_bytes(self.read_array(offset)?))
+    }
+
+    /// Reads a little-endian `i64` value at `offset`.
+    ///
+    /// # Argument
+    ///
+    /// * `offset` - The data offset in the viewed memory.
+    ///
+    /// # Error
+    ///
+    /// If the value is out of the bounds of the viewed memory, then an error is returned.
+    pub fn read_i64_le(&self, offset: u32) -> WasmEdgeResult<i64> {
+        Ok(i64::from_le_bytes(self.read_array(offset)?))
+    }
+
+    fn read_array<const N: usize>(&self, offset: u32) -> WasmEdgeResult<[u8; N]> {
+        let data = self.inner.get_data(offset, N as u32)?;
+        let mut bytes = [0u8; N];
+        bytes.copy_from_slice(&data);
+        Ok(bytes)
+    }
+}
@@ -1,6 +1,8 @@
 //! Defines WasmEdge AST Module, ImportType, and ExportType.
 
 use crate::{config::Config, ExternalInstanceType, WasmEdgeResult};
+#[cfg(feature = "wasm-tools")]
+use std::collections::HashMap;
 use std::{borrow::Cow, marker::PhantomData, path::Path, sync::Arc};
 use wasmedge_sys as sys;
 
@@ -62,6 +64,82 @@ impl Module {
         })
     }
 
+    /// The timeout of downloading a wasm binary by [from_url](Module::from_url), which covers the whole request including reading the body.
+    #[cfg(feature = "fetch")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "fetch")))]
+    pub const FETCH_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(60);
+
+    /// The maximum size in bytes of a wasm binary downloaded by [from_url](Module::from_url).
+    #[cfg(feature = "fetch")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "fetch")))]
+    pub const MAX_FETCH_SIZE: u64 = 256 * 1024 * 1024;
+
+    /// Downloads a WebAssembly binary module from the given URL, and then loads and validates it.
+    ///
+    /// The download fails if it takes longer than [FETCH_TIMEOUT](Module::FETCH_TIMEOUT), or if the body is larger than [MAX_FETCH_SIZE](Module::MAX_FETCH_SIZE) bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The global configuration.
+    ///
+    /// * `url` - The HTTP(S) URL of the wasm binary.
+    ///
+    /// * `expected_sha256` - The expected SHA-256 digest of the downloaded bytes. If `None`, then the bytes are not verified.
+    ///
+    /// # Error
+    ///
+    /// If fail to download the wasm binary in time, or the body is too large, or the digest of the downloaded bytes doesn't match `expected_sha256`, or fail to load and validate the module, then an error is returned.
+    #[cfg(feature = "fetch")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "fetch")))]
+    pub fn from_url(
+        config: Option<&Config>,
+        url: &str,
+        expected_sha256: Option<[u8; 32]>,
+    ) -> WasmEdgeResult<Self> {
+        use crate::error::WasmEdgeError;
+        use sha2::{Digest, Sha256};
+
+        let fetch_error = |e: String| {
+            Box::new(WasmEdgeError::Operation(format!(
+                "failed to fetch the wasm module from {url}: {e}"
+            )))
+        };
+
+        // download the wasm bytes
+        let bytes =
+            Self::fetch(url, Self::FETCH_TIMEOUT, Self::MAX_FETCH_SIZE).map_err(fetch_error)?;
+
+        // verify the content hash
+        if let Some(expected) = expected_sha256 {
+            let digest: [u8; 32] = Sha256::digest(&bytes).into();
+            if digest != expected {
+                return Err(fetch_error("the SHA-256 digest does not match".into()));
+            }
+        }
+
+        Self::from_bytes(config, bytes)
+    }
+
+    // downloads the body of the given URL within the timeout, failing if the body is larger than `max_size`
+    #[cfg(feature = "fetch")]
+    fn fetch(url: &str, timeout: std::time::Duration, max_size: u64) -> Result<Vec<u8>, String> {
+        use std::io::Read;
+
+        let agent = ureq::AgentBuilder::new().timeout(timeout).build();
+        let response = agent.get(url).call().map_err(|e| e.to_string())?;
+        let mut bytes = Vec::new();
+        // read one more byte than the limit to tell an oversized body
+        response
+            .into_reader()
+            .take(max_size + 1)
+            .read_to_end(&mut bytes)
+            .map_err(|e| e.to_string())?;
+        if bytes.len() as u64 > max_size {
+            return Err(format!("the body is larger than {max_size} bytes"));
+        }
+        Ok(bytes)
+    }
+
     /// Returns the count of the imported WasmEdge instances in the [module](crate::Module).
     pub fn count_of_imports(&self) -> u32 {
         self.inner.count_of_imports()
@@ -80,6 +158,23 @@ impl Module {
         imports
     }
 
+    /// Returns an iterator over the [descriptions](crate::ImportDescription) of all imported WasmEdge instances in the [module](crate::Module), each of which includes the name of the module hosting the imported instance, the imported name, and the type of the imported instance.
+    ///
+    /// This is useful to check if all the required imports are satisfiable before instantiating the [module](crate::Module). The descriptions are built lazily, so that a check can stop at the first unsatisfied import.
+    ///
+    /// # Error
+    ///
+    /// If fail to get the type of an imported instance, then the iterator yields an error for the import, rather than leaving the import out.
+    pub fn import_list(&self) -> impl Iterator<Item = WasmEdgeResult<ImportDescription>> + '_ {
+        self.inner.imports().into_iter().map(|import| {
+            Ok(ImportDescription {
+                module: import.module_name().into_owned(),
+                name: import.name().into_owned(),
+                ty: import.ty()?,
+            })
+        })
+    }
+
     /// Returns the count of the exported WasmEdge instances from the [module](crate::Module).
     pub fn count_of_exports(&self) -> u32 {
         self.inner.count_of_exports()
@@ -114,6 +209,73 @@ impl Module {
             false => exports[0].ty().ok(),
         }
     }
+
+    /// Parses the `name` custom section of a WebAssembly binary, which holds the debug names of the module, the functions and the locals. The names can be used to make the traps readable.
+    ///
+    /// Notice that the custom sections are not kept by a loaded [module](crate::Module), therefore, the names are parsed from the binary the module is loaded from.
+    ///
+    /// Returns `None` if the binary has no `name` section, or fail to parse the binary.
+    ///
+    /// # Argument
+    ///
+    /// * `bytes` - The in-memory bytes of the WebAssembly binary.
+    #[cfg(feature = "wasm-tools")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "wasm-tools")))]
+    pub fn parse_name_section(bytes: impl AsRef<[u8]>) -> Option<ModuleNames> {
+        use wasmparser::{KnownCustom, Name, Parser, Payload};
+
+        for payload in Parser::new(0).parse_all(bytes.as_ref()) {
+            let Payload::CustomSection(reader) = payload.ok()? else {
+                continue;
+            };
+            let KnownCustom::Name(reader) = reader.as_known() else {
+                continue;
+            };
+
+            let mut names = ModuleNames::default();
+            for name in reader {
+                match name.ok()? {
+                    Name::Module { name, .. } => names.module_name = Some(name.to_string()),
+                    Name::Function(map) => {
+                        for naming in map {
+                            let naming = naming.ok()?;
+                            names
+                                .function_names
+                                .insert(naming.index, naming.name.to_string());
+                        }
+                    }
+                    Name::Local(map) => {
+                        for indirect in map {
+                            let indirect = indirect.ok()?;
+                            for naming in indirect.names {
+                                let naming = naming.ok()?;
+                                names.local_names.insert(
+                                    (indirect.index, naming.index),
+                                    naming.name.to_string(),
+                                );
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            return Some(names);
+        }
+        None
+    }
+}
+
+/// Defines the debug names parsed from the `name` custom section of a WebAssembly binary.
+#[cfg(feature = "wasm-tools")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wasm-tools")))]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ModuleNames {
+    /// The name of the module.
+    pub module_name: Option<String>,
+    /// The names of the functions, indexed by the function indices.
+    pub function_names: HashMap<u32, String>,
+    /// The names of the locals, indexed by the function indices and the local indices.
+    pub local_names: HashMap<(u32, u32), String>,
 }
 
 /// Defines the types of the imported instances.
@@ -140,6 +302,17 @@ impl ImportType<'_> {
     }
 }
 
+/// Describes an imported WasmEdge instance of a [module](crate::Module).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportDescription {
+    /// The name of the module hosting the imported WasmEdge instance, for example, `wasi_snapshot_preview1`.
+    pub module: String,
+    /// The imported name of the WasmEdge instance.
+    pub name: String,
+    /// The type of the imported WasmEdge instance.
+    pub ty: ExternalInstanceType,
+}
+
 /// Defines the types of the exported instances.
 #[derive(Debug)]
 pub struct ExportType<'module> {
@@ -254,6 +427,155 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_module_from_bytes_with_gc() {
+        use crate::config::{CommonConfigOptions, ConfigBuilder};
+
+        let wasm_bytes = wat2wasm(
+            br#"
+        (module
+            (type $t (struct (field i32)))
+            (func (export "new") (result (ref $t))
+              (struct.new $t (i32.const 1)))
+        )
+"#,
+        )
+        .unwrap();
+
+        // the GC proposal is disabled by default
+        let result = Module::from_bytes(None, &wasm_bytes);
+        assert!(result.is_err());
+
+        // enabling GC also enables FunctionReferences
+        let config = ConfigBuilder::new(CommonConfigOptions::default().gc(true))
+            .build()
+            .unwrap();
+        assert!(config.gc_enabled());
+        assert!(config.function_references_enabled());
+        let result = Module::from_bytes(Some(&config), &wasm_bytes);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_module_from_bytes_with_function_references() {
+        use crate::config::{CommonConfigOptions, ConfigBuilder};
+
+        let wasm_bytes = wat2wasm(
+            br#"
+        (module
+            (type $f (func (result i32)))
+            (func $g (type $f) (i32.const 7))
+            (elem declare func $g)
+            (func (export "call") (result i32)
+              (call_ref $f (ref.func $g)))
+        )
+"#,
+        )
+        .unwrap();
+
+        // the ReferenceTypes option does not cover the typed function references
+        let config = ConfigBuilder::new(CommonConfigOptions::default().reference_types(true))
+            .build()
+            .unwrap();
+        assert!(!config.function_references_enabled());
+        let result = Module::from_bytes(Some(&config), &wasm_bytes);
+        assert!(result.is_err());
+
+        let config = ConfigBuilder::new(CommonConfigOptions::default().function_references(true))
+            .build()
+            .unwrap();
+        assert!(config.function_references_enabled());
+        let result = Module::from_bytes(Some(&config), &wasm_bytes);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "fetch")]
+    fn test_module_from_url() {
+        use sha2::{Digest, Sha256};
+        use std::{io::Write, net::TcpListener};
+
+        let wasm_bytes = wat2wasm(br#"(module (func (export "nop")))"#).unwrap();
+
+        // serve the wasm bytes to two requests
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/module.wasm", listener.local_addr().unwrap());
+        let body = wasm_bytes.to_vec();
+        let server = std::thread::spawn(move || {
+            for stream in listener.incoming().take(2) {
+                let mut stream = stream.unwrap();
+                let mut request = [0u8; 1024];
+                let _ = std::io::Read::read(&mut stream, &mut request).unwrap();
+                write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                    body.len()
+                )
+                .unwrap();
+                stream.write_all(&body).unwrap();
+            }
+        });
+
+        let digest: [u8; 32] = Sha256::digest(&wasm_bytes).into();
+        let module = Module::from_url(None, &url, Some(digest)).unwrap();
+        assert_eq!(module.exports().len(), 1);
+
+        let result = Module::from_url(None, &url, Some([0; 32]));
+        assert!(matches!(
+            result.unwrap_err().as_ref(),
+            WasmEdgeError::Operation(_)
+        ));
+
+        server.join().unwrap();
+    }
+
+    #[test]
+    #[cfg(feature = "fetch")]
+    fn test_module_fetch_limits() {
+        use std::{io::Write, net::TcpListener, time::Duration};
+
+        // serve a 16-byte body, and then hang on the next request
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/module.wasm", listener.local_addr().unwrap());
+        let (done_tx, done_rx) = std::sync::mpsc::channel();
+        let server = std::thread::spawn(move || {
+            let mut streams = vec![];
+            for (i, stream) in listener.incoming().take(3).enumerate() {
+                let mut stream = stream.unwrap();
+                let mut request = [0u8; 1024];
+                let _ = std::io::Read::read(&mut stream, &mut request).unwrap();
+                if i < 2 {
+                    write!(
+                        stream,
+                        "HTTP/1.1 200 OK\r\nContent-Length: 16\r\nConnection: close\r\n\r\n"
+                    )
+                    .unwrap();
+                    stream.write_all(&[0u8; 16]).unwrap();
+                }
+                streams.push(stream);
+            }
+            // keep the connections open until the client is done
+            done_rx.recv().unwrap();
+        });
+
+        // the body fits the limit
+        assert_eq!(
+            Module::fetch(&url, Duration::from_secs(10), 16),
+            Ok(vec![0; 16])
+        );
+
+        // the body is larger than the limit
+        let result = Module::fetch(&url, Duration::from_secs(10), 15);
+        assert!(result.unwrap_err().contains("larger than 15 bytes"));
+
+        // the server does not respond in time
+        let result = Module::fetch(&url, Duration::from_millis(100), 16);
+        assert!(result.is_err());
+
+        done_tx.send(()).unwrap();
+        server.join().unwrap();
+    }
+
     #[test]
     #[allow(clippy::assertions_on_result_states)]
     fn test_module_clone() {
@@ -303,4 +625,62 @@ mod tests {
         let module_clone = module.clone();
         assert_eq!(module.exports().len(), module_clone.exports().len());
     }
+
+    #[test]
+    fn test_module_import_list() {
+        let wasm_bytes = wat2wasm(
+            br#"
+        (module
+            (import "wasi_snapshot_preview1" "proc_exit" (func (param i32)))
+            (import "env" "memory" (memory 1))
+            (import "env" "counter" (global (mut i32)))
+        )
+"#,
+        )
+        .unwrap();
+
+        let result = Module::from_bytes(None, wasm_bytes);
+        assert!(result.is_ok());
+        let module = result.unwrap();
+
+        let imports = module
+            .import_list()
+            .collect::<WasmEdgeResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(imports.len(), module.count_of_imports() as usize);
+        assert_eq!(imports.len(), 3);
+
+        assert_eq!(imports[0].module, "wasi_snapshot_preview1");
+        assert_eq!(imports[0].name, "proc_exit");
+        assert!(matches!(imports[0].ty, ExternalInstanceType::Func(_)));
+
+        assert_eq!(imports[1].module, "env");
+        assert_eq!(imports[1].name, "memory");
+        assert!(matches!(imports[1].ty, ExternalInstanceType::Memory(_)));
+
+        assert_eq!(imports[2].module, "env");
+        assert_eq!(imports[2].name, "counter");
+        assert!(matches!(imports[2].ty, ExternalInstanceType::Global(_)));
+    }
+
+    #[test]
+    #[cfg(feature = "wasm-tools")]
+    fn test_module_parse_name_section() {
+        let wasm_bytes = wat2wasm(
+            br#"(module $math
+              (func $add (export "add") (param $a i32) (param $b i32) (result i32)
+                (i32.add (local.get $a) (local.get $b)))
+            )"#,
+        )
+        .unwrap();
+        let names = Module::parse_name_section(&wasm_bytes).unwrap();
+        assert_eq!(names.module_name.as_deref(), Some("math"));
+        assert_eq!(names.function_names[&0], "add");
+        assert_eq!(names.local_names[&(0, 0)], "a");
+        assert_eq!(names.local_names[&(0, 1)], "b");
+
+        // no name section
+        let wasm_bytes = wat2wasm(br#"(module (func))"#).unwrap();
+        assert!(Module::parse_name_section(&wasm_bytes).is_none());
+    }
 }
@@ -0,0 +1,295 @@
+//! Defines WasmEdge SandboxBuilder struct.
+
+use crate::WasmEdgeResult;
+use async_wasi::snapshots::WasiCtx;
+use wasmedge_sys::r#async::AsyncWasiModule;
+
+/// Creates an [AsyncWasiModule] with deny-by-default wasi host functions.
+///
+/// All the wasi functions are disabled by default, and each group of them can be enabled by the corresponding `allow_*` method. The disabled wasi functions are still exported by the created module, so that the wasm modules importing them can be instantiated, but they return `ENOSYS` without doing anything. Notice that `proc_exit` is always enabled.
+///
+/// # Example
+///
+/// ```rust
+/// use std::collections::HashMap;
+/// use wasmedge_sdk::{
+///     r#async::vm::{AsyncInst, Vm},
+///     sandbox::SandboxBuilder,
+///     Store,
+/// };
+///
+/// // only the functions writing to the file descriptors are allowed
+/// let mut wasi_module = SandboxBuilder::new().allow_fd_write(true).build().unwrap();
+///
+/// let mut instances: HashMap<String, &mut (dyn AsyncInst + Send)> = HashMap::new();
+/// instances.insert(wasi_module.name().to_string(), wasi_module.as_mut());
+/// let vm = Vm::new(Store::new(None, instances).unwrap());
+/// ```
+#[derive(Debug, Default)]
+pub struct SandboxBuilder {
+    args: Vec<String>,
+    envs: Vec<(String, String)>,
+    wasi_ctx: Option<WasiCtx>,
+    allow_args: bool,
+    allow_clock: bool,
+    allow_random: bool,
+    allow_fd_read: bool,
+    allow_fd_write: bool,
+    allow_fs: bool,
+    allow_sock: bool,
+    allow_poll: bool,
+    allow_sched: bool,
+}
+impl SandboxBuilder {
+    /// Creates a new [SandboxBuilder], in which all the wasi functions are disabled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the commandline arguments. The first argument is the program name.
+    ///
+    /// # Argument
+    ///
+    /// * `args` - The commandline arguments.
+    pub fn with_args(self, args: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            args: args.into_iter().map(Into::into).collect(),
+            ..self
+        }
+    }
+
+    /// Sets the environment variables.
+    ///
+    /// # Argument
+    ///
+    /// * `envs` - The environment variables in the form of `(key, value)`.
+    pub fn with_envs(
+        self,
+        envs: impl IntoIterator<Item = (impl Into<String>, impl Into<String>)>,
+    ) -> Self {
+        Self {
+            envs: envs
+                .into_iter()
+                .map(|(k, v)| (k.into(), v.into()))
+                .collect(),
+            ..self
+        }
+    }
+
+    /// Sets the [WasiCtx](async_wasi::snapshots::WasiCtx), for example, with the mounted file systems. The commandline arguments and environment variables set by [with_args](SandboxBuilder::with_args) and [with_envs](SandboxBuilder::with_envs) are appended to it.
+    ///
+    /// # Argument
+    ///
+    /// * `wasi_ctx` - The [WasiCtx](async_wasi::snapshots::WasiCtx) instance.
+    pub fn with_wasi_context(self, wasi_ctx: WasiCtx) -> Self {
+        Self {
+            wasi_ctx: Some(wasi_ctx),
+            ..self
+        }
+    }
+
+    /// Allows or denies `args_get`, `args_sizes_get`, `environ_get` and `environ_sizes_get`.
+    ///
+    /// # Argument
+    ///
+    /// * `allow` - Whether the functions are allowed or not.
+    pub fn allow_args(self, allow: bool) -> Self {
+        Self {
+            allow_args: allow,
+            ..self
+        }
+    }
+
+    /// Allows or denies `clock_res_get` and `clock_time_get`.
+    ///
+    /// # Argument
+    ///
+    /// * `allow` - Whether the functions are allowed or not.
+    pub fn allow_clock(self, allow: bool) -> Self {
+        Self {
+            allow_clock: allow,
+            ..self
+        }
+    }
+
+    /// Allows or denies `random_get`.
+    ///
+    /// # Argument
+    ///
+    /// * `allow` - Whether the function is allowed or not.
+    pub fn allow_random(self, allow: bool) -> Self {
+        Self {
+            allow_random: allow,
+            ..self
+        }
+    }
+
+    /// Allows or denies `fd_read` and `fd_pread`.
+    ///
+    /// # Argument
+    ///
+    /// * `allow` - Whether the functions are allowed or not.
+    pub fn allow_fd_read(self, allow: bool) -> Self {
+        Self {
+            allow_fd_read: allow,
+            ..self
+        }
+    }
+
+    /// Allows or denies `fd_write` and `fd_pwrite`.
+    ///
+    /// # Argument
+    ///
+    /// * `allow` - Whether the functions are allowed or not.
+    pub fn allow_fd_write(self, allow: bool) -> Self {
+        Self {
+            allow_fd_write: allow,
+            ..self
+        }
+    }
+
+    /// Allows or denies the other `fd_*` functions and all the `path_*` functions.
+    ///
+    /// # Argument
+    ///
+    /// * `allow` - Whether the functions are allowed or not.
+    pub fn allow_fs(self, allow: bool) -> Self {
+        Self {
+            allow_fs: allow,
+            ..self
+        }
+    }
+
+    /// Allows or denies the `sock_*` functions.
+    ///
+    /// # Argument
+    ///
+    /// * `allow` - Whether the functions are allowed or not.
+    pub fn allow_sock(self, allow: bool) -> Self {
+        Self {
+            allow_sock: allow,
+            ..self
+        }
+    }
+
+    /// Allows or denies `poll_oneoff` and `epoll_oneoff`.
+    ///
+    /// # Argument
+    ///
+    /// * `allow` - Whether the functions are allowed or not.
+    pub fn allow_poll(self, allow: bool) -> Self {
+        Self {
+            allow_poll: allow,
+            ..self
+        }
+    }
+
+    /// Allows or denies `sched_yield` and `proc_raise`.
+    ///
+    /// # Argument
+    ///
+    /// * `allow` - Whether the functions are allowed or not.
+    pub fn allow_sched(self, allow: bool) -> Self {
+        Self {
+            allow_sched: allow,
+            ..self
+        }
+    }
+
+    /// Checks if the wasi function with the given name is allowed.
+    fn is_allowed(&self, func_name: &str) -> bool {
+        match func_name {
+            "args_get" | "args_sizes_get" | "environ_get" | "environ_sizes_get" => self.allow_args,
+            "clock_res_get" | "clock_time_get" => self.allow_clock,
+            "random_get" => self.allow_random,
+            "fd_read" | "fd_pread" => self.allow_fd_read,
+            "fd_write" | "fd_pwrite" => self.allow_fd_write,
+            "poll_oneoff" | "epoll_oneoff" => self.allow_poll,
+            "sched_yield" | "proc_raise" => self.allow_sched,
+            name if name.starts_with("fd_") || name.starts_with("path_") => self.allow_fs,
+            name if name.starts_with("sock_") => self.allow_sock,
+            _ => false,
+        }
+    }
+
+    /// Creates a new [AsyncWasiModule] from the [SandboxBuilder].
+    ///
+    /// # Error
+    ///
+    /// If fail to create the [AsyncWasiModule], then an error is returned.
+    pub fn build(mut self) -> WasmEdgeResult<AsyncWasiModule> {
+        let mut wasi_ctx = self.wasi_ctx.take().unwrap_or_default();
+        wasi_ctx.push_args(std::mem::take(&mut self.args));
+        wasi_ctx.push_envs(
+            std::mem::take(&mut self.envs)
+                .into_iter()
+                .map(|(k, v)| format!("{k}={v}"))
+                .collect(),
+        );
+
+        AsyncWasiModule::create_from_wasi_context_with_filter(wasi_ctx, |name| {
+            self.is_allowed(name)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        params,
+        r#async::vm::{AsyncInst, Vm},
+        wat2wasm, Module, Store,
+    };
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_sandbox_is_allowed() {
+        let builder = SandboxBuilder::new();
+        assert!(!builder.is_allowed("fd_write"));
+        assert!(!builder.is_allowed("random_get"));
+        assert!(!builder.is_allowed("path_open"));
+
+        let builder = SandboxBuilder::new()
+            .allow_fd_write(true)
+            .allow_random(true);
+        assert!(builder.is_allowed("fd_write"));
+        assert!(builder.is_allowed("fd_pwrite"));
+        assert!(builder.is_allowed("random_get"));
+        assert!(!builder.is_allowed("fd_read"));
+        assert!(!builder.is_allowed("fd_close"));
+        assert!(!builder.is_allowed("sock_open"));
+
+        let builder = SandboxBuilder::new().allow_fs(true);
+        assert!(builder.is_allowed("fd_close"));
+        assert!(builder.is_allowed("path_open"));
+        assert!(!builder.is_allowed("fd_write"));
+    }
+
+    #[tokio::test]
+    async fn test_sandbox_deny_by_default() {
+        let wasm_bytes = wat2wasm(
+            br#"(module
+            (import "wasi_snapshot_preview1" "random_get" (func $random_get (param i32 i32) (result i32)))
+            (memory (export "memory") 1)
+            (func (export "random") (result i32)
+              (call $random_get (i32.const 0) (i32.const 8)))
+           )
+        "#,
+        )
+        .unwrap();
+
+        for (allow, errno) in [(false, 52), (true, 0)] {
+            let mut wasi_module = SandboxBuilder::new().allow_random(allow).build().unwrap();
+
+            let mut instances: HashMap<String, &mut (dyn AsyncInst + Send)> = HashMap::new();
+            instances.insert(wasi_module.name().to_string(), wasi_module.as_mut());
+            let mut vm = Vm::new(Store::new(None, instances).unwrap());
+
+            let module = Module::from_bytes(None, &wasm_bytes).unwrap();
+            vm.register_module(None, module).unwrap();
+            let returns = vm.run_func(None, "random", params!()).await.unwrap();
+            assert_eq!(returns[0].to_i32(), errno);
+        }
+    }
+}
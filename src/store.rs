@@ -1,8 +1,11 @@
 //! Defines WasmEdge Store struct.
 
-use std::{collections::HashMap, fmt::Debug};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Debug,
+};
 
-use crate::{config::Config, Module, WasmEdgeResult};
+use crate::{config::Config, ExternalInstanceType, Module, WasmEdgeResult};
 use sys::{AsInstance, Instance};
 use wasmedge_sys as sys;
 
@@ -12,16 +15,66 @@ pub struct Store<'inst, T: ?Sized> {
     pub(crate) inner: sys::Store,
     pub(crate) instances: HashMap<String, &'inst mut T>,
     pub(crate) wasm_instance_map: HashMap<String, Instance>,
+    // the modules of the named wasm module instances, which are registered again if reloading them fails
+    pub(crate) wasm_modules: HashMap<String, Module>,
+    // the names of the named wasm modules in the order they were first registered
+    pub(crate) registration_order: Vec<String>,
     pub(crate) executor: sys::Executor,
+    // the config used to create the stores of the spawned threads
+    #[cfg(feature = "threads")]
+    pub(crate) config: Option<Config>,
+    // the import modules owned by this store, which are dropped after `instances`
+    owned_instances: OwnedInstances<T>,
 }
 
+// Holds the import modules moved into a store by `Store::new_owned`.
+struct OwnedInstances<T: ?Sized>(Vec<*mut T>);
+impl<T: ?Sized> Drop for OwnedInstances<T> {
+    fn drop(&mut self) {
+        for ptr in self.0.drain(..) {
+            drop(unsafe { Box::from_raw(ptr) });
+        }
+    }
+}
+unsafe impl<T: ?Sized + Send> Send for OwnedInstances<T> {}
+unsafe impl<T: ?Sized + Sync> Sync for OwnedInstances<T> {}
+
 impl<T: ?Sized> Debug for Store<'_, T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        /// The counts of the exported instances of a registered module.
+        #[derive(Debug)]
+        #[allow(dead_code)]
+        struct Exports {
+            funcs: u32,
+            memories: u32,
+            tables: u32,
+            globals: u32,
+        }
+
+        let modules: BTreeMap<_, _> = self
+            .inner
+            .module_names()
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|name| {
+                let inst = self.inner.module(&name).ok()?;
+                let exports = Exports {
+                    funcs: inst.func_len(),
+                    memories: inst.mem_len(),
+                    tables: inst.table_len(),
+                    globals: inst.global_len(),
+                };
+                Some((name, exports))
+            })
+            .collect();
+
+        let mut import_modules: Vec<_> = self.instances.keys().collect();
+        import_modules.sort();
+
         f.debug_struct("Store")
-            .field("inner", &self.inner)
-            .field("instance_map", &self.instances.keys())
-            .field("wasm_instance_map", &self.wasm_instance_map.keys())
-            .field("wasm_instance_map", &self.executor)
+            .field("modules", &modules)
+            .field("import_modules", &import_modules)
+            .field("executor", &self.executor)
             .finish()
     }
 }
@@ -37,7 +90,7 @@ impl<'inst, T: AsInstance + ?Sized> Store<'inst, T> {
         instances: HashMap<String, &'inst mut T>,
     ) -> WasmEdgeResult<Self> {
         let mut store = sys::Store::create()?;
-        let mut executor = sys::Executor::create(config.map(|cfg| cfg.inner.as_ref()), None)?;
+        let mut executor = create_executor(config.map(|cfg| cfg.inner.as_ref()))?;
 
         for v in instances.values() {
             executor.register_import_module(&mut store, *v)?;
@@ -47,10 +100,47 @@ impl<'inst, T: AsInstance + ?Sized> Store<'inst, T> {
             inner: store,
             instances,
             wasm_instance_map: Default::default(),
+            wasm_modules: Default::default(),
+            registration_order: Vec::new(),
             executor,
+            #[cfg(feature = "threads")]
+            config: config.cloned(),
+            owned_instances: OwnedInstances(Vec::new()),
         })
     }
 
+    /// Creates a new [Store] that takes the ownership of the given import modules, so neither the import modules nor the map of them need to outlive the store. The import modules are dropped together with the store.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The global configuration.
+    ///
+    /// * `instances` - The import modules keyed by their module names.
+    ///
+    /// # Error
+    ///
+    /// If fail to create a new [Store], then an error is returned.
+    pub fn new_owned(
+        config: Option<&Config>,
+        instances: HashMap<String, Box<T>>,
+    ) -> WasmEdgeResult<Store<'static, T>>
+    where
+        T: 'static,
+    {
+        // declared before `refs`, so the import modules are freed after the references to them on error
+        let mut owned = OwnedInstances(Vec::with_capacity(instances.len()));
+        let mut refs = HashMap::with_capacity(instances.len());
+        for (name, inst) in instances {
+            let ptr = Box::into_raw(inst);
+            owned.0.push(ptr);
+            refs.insert(name, unsafe { &mut *ptr });
+        }
+
+        let mut store = Store::new(config, refs)?;
+        store.owned_instances = owned;
+        Ok(store)
+    }
+
     /// Registers and instantiates a WasmEdge [compiled module](crate::Module) into this [store](crate::Store) as an anonymous active [module instance](crate::Instance), and returns the module instance.
     ///
     /// # Arguments
@@ -79,10 +169,17 @@ impl<'inst, T: AsInstance + ?Sized> Store<'inst, T> {
             inner,
             executor,
             wasm_instance_map,
+            wasm_modules,
+            registration_order,
             ..
         } = self;
         let name = name.as_ref().to_string();
         let inst = executor.register_named_module(inner, &module.inner, &name)?;
+        // a reloaded module keeps its original position
+        if !registration_order.contains(&name) {
+            registration_order.push(name.clone());
+        }
+        wasm_modules.insert(name.clone(), module.clone());
         wasm_instance_map.insert(name, inst);
         Ok(())
     }
@@ -92,6 +189,16 @@ impl<'inst, T: AsInstance + ?Sized> Store<'inst, T> {
         self.instances.len() + self.wasm_instance_map.len()
     }
 
+    /// Returns the number of the named [module instances](crate::Instance) registered in the underlying WasmEdge store, including both the import modules and the named wasm modules.
+    pub fn module_count(&self) -> usize {
+        self.inner.module_len() as usize
+    }
+
+    /// Returns the number of the import modules registered in this [store](crate::Store).
+    pub fn import_module_count(&self) -> usize {
+        self.instances.len()
+    }
+
     /// Returns the names of all registered named [module instances](crate::Instance).
     pub fn instance_names(&self) -> Vec<String> {
         self.instances
@@ -112,6 +219,37 @@ impl<'inst, T: AsInstance + ?Sized> Store<'inst, T> {
         self.instances.contains_key(&mod_name) || self.wasm_instance_map.contains_key(&mod_name)
     }
 
+    /// Checks if a named module instance in the [store](crate::Store) exports an instance with the given name and the same kind as the given type.
+    pub(crate) fn exports(
+        &self,
+        mod_name: impl AsRef<str>,
+        name: impl AsRef<str>,
+        ty: &ExternalInstanceType,
+    ) -> bool {
+        fn exported<I: AsInstance + ?Sized>(
+            inst: &I,
+            name: &str,
+            ty: &ExternalInstanceType,
+        ) -> bool {
+            let names = match ty {
+                ExternalInstanceType::Func(_) => inst.func_names(),
+                ExternalInstanceType::Table(_) => inst.table_names(),
+                ExternalInstanceType::Memory(_) => inst.mem_names(),
+                ExternalInstanceType::Global(_) => inst.global_names(),
+            };
+            names.is_some_and(|names| names.iter().any(|n| n == name))
+        }
+
+        let (mod_name, name) = (mod_name.as_ref(), name.as_ref());
+        if let Some(inst) = self.instances.get(mod_name) {
+            exported(*inst, name, ty)
+        } else if let Some(inst) = self.wasm_instance_map.get(mod_name) {
+            exported(inst, name, ty)
+        } else {
+            false
+        }
+    }
+
     pub fn get_instance_and_executor(
         &mut self,
         mod_name: impl AsRef<str>,
@@ -136,3 +274,14 @@ impl<'inst, T: AsInstance + ?Sized> Store<'inst, T> {
         &mut self.executor
     }
 }
+
+/// Creates an [executor](sys::Executor) with the given configuration, which also creates the statistics required to count the instructions or measure the costs.
+pub(crate) fn create_executor(config: Option<&sys::Config>) -> WasmEdgeResult<sys::Executor> {
+    let stat = match config {
+        Some(cfg) if cfg.is_instruction_counting() || cfg.is_cost_measuring() => {
+            Some(sys::Statistics::create()?)
+        }
+        _ => None,
+    };
+    sys::Executor::create(config, stat)
+}
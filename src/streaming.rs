@@ -0,0 +1,147 @@
+//! Defines the helpers for validating WebAssembly binaries delivered in chunks.
+
+use crate::{config::Config, error::WasmEdgeError, Module, WasmEdgeResult};
+use wasmparser::{Chunk, Parser, ValidPayload, Validator};
+
+/// A [WasmStreamValidator] validates a WebAssembly binary section by section while its chunks are delivered, for example, from a network stream, so that a malformed binary is rejected as soon as the broken section arrives.
+///
+/// Notice that the WasmEdge C API can only load a module from a complete binary, therefore, the delivered chunks are kept until [finalize](WasmStreamValidator::finalize), which loads and validates the binary into a [Module] with the given [config](crate::config::Config).
+///
+/// # Example
+///
+/// ```rust
+/// use wasmedge_sdk::{streaming::WasmStreamValidator, wat2wasm};
+///
+/// let wasm_bytes = wat2wasm(br#"(module (func (export "f")))"#).unwrap();
+///
+/// let mut validator = WasmStreamValidator::new(None);
+/// for chunk in wasm_bytes.chunks(4) {
+///     validator.write(chunk).unwrap();
+/// }
+/// let module = validator.finalize().unwrap();
+/// assert_eq!(module.count_of_exports(), 1);
+/// ```
+pub struct WasmStreamValidator<'a> {
+    config: Option<&'a Config>,
+    bytes: Vec<u8>,
+    // the number of the bytes parsed so far
+    offset: usize,
+    parser: Parser,
+    validator: Validator,
+    finished: bool,
+}
+impl<'a> WasmStreamValidator<'a> {
+    /// Creates a new [WasmStreamValidator].
+    ///
+    /// # Argument
+    ///
+    /// * `config` - The global configuration used to load the [Module] in [finalize](WasmStreamValidator::finalize).
+    pub fn new(config: Option<&'a Config>) -> Self {
+        Self {
+            config,
+            bytes: Vec::new(),
+            offset: 0,
+            parser: Parser::new(0),
+            validator: Validator::new(),
+            finished: false,
+        }
+    }
+
+    /// Delivers the next chunk of the binary, and validates the sections completed by the chunk.
+    ///
+    /// # Argument
+    ///
+    /// * `chunk` - The next chunk of the binary.
+    ///
+    /// # Error
+    ///
+    /// If any completed section is invalid, or any byte is delivered after the end of the binary, then an error is returned.
+    pub fn write(&mut self, chunk: &[u8]) -> WasmEdgeResult<()> {
+        self.bytes.extend_from_slice(chunk);
+        self.validate(false)
+    }
+
+    /// Validates the remaining sections, and loads the delivered binary into a [Module].
+    ///
+    /// # Error
+    ///
+    /// If the binary is incomplete or invalid, or fail to load the [Module], then an error is returned.
+    pub fn finalize(mut self) -> WasmEdgeResult<Module> {
+        self.validate(true)?;
+        Module::from_bytes(self.config, &self.bytes)
+    }
+
+    fn validate(&mut self, eof: bool) -> WasmEdgeResult<()> {
+        let stream_error = |e: wasmparser::BinaryReaderError| {
+            Box::new(WasmEdgeError::Operation(format!(
+                "failed to validate the wasm stream: {e}"
+            )))
+        };
+
+        while !self.finished {
+            let (consumed, payload) = match self
+                .parser
+                .parse(&self.bytes[self.offset..], eof)
+                .map_err(stream_error)?
+            {
+                Chunk::NeedMoreData(_) => return Ok(()),
+                Chunk::Parsed { consumed, payload } => (consumed, payload),
+            };
+            match self.validator.payload(&payload).map_err(stream_error)? {
+                ValidPayload::Func(func, body) => {
+                    let mut func_validator = func.into_validator(Default::default());
+                    func_validator.validate(&body).map_err(stream_error)?;
+                }
+                ValidPayload::End(_) => self.finished = true,
+                _ => {}
+            }
+            self.offset += consumed;
+        }
+
+        if self.offset < self.bytes.len() {
+            return Err(Box::new(WasmEdgeError::Operation(
+                "failed to validate the wasm stream: unexpected bytes after the end of the binary"
+                    .into(),
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wat2wasm;
+
+    #[test]
+    fn test_stream_validator() {
+        let wasm_bytes = wat2wasm(
+            br#"(module
+              (func (export "add") (param i32 i32) (result i32)
+                (i32.add (local.get 0) (local.get 1)))
+            )"#,
+        )
+        .unwrap();
+
+        let mut validator = WasmStreamValidator::new(None);
+        for chunk in wasm_bytes.chunks(3) {
+            validator.write(chunk).unwrap();
+        }
+        let module = validator.finalize().unwrap();
+        assert_eq!(module.count_of_exports(), 1);
+
+        // the broken function body is rejected before the end of the stream
+        let mut broken = wasm_bytes.to_vec();
+        let len = broken.len();
+        broken[len - 3] = 0xff;
+        let mut validator = WasmStreamValidator::new(None);
+        assert!(broken
+            .chunks(3)
+            .any(|chunk| validator.write(chunk).is_err()));
+
+        // the binary is incomplete
+        let mut validator = WasmStreamValidator::new(None);
+        validator.write(&wasm_bytes[..10]).unwrap();
+        assert!(validator.finalize().is_err());
+    }
+}
@@ -0,0 +1,166 @@
+//! Defines helpers for writing unit tests against WebAssembly modules.
+
+use std::collections::HashMap;
+
+use crate::{
+    vm::{SyncInst, Vm},
+    wat2wasm, Module, Store, WasmEdgeResult, WasmValue,
+};
+
+/// A [WasmTestHarness] wraps the boilerplate needed to run the exported functions of a single WebAssembly module, which is convenient for `#[test]` functions.
+///
+/// The module is registered into a fresh [Vm](crate::Vm) as the active module, and no import modules are provided. All the constructors and [call](WasmTestHarness::call) panic on failure, since they are designed to be used in tests.
+///
+/// # Example
+///
+/// ```rust
+/// use wasmedge_sdk::{params, testing::WasmTestHarness, WasmVal};
+///
+/// let mut harness = WasmTestHarness::from_wat(
+///     br#"(module
+///       (func (export "add") (param i32 i32) (result i32)
+///         (i32.add (local.get 0) (local.get 1)))
+///     )"#,
+/// );
+///
+/// let returns = harness.call("add", params!(1, 2));
+/// assert_eq!(returns[0].to_i32(), 3);
+/// ```
+pub struct WasmTestHarness {
+    vm: Vm<'static, dyn SyncInst>,
+}
+impl WasmTestHarness {
+    /// Creates a new [WasmTestHarness] from the given WebAssembly text (or binary) source.
+    ///
+    /// # Argument
+    ///
+    /// * `source` - The in-memory bytes in the WebAssembly text format or binary format.
+    ///
+    /// # Panics
+    ///
+    /// If fail to parse, load, validate or instantiate the module, then this function panics.
+    pub fn from_wat(source: &[u8]) -> Self {
+        let wasm_bytes = wat2wasm(source).expect("[wasmedge-sdk] failed to parse the wat source");
+        Self::from_bytes(wasm_bytes)
+    }
+
+    /// Creates a new [WasmTestHarness] from the given WebAssembly binary bytes.
+    ///
+    /// # Argument
+    ///
+    /// * `bytes` - The in-memory bytes of a WebAssembly binary.
+    ///
+    /// # Panics
+    ///
+    /// If fail to load, validate or instantiate the module, then this function panics.
+    pub fn from_bytes(bytes: impl AsRef<[u8]>) -> Self {
+        let module =
+            Module::from_bytes(None, bytes).expect("[wasmedge-sdk] failed to load the wasm module");
+        Self::from_module(module)
+    }
+
+    /// Creates a new [WasmTestHarness] from the given [module](crate::Module).
+    ///
+    /// # Argument
+    ///
+    /// * `module` - The validated module to run.
+    ///
+    /// # Panics
+    ///
+    /// If fail to instantiate the module, then this function panics.
+    pub fn from_module(module: Module) -> Self {
+        let store = Store::new(None, HashMap::<String, &'static mut dyn SyncInst>::new())
+            .expect("[wasmedge-sdk] failed to create a store");
+        let mut vm = Vm::new(store);
+        vm.register_module(None, module)
+            .expect("[wasmedge-sdk] failed to instantiate the wasm module");
+        Self { vm }
+    }
+
+    /// Runs an exported function of the module and returns the results.
+    ///
+    /// # Arguments
+    ///
+    /// * `func_name` - The exported name of the target wasm function.
+    ///
+    /// * `args` - The arguments to be passed to the target wasm function.
+    ///
+    /// # Panics
+    ///
+    /// If fail to run the wasm function, then this function panics. Use [try_call](WasmTestHarness::try_call) to check the failures, such as traps.
+    pub fn call(
+        &mut self,
+        func_name: impl AsRef<str>,
+        args: impl IntoIterator<Item = WasmValue>,
+    ) -> Vec<WasmValue> {
+        let func_name = func_name.as_ref();
+        match self.try_call(func_name, args) {
+            Ok(returns) => returns,
+            Err(e) => panic!("[wasmedge-sdk] failed to run `{func_name}`: {e}"),
+        }
+    }
+
+    /// Runs an exported function of the module and returns the results or the error.
+    ///
+    /// # Arguments
+    ///
+    /// * `func_name` - The exported name of the target wasm function.
+    ///
+    /// * `args` - The arguments to be passed to the target wasm function.
+    ///
+    /// # Error
+    ///
+    /// If fail to run the wasm function, then an error is returned.
+    pub fn try_call(
+        &mut self,
+        func_name: impl AsRef<str>,
+        args: impl IntoIterator<Item = WasmValue>,
+    ) -> WasmEdgeResult<Vec<WasmValue>> {
+        self.vm.run_func(None, func_name, args)
+    }
+
+    /// Returns a mutable reference to the internal [vm](crate::Vm).
+    pub fn vm_mut(&mut self) -> &mut Vm<'static, dyn SyncInst> {
+        &mut self.vm
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        error::{CoreError, CoreExecutionError, WasmEdgeError},
+        params, WasmVal,
+    };
+
+    #[test]
+    fn test_harness_call() {
+        let mut harness = WasmTestHarness::from_wat(
+            br#"(module
+              (func (export "add") (param i32 i32) (result i32)
+                (i32.add (local.get 0) (local.get 1)))
+              (func (export "trap")
+                unreachable)
+            )"#,
+        );
+
+        let returns = harness.call("add", params!(2, 3));
+        assert_eq!(returns.len(), 1);
+        assert_eq!(returns[0].to_i32(), 5);
+
+        let result = harness.try_call("trap", params!());
+        assert_eq!(
+            result.unwrap_err(),
+            Box::new(WasmEdgeError::Core(CoreError::Execution(
+                CoreExecutionError::Unreachable
+            )))
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_harness_call_not_found() {
+        let mut harness = WasmTestHarness::from_wat(br#"(module)"#);
+        harness.call("missing", params!());
+    }
+}
@@ -0,0 +1,89 @@
+//! Defines the helpers for running the WebAssembly modules using the [Threads proposal](https://github.com/WebAssembly/threads/blob/main/proposals/threads/Overview.md) in multiple threads.
+
+use crate::{MemoryType, WasmEdgeResult};
+use sys::AsInstance;
+use wasmedge_sys as sys;
+
+/// A [SharedMemory] is a shared linear memory, which is imported by the instances of the wasm modules running in different threads, see [Vm::spawn_thread](crate::Vm::spawn_thread).
+///
+/// The memory is exported by an import module named `mod_name` as `mem_name`, so the wasm modules should import it as `(import "<mod_name>" "<mem_name>" (memory <initial_pages> <max_pages> shared))`.
+#[derive(Debug)]
+pub struct SharedMemory {
+    inner: sys::ImportModule<()>,
+    mem_name: String,
+}
+impl SharedMemory {
+    /// Creates a new [SharedMemory].
+    ///
+    /// # Arguments
+    ///
+    /// * `mod_name` - The name of the import module which exports the memory.
+    ///
+    /// * `mem_name` - The exported name of the memory.
+    ///
+    /// * `initial_pages` - The initial size of the memory in pages.
+    ///
+    /// * `max_pages` - The maximum size of the memory in pages, which is required by a shared memory.
+    ///
+    /// # Error
+    ///
+    /// If fail to create the memory or the import module, then an error is returned.
+    pub fn new(
+        mod_name: impl AsRef<str>,
+        mem_name: impl AsRef<str>,
+        initial_pages: u32,
+        max_pages: u32,
+    ) -> WasmEdgeResult<Self> {
+        let ty = MemoryType::new(initial_pages, Some(max_pages), true)?;
+        let memory = sys::Memory::create(&ty)?;
+        let mut inner = sys::ImportModule::create(mod_name, Box::new(()))?;
+        inner.add_memory(mem_name.as_ref(), memory);
+
+        Ok(Self {
+            inner,
+            mem_name: mem_name.as_ref().to_string(),
+        })
+    }
+
+    /// Returns the name of the import module which exports the memory.
+    pub fn mod_name(&self) -> String {
+        self.inner.name().unwrap_or_default()
+    }
+
+    /// Returns the data of the memory.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - The data start offset in the memory.
+    ///
+    /// * `len` - The requested data length.
+    ///
+    /// # Error
+    ///
+    /// If the `offset + len` is larger than the data size in the memory, then an error is returned.
+    pub fn get_data(&self, offset: u32, len: u32) -> WasmEdgeResult<Vec<u8>> {
+        self.inner
+            .get_memory_ref(&self.mem_name)?
+            .get_data(offset, len)
+    }
+
+    /// Copies the data to the memory, for example, to initialize the memory before spawning the threads.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The data to copy.
+    ///
+    /// * `offset` - The data start offset in the memory.
+    ///
+    /// # Error
+    ///
+    /// If the sum of the `offset` and the data length is larger than the size of the memory, then an error is returned.
+    pub fn set_data(&mut self, data: impl AsRef<[u8]>, offset: u32) -> WasmEdgeResult<()> {
+        let mem_name = self.mem_name.clone();
+        self.inner.get_memory_mut(mem_name)?.set_data(data, offset)
+    }
+
+    pub(crate) fn as_import_module(&self) -> &sys::ImportModule<()> {
+        &self.inner
+    }
+}
@@ -1,4 +1,9 @@
-//! Defines WasmEdge Driver and CoreVersion types
+//! Defines WasmEdge Driver, CoreVersion and ModuleGraph types, and the helpers for WebAssembly binaries
+use crate::{
+    error::{CoreError, CoreLoadError, WasmEdgeError},
+    Module, ProposalName, WasmEdgeResult,
+};
+use std::collections::{HashMap, VecDeque};
 use wasmedge_sys::utils;
 
 /// Defines WasmEdge Driver functions
@@ -56,3 +61,408 @@ impl CoreVersion {
         wasmedge_sys::utils::version_string()
     }
 }
+
+/// The version and the supported proposals of the WasmEdge library linked at runtime, which can be used to check the installed library before running wasm services.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WasmEdgeVersion {
+    /// The major version value.
+    pub major: u32,
+    /// The minor version value.
+    pub minor: u32,
+    /// The patch version value.
+    pub patch: u32,
+}
+impl WasmEdgeVersion {
+    /// Returns the version of the WasmEdge library linked at runtime.
+    pub fn current() -> Self {
+        Self {
+            major: CoreVersion::major(),
+            minor: CoreVersion::minor(),
+            patch: CoreVersion::patch(),
+        }
+    }
+
+    /// Checks if the WasmEdge library can enable the given proposal.
+    ///
+    /// # Argument
+    ///
+    /// * `proposal` - The proposal to check.
+    pub fn supports_proposal(&self, proposal: ProposalName) -> bool {
+        match wasmedge_sys::Config::create() {
+            Ok(mut config) => {
+                config.add_proposal(proposal);
+                config.has_proposal(proposal)
+            }
+            Err(_) => false,
+        }
+    }
+}
+
+/// Defines the dependency graph of a set of named [modules](crate::Module), which is used to determine the order of registering them.
+///
+/// A module depends on another one if it imports anything from the module name of the other one. The imports from the module names that are not in the graph, such as the ones provided by host import objects, are ignored.
+#[derive(Debug)]
+pub struct ModuleGraph<'a> {
+    modules: Vec<(&'a str, &'a Module)>,
+    // the indices of the modules which the module at the same position depends on
+    deps: Vec<Vec<usize>>,
+}
+impl<'a> ModuleGraph<'a> {
+    /// Creates a new [ModuleGraph].
+    ///
+    /// # Argument
+    ///
+    /// * `modules` - The modules with the module names they will be registered as.
+    pub fn new(modules: Vec<(&'a str, &'a Module)>) -> Self {
+        let indices: HashMap<&str, usize> = modules
+            .iter()
+            .enumerate()
+            .map(|(idx, (name, _))| (*name, idx))
+            .collect();
+
+        let deps = modules
+            .iter()
+            .enumerate()
+            .map(|(idx, (_, module))| {
+                let mut deps: Vec<usize> = module
+                    .imports()
+                    .iter()
+                    .filter_map(|import| indices.get(import.module_name().as_ref()).copied())
+                    .filter(|dep| *dep != idx)
+                    .collect();
+                deps.sort_unstable();
+                deps.dedup();
+                deps
+            })
+            .collect();
+
+        Self { modules, deps }
+    }
+
+    /// Returns the modules in the order of registration, that is, every module comes after all the modules it depends on. The modules that do not depend on each other keep the order they are given in.
+    ///
+    /// # Error
+    ///
+    /// If there is a circular dependency among the modules, then `WasmEdgeError::CircularDependency` is returned.
+    pub fn topological_order(&self) -> WasmEdgeResult<Vec<&'a Module>> {
+        let mut in_degrees: Vec<usize> = self.deps.iter().map(|deps| deps.len()).collect();
+        let mut dependents = vec![vec![]; self.modules.len()];
+        for (idx, deps) in self.deps.iter().enumerate() {
+            for dep in deps {
+                dependents[*dep].push(idx);
+            }
+        }
+
+        let mut queue: VecDeque<usize> = (0..self.modules.len())
+            .filter(|idx| in_degrees[*idx] == 0)
+            .collect();
+        let mut order = Vec::with_capacity(self.modules.len());
+        while let Some(idx) = queue.pop_front() {
+            order.push(self.modules[idx].1);
+            for dependent in &dependents[idx] {
+                in_degrees[*dependent] -= 1;
+                if in_degrees[*dependent] == 0 {
+                    queue.push_back(*dependent);
+                }
+            }
+        }
+
+        if order.len() != self.modules.len() {
+            return Err(Box::new(WasmEdgeError::CircularDependency));
+        }
+
+        Ok(order)
+    }
+}
+
+/// Removes all the custom sections, such as the `name` section and the debug info sections, from the given WebAssembly binary, while the other sections are kept as they are.
+///
+/// If the bytes are not a well-formed WebAssembly binary, then the malformed part starting from the first unparsable section is kept unchanged.
+///
+/// # Argument
+///
+/// * `bytes` - The in-memory bytes of a WebAssembly binary.
+pub fn strip_custom_sections(bytes: &[u8]) -> Vec<u8> {
+    const HEADER_LEN: usize = 8;
+    const CUSTOM_SECTION_ID: u8 = 0;
+
+    if bytes.len() < HEADER_LEN {
+        return bytes.to_vec();
+    }
+
+    let mut stripped = Vec::with_capacity(bytes.len());
+    stripped.extend_from_slice(&bytes[..HEADER_LEN]);
+
+    let mut offset = HEADER_LEN;
+    while offset < bytes.len() {
+        let id = bytes[offset];
+        let section_end = read_leb128_u32(&bytes[offset + 1..])
+            .and_then(|(size, len)| (offset + 1 + len).checked_add(size as usize))
+            .filter(|end| *end <= bytes.len());
+
+        match section_end {
+            Some(end) => {
+                if id != CUSTOM_SECTION_ID {
+                    stripped.extend_from_slice(&bytes[offset..end]);
+                }
+                offset = end;
+            }
+            None => {
+                stripped.extend_from_slice(&bytes[offset..]);
+                break;
+            }
+        }
+    }
+
+    stripped
+}
+
+/// Returns the payload size of each section in the given WebAssembly binary, which is keyed by the section name, such as `"type"`, `"import"`, `"function"`, `"code"`, `"data"` and `"custom"`. The sizes of all the custom sections are summed up under `"custom"`.
+///
+/// The section headers are walked without validating the section contents.
+///
+/// # Argument
+///
+/// * `bytes` - The in-memory bytes of a WebAssembly binary.
+///
+/// # Error
+///
+/// If the bytes are not a WebAssembly binary, or any section header is malformed, then an error is returned.
+pub fn section_sizes(bytes: &[u8]) -> WasmEdgeResult<HashMap<&'static str, usize>> {
+    const SECTION_NAMES: [&str; 14] = [
+        "custom",
+        "type",
+        "import",
+        "function",
+        "table",
+        "memory",
+        "global",
+        "export",
+        "start",
+        "element",
+        "code",
+        "data",
+        "datacount",
+        "tag",
+    ];
+
+    let load_error = |e| Box::new(WasmEdgeError::Core(CoreError::Load(e)));
+
+    if bytes.len() < 8 {
+        return Err(load_error(CoreLoadError::UnexpectedEnd));
+    }
+    if &bytes[..4] != b"\0asm" {
+        return Err(load_error(CoreLoadError::MalformedMagic));
+    }
+    if bytes[4..8] != [1, 0, 0, 0] {
+        return Err(load_error(CoreLoadError::MalformedVersion));
+    }
+
+    let mut sizes = HashMap::new();
+    let mut offset = 8;
+    while offset < bytes.len() {
+        let name = SECTION_NAMES
+            .get(bytes[offset] as usize)
+            .ok_or(load_error(CoreLoadError::MalformedSection))?;
+        let (size, len) = read_leb128_u32(&bytes[offset + 1..])
+            .ok_or(load_error(CoreLoadError::UnexpectedEnd))?;
+        let end = offset + 1 + len + size as usize;
+        if end > bytes.len() {
+            return Err(load_error(CoreLoadError::UnexpectedEnd));
+        }
+
+        *sizes.entry(*name).or_default() += size as usize;
+        offset = end;
+    }
+
+    Ok(sizes)
+}
+
+/// Disassembles the given WebAssembly binary into the pretty-printed WebAssembly text format, which is useful for debugging the loaded modules.
+///
+/// # Argument
+///
+/// * `bytes` - The in-memory bytes of a WebAssembly binary.
+///
+/// # Error
+///
+/// If fail to parse the WebAssembly binary, then an error is returned.
+#[cfg(feature = "wasm-tools")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wasm-tools")))]
+pub fn wasm_to_wat(bytes: &[u8]) -> WasmEdgeResult<String> {
+    wasmprinter::print_bytes(bytes).map_err(|e| {
+        Box::new(WasmEdgeError::Operation(format!(
+            "failed to disassemble the wasm binary: {e}"
+        )))
+    })
+}
+
+// Returns the decoded value and the number of bytes it takes.
+fn read_leb128_u32(bytes: &[u8]) -> Option<(u32, usize)> {
+    let mut value: u32 = 0;
+    for (idx, byte) in bytes.iter().take(5).enumerate() {
+        value |= ((byte & 0x7f) as u32).checked_shl(7 * idx as u32)?;
+        if byte & 0x80 == 0 {
+            return Some((value, idx + 1));
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wat2wasm;
+
+    #[test]
+    fn test_wasmedge_version() {
+        let version = WasmEdgeVersion::current();
+        assert_eq!(version.major, CoreVersion::major());
+        assert_eq!(version.minor, CoreVersion::minor());
+        assert_eq!(version.patch, CoreVersion::patch());
+
+        // the proposals of the WebAssembly 2.0 standard are always supported
+        assert!(version.supports_proposal(ProposalName::BulkMemoryOperations));
+        assert!(version.supports_proposal(ProposalName::Simd));
+    }
+
+    #[test]
+    fn test_module_graph_topological_order() {
+        let wasm_bytes = wat2wasm(br#"(module (func (export "f")))"#).unwrap();
+        let base = Module::from_bytes(None, wasm_bytes).unwrap();
+
+        let wasm_bytes = wat2wasm(
+            br#"(module (import "base" "f" (func)) (import "env" "g" (func)) (func (export "f")))"#,
+        )
+        .unwrap();
+        let middle = Module::from_bytes(None, wasm_bytes).unwrap();
+
+        let wasm_bytes =
+            wat2wasm(br#"(module (import "middle" "f" (func)) (import "base" "f" (func)))"#)
+                .unwrap();
+        let top = Module::from_bytes(None, wasm_bytes).unwrap();
+
+        let graph = ModuleGraph::new(vec![("top", &top), ("middle", &middle), ("base", &base)]);
+        let order = graph.topological_order().unwrap();
+        assert_eq!(order.len(), 3);
+        assert!(std::ptr::eq(order[0], &base));
+        assert!(std::ptr::eq(order[1], &middle));
+        assert!(std::ptr::eq(order[2], &top));
+
+        // circular dependency
+        let wasm_bytes =
+            wat2wasm(br#"(module (import "b" "f" (func)) (func (export "f")))"#).unwrap();
+        let a = Module::from_bytes(None, wasm_bytes).unwrap();
+        let wasm_bytes =
+            wat2wasm(br#"(module (import "a" "f" (func)) (func (export "f")))"#).unwrap();
+        let b = Module::from_bytes(None, wasm_bytes).unwrap();
+
+        let graph = ModuleGraph::new(vec![("a", &a), ("b", &b), ("base", &base)]);
+        let result = graph.topological_order();
+        assert_eq!(
+            result.unwrap_err(),
+            Box::new(WasmEdgeError::CircularDependency)
+        );
+    }
+
+    #[test]
+    fn test_strip_custom_sections() {
+        let wasm_bytes = wat2wasm(
+            br#"(module $math
+              (func $add (export "add") (param $a i32) (param $b i32) (result i32)
+                (i32.add (local.get $a) (local.get $b)))
+            )"#,
+        )
+        .unwrap()
+        .into_owned();
+
+        // append a custom section with a multi-byte section size
+        let mut with_custom = wasm_bytes.clone();
+        with_custom.extend_from_slice(&[0x00, 0x85, 0x01, 0x04]);
+        with_custom.extend_from_slice(b"test");
+        with_custom.extend_from_slice(&[0xab; 128]);
+
+        // the `name` section generated from the identifiers is stripped too
+        let stripped = strip_custom_sections(&with_custom);
+        assert!(stripped.len() < wasm_bytes.len());
+        assert_eq!(strip_custom_sections(&stripped), stripped);
+
+        let module = Module::from_bytes(None, &stripped).unwrap();
+        assert_eq!(module.count_of_exports(), 1);
+        assert!(module.get_export("add").is_some());
+
+        // malformed input is kept as it is
+        let truncated = &with_custom[..with_custom.len() - 1];
+        assert!(strip_custom_sections(truncated).ends_with(&[0xab; 127]));
+        assert_eq!(strip_custom_sections(b"\0asm"), b"\0asm");
+    }
+
+    #[test]
+    fn test_section_sizes() {
+        let wasm_bytes = wat2wasm(
+            br#"(module
+              (memory 1)
+              (func (export "add") (param i32 i32) (result i32)
+                (i32.add (local.get 0) (local.get 1)))
+              (data (i32.const 0) "hello")
+            )"#,
+        )
+        .unwrap()
+        .into_owned();
+
+        // append a custom section
+        let mut with_custom = wasm_bytes.clone();
+        with_custom.extend_from_slice(&[0x00, 0x05, 0x04]);
+        with_custom.extend_from_slice(b"test");
+
+        let sizes = section_sizes(&with_custom).unwrap();
+        for name in ["type", "function", "memory", "export", "code", "data"] {
+            assert!(sizes[name] > 0, "missing the {name} section");
+        }
+        assert!(!sizes.contains_key("import"));
+        assert_eq!(sizes["custom"], 5);
+        assert_eq!(sizes["data"], 11);
+
+        // malformed input
+        assert_eq!(
+            section_sizes(b"\0asm").unwrap_err(),
+            Box::new(WasmEdgeError::Core(CoreError::Load(
+                CoreLoadError::UnexpectedEnd
+            )))
+        );
+        assert_eq!(
+            section_sizes(&with_custom[..with_custom.len() - 1]).unwrap_err(),
+            Box::new(WasmEdgeError::Core(CoreError::Load(
+                CoreLoadError::UnexpectedEnd
+            )))
+        );
+        assert_eq!(
+            section_sizes(b"\0wasm\x01\0\0").unwrap_err(),
+            Box::new(WasmEdgeError::Core(CoreError::Load(
+                CoreLoadError::MalformedMagic
+            )))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "wasm-tools")]
+    fn test_wasm_to_wat() {
+        let wasm_bytes = wat2wasm(
+            br#"(module
+              (func (export "add") (param i32 i32) (result i32)
+                (i32.add (local.get 0) (local.get 1)))
+            )"#,
+        )
+        .unwrap();
+
+        let wat = wasm_to_wat(&wasm_bytes).unwrap();
+        assert!(wat.starts_with("(module"));
+        assert!(wat.contains("(export \"add\""));
+        assert!(wat.contains("i32.add"));
+
+        // the round trip gives the same binary
+        assert_eq!(wat2wasm(wat.as_bytes()).unwrap().as_ref(), &wasm_bytes[..]);
+
+        assert!(wasm_to_wat(b"not a wasm binary").is_err());
+    }
+}
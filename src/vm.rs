@@ -1,9 +1,14 @@
 //! Defines WasmEdge Vm struct.
 use crate::{
-    error::{VmError, WasmEdgeError},
-    ImportObject, Instance, Module, Store, WasmEdgeResult, WasmValue,
+    error::{
+        CoreCommonError, CoreError, CoreExecutionError, HotreloadError, TableError, VmError,
+        WasmEdgeError,
+    },
+    io::{FromWasmValList, WasmValList, WasmValTypeList},
+    ExternalInstanceType, FuncType, ImportObject, Instance, MemoryView, Module, Store, ValType,
+    WasmEdgeResult, WasmValue,
 };
-use sys::AsInstance;
+use sys::{AsFunc, AsInstance};
 use wasmedge_sys as sys;
 
 pub trait SyncInst: AsInstance {}
@@ -69,25 +74,71 @@ impl SyncInst for Instance {}
 /// assert_eq!(returns.len(), 1);
 /// assert_eq!(returns[0].to_i32(), 89);
 /// ```
-#[derive(Debug)]
 pub struct Vm<'inst, T: ?Sized + SyncInst> {
     store: Store<'inst, T>,
-    active_instance: Option<sys::Instance>,
+    anonymous_instances: std::collections::BTreeMap<u64, sys::Instance>,
+    next_anonymous_id: u64,
+    max_anonymous_instances: usize,
+    cancellation_token: crate::CancellationToken,
+    #[cfg(feature = "profiling")]
+    profiling: ProfilingData,
+}
+impl<T: ?Sized + SyncInst> std::fmt::Debug for Vm<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut modules = self.instance_names();
+        modules.sort();
+
+        f.debug_struct("Vm")
+            .field("modules", &modules)
+            .field("anonymous_instances", &self.anonymous_instances.len())
+            .field("store", &self.store)
+            .finish()
+    }
 }
 impl<'inst, T: ?Sized + SyncInst> Vm<'inst, T> {
+    /// The default maximum number of the anonymous [module instances](crate::Instance) kept by a vm. Only the active module instance is kept by default, use [set_max_anonymous_instances](Vm::set_max_anonymous_instances) to keep more.
+    pub const DEFAULT_MAX_ANONYMOUS_INSTANCES: usize = 1;
+
     pub fn new(store: Store<'inst, T>) -> Self {
         // create a Vm instance
         Vm {
             store,
-            active_instance: None,
+            anonymous_instances: std::collections::BTreeMap::new(),
+            next_anonymous_id: 0,
+            max_anonymous_instances: Self::DEFAULT_MAX_ANONYMOUS_INSTANCES,
+            cancellation_token: crate::CancellationToken::new(),
+            #[cfg(feature = "profiling")]
+            profiling: ProfilingData::default(),
         }
     }
 
+    /// Creates a new [Vm] and registers the given [wasm modules](crate::Module) in order, so that a multi-module vm is initialized in one step. The vm is returned only if all the modules are registered successfully.
+    ///
+    /// # Arguments
+    ///
+    /// * `store` - The [store](crate::Store) of the new vm.
+    ///
+    /// * `modules` - The modules to register and their names. A module without a name is registered as an anonymous instance, see [register_module](Vm::register_module).
+    ///
+    /// # Error
+    ///
+    /// If fail to register any of the modules, then an error is returned.
+    pub fn with_initial_modules(
+        store: Store<'inst, T>,
+        modules: Vec<(Option<String>, Module)>,
+    ) -> WasmEdgeResult<Self> {
+        let mut vm = Self::new(store);
+        for (mod_name, module) in modules {
+            vm.register_module(mod_name.as_deref(), module)?;
+        }
+        Ok(vm)
+    }
+
     /// Registers a [wasm module](crate::Module) into this vm as a named or active module [instance](crate::Instance).
     ///
     /// # Arguments
     ///
-    /// * `mod_name` - The exported name for the registered module. If `None`, then the module is registered as an active instance.
+    /// * `mod_name` - The exported name for the registered module. If `None`, then the module is registered as an anonymous instance, which becomes the active instance. The anonymous instances are identified by their registration order, starting from `0`. Once more than [max_anonymous_instances](Vm::max_anonymous_instances) anonymous instances are registered, the oldest ones are dropped, while the ids of the remaining ones are not changed.
     ///
     /// * `module` - The module to be registered.
     ///
@@ -105,13 +156,197 @@ impl<'inst, T: ?Sized + SyncInst> Vm<'inst, T> {
                 self.store.register_named_module(name, &module)?;
             }
             None => {
-                self.active_instance = Some(self.store.register_active_module(&module)?);
+                let inst = self.store.register_active_module(&module)?;
+                self.anonymous_instances
+                    .insert(self.next_anonymous_id, inst);
+                self.next_anonymous_id += 1;
+                self.evict_anonymous_instances();
             }
         };
 
         Ok(self)
     }
 
+    /// Registers the core module embedded in a [Component Model](https://component-model.bytecodealliance.org/) component binary into this vm, so that the functions exported by the component can be run by [run_func](Vm::run_func) in their forms lowered by the canonical ABI. If the given bytes are a core module binary, then it is registered as is.
+    ///
+    /// Notice that the WasmEdge C API cannot instantiate a component, therefore, only the components embedding a single core module are supported, such as the ones composed from one core module without adapters by [ComponentComposer](crate::component::ComponentComposer).
+    ///
+    /// # Arguments
+    ///
+    /// * `mod_name` - The exported name for the registered module. If `None`, then the module is registered as an anonymous instance, which becomes the active instance.
+    ///
+    /// * `component_bytes` - The in-memory bytes of the component binary.
+    ///
+    /// # Error
+    ///
+    /// If the bytes are neither a component nor a core module, or the component does not embed exactly one core module, or fail to register the core module, then an error is returned.
+    #[cfg(feature = "component-model")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "component-model")))]
+    pub fn register_module_from_component(
+        &mut self,
+        mod_name: Option<&str>,
+        component_bytes: &[u8],
+    ) -> WasmEdgeResult<&mut Self> {
+        use wasmparser::{Parser, Payload};
+
+        let component_error = |msg: String| {
+            Box::new(WasmEdgeError::Operation(format!(
+                "failed to register the component: {msg}"
+            )))
+        };
+
+        if Parser::is_core_wasm(component_bytes) {
+            let module = Module::from_bytes(None, component_bytes)?;
+            return self.register_module(mod_name, module);
+        }
+        if !Parser::is_component(component_bytes) {
+            return Err(component_error("not a component binary".into()));
+        }
+
+        // collect the core modules defined at the top level of the component
+        let mut modules = Vec::new();
+        let mut depth = 0;
+        for payload in Parser::new(0).parse_all(component_bytes) {
+            match payload.map_err(|e| component_error(e.to_string()))? {
+                Payload::ModuleSection {
+                    unchecked_range, ..
+                } => {
+                    if depth == 0 {
+                        modules.push(unchecked_range);
+                    }
+                    depth += 1;
+                }
+                Payload::ComponentSection { .. } => depth += 1,
+                Payload::End(_) if depth > 0 => depth -= 1,
+                _ => {}
+            }
+        }
+
+        match modules.as_slice() {
+            [range] => {
+                let bytes = &component_bytes[range.start as usize..range.end as usize];
+                let module = Module::from_bytes(None, bytes)?;
+                self.register_module(mod_name, module)
+            }
+            _ => Err(component_error(format!(
+                "{} core modules are embedded, but only one is supported",
+                modules.len()
+            ))),
+        }
+    }
+
+    /// Registers a [wasm module](crate::Module) into this vm as a named module [instance](crate::Instance), and returns a handle to the registered instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `mod_name` - The exported name for the registered module.
+    ///
+    /// * `module` - The module to be registered.
+    ///
+    /// # Error
+    ///
+    /// If fail to register the given [module](crate::Module), then an error is returned.
+    pub fn instantiate_named(
+        &mut self,
+        mod_name: &str,
+        module: Module,
+    ) -> WasmEdgeResult<NamedInstance<'_>> {
+        self.store.register_named_module(mod_name, &module)?;
+        self.named_module(mod_name)
+    }
+
+    /// Checks if all the imports of the given [module](crate::Module) are satisfied by the named module instances registered into this vm, which is useful to be called before registering the module and running its functions.
+    ///
+    /// Each import is satisfied if a registered module instance with the same module name exports an instance with the same name and the same kind.
+    ///
+    /// # Argument
+    ///
+    /// * `module` - The module to be checked.
+    ///
+    /// # Error
+    ///
+    /// * If fail to get the type of any import, then an error is returned, so that an import of an unknown type is not taken as satisfied.
+    ///
+    /// * If any import is not satisfied, then a [WasmEdgeError::ImportNotSatisfied] error describing the first unsatisfied import is returned.
+    pub fn check_import_saturation(&self, module: &Module) -> WasmEdgeResult<()> {
+        for import in module.import_list() {
+            let import = import?;
+            if !self.store.exports(&import.module, &import.name, &import.ty) {
+                return Err(Box::new(WasmEdgeError::ImportNotSatisfied {
+                    module: import.module,
+                    name: import.name,
+                    ty: import.ty,
+                }));
+            }
+        }
+        Ok(())
+    }
+
+    /// Replaces a named wasm [module instance](crate::Instance) registered into this vm with a new instance of the given [module](crate::Module), which keeps the name of the module instance, so that the subsequent calls run the functions of the new module.
+    ///
+    /// The exports and the imports of the new module are checked before the old module instance is unregistered, so that a module with different exports or unsatisfied imports leaves the old module instance registered. Because the names of the module instances must be unique in the store, the new module can only be instantiated after the old module instance is unregistered. If the instantiation fails anyway, for example, because of a trap in the start function or an import of a mismatched type, then the old module is registered again, which creates a fresh instance of it, so the state of the old module instance, such as its memories and globals, is not kept in this case.
+    ///
+    /// Notice that no reference count of the in-flight calls is kept. Since running functions requires `&mut self`, no call of this vm to the old module instance is in flight when reloading, but the [functions](crate::Function) or other exported instances fetched from the old module instance by hand must not be used after reloading.
+    ///
+    /// # Arguments
+    ///
+    /// * `mod_name` - The name of the named module instance to be reloaded.
+    ///
+    /// * `new_module` - The new module to be registered.
+    ///
+    /// # Error
+    ///
+    /// * If the named wasm module instance is not found, then [VmError::NotFoundModule](crate::error::VmError) is returned.
+    ///
+    /// * If the exports of the new module, including the names, the kinds and the function types, are different from the ones of the old module instance, then [HotreloadError::SignatureMismatch](crate::error::HotreloadError) is returned and the old module instance is kept.
+    ///
+    /// * If any import of the new module is not satisfied, then [WasmEdgeError::ImportNotSatisfied] is returned and the old module instance is kept.
+    ///
+    /// * If fail to instantiate the new module, then an error is returned and the old module is registered again.
+    pub fn hotreload_module(&mut self, mod_name: &str, new_module: Module) -> WasmEdgeResult<()> {
+        let old_inst =
+            self.store.wasm_instance_map.get(mod_name).ok_or_else(|| {
+                Box::new(WasmEdgeError::Vm(VmError::NotFoundModule(mod_name.into())))
+            })?;
+
+        if instance_exports(old_inst)? != module_exports(&new_module)? {
+            return Err(Box::new(WasmEdgeError::Hotreload(
+                HotreloadError::SignatureMismatch,
+            )));
+        }
+
+        self.check_import_saturation(&new_module)?;
+
+        // dropping the old instance unregisters it from the store
+        let old_module = self.store.wasm_modules.get(mod_name).cloned();
+        self.store.wasm_instance_map.remove(mod_name);
+        if let Err(e) = self.store.register_named_module(mod_name, &new_module) {
+            if let Some(old_module) = old_module {
+                self.store.register_named_module(mod_name, &old_module)?;
+            }
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    /// Returns a handle to a named wasm [module instance](crate::Instance) registered into this vm.
+    ///
+    /// # Argument
+    ///
+    /// * `mod_name` - The exported name of the target module instance.
+    ///
+    /// # Error
+    ///
+    /// If fail to find the named wasm module instance, then an error is returned.
+    pub fn named_module(&self, mod_name: &str) -> WasmEdgeResult<NamedInstance<'_>> {
+        let (name, inner) = self
+            .store
+            .wasm_instance_map
+            .get_key_value(mod_name)
+            .ok_or_else(|| Box::new(WasmEdgeError::Vm(VmError::NotFoundModule(mod_name.into()))))?;
+        Ok(NamedInstance { name, inner })
+    }
+
     /// Runs an exported wasm function in a (named or active) [module instance](crate::Instance).
     ///
     /// # Arguments
@@ -131,37 +366,135 @@ impl<'inst, T: ?Sized + SyncInst> Vm<'inst, T> {
         func_name: impl AsRef<str>,
         args: impl IntoIterator<Item = WasmValue>,
     ) -> WasmEdgeResult<Vec<WasmValue>> {
-        let (mut func, executor) = match mod_name {
-            Some(mod_name) => {
-                if let Some((inst, executor)) = self.store.get_instance_and_executor(mod_name) {
-                    (inst.get_func_mut(func_name.as_ref())?, executor)
-                } else if let Some((wasm_mod, executor)) =
-                    self.store.get_named_wasm_and_executor(mod_name)
-                {
-                    (wasm_mod.get_func_mut(func_name.as_ref())?, executor)
-                } else {
-                    return Err(Box::new(WasmEdgeError::Vm(VmError::NotFoundModule(
-                        mod_name.into(),
-                    ))));
-                }
+        self.call_func_in(
+            FuncOwner::Module(mod_name),
+            func_name.as_ref(),
+            |executor, func| executor.call_func(func, args),
+        )
+    }
+
+    /// Runs the `_start` functions of all the wasm [module instances](crate::Instance) registered into this vm, which are skipped if they do not export a `_start` function.
+    ///
+    /// The named module instances are run in the order they were registered, which is a dependency order because a module can only be registered after the modules it imports from. The anonymous module instances are run after them in their registration order. A failure of one `_start` function does not stop running the remaining ones.
+    ///
+    /// # Error
+    ///
+    /// If any `_start` function fails, then [WasmEdgeError::StartFailed] is returned, which collects the errors with the names of the module instances. The anonymous module instances are named as `anonymous#<id>`.
+    pub fn run_start_all(&mut self) -> WasmEdgeResult<()> {
+        const START_FUNC: &str = "_start";
+
+        let mut errors = Vec::new();
+        let Vm {
+            store,
+            anonymous_instances,
+            ..
+        } = self;
+        let order = store.registration_order.clone();
+        for name in order {
+            let Some((inst, executor)) = store.get_named_wasm_and_executor(&name) else {
+                continue;
+            };
+            let Ok(mut func) = inst.get_func_mut(START_FUNC) else {
+                continue;
+            };
+            if let Err(e) = executor.call_func(&mut func, []) {
+                errors.push((name, *e));
             }
-            None => {
-                let active_inst = self
-                    .active_instance
-                    .as_mut()
-                    .ok_or(Box::new(WasmEdgeError::Vm(VmError::NotFoundActiveModule)))?;
+        }
+        for (id, inst) in anonymous_instances.iter_mut() {
+            let Ok(mut func) = inst.get_func_mut(START_FUNC) else {
+                continue;
+            };
+            if let Err(e) = store.executor().call_func(&mut func, []) {
+                errors.push((format!("anonymous#{id}"), *e));
+            }
+        }
 
-                (
-                    active_inst.get_func_mut(func_name.as_ref())?,
-                    self.store.executor(),
-                )
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(Box::new(WasmEdgeError::StartFailed(errors)))
+        }
+    }
+
+    /// Runs an exported wasm function in a (named or active) [module instance](crate::Instance) in fuel mode, in which the executed instructions are charged by the costs of the given [FuelPolicy], and the function is stopped once the charged fuel exceeds the budget.
+    ///
+    /// Notice that the cost measuring must be enabled in the [configuration](crate::config::StatisticsConfigOptions) of the [store](crate::Store). The costs of the policy are merged into the cost table of the store during the call, so the costs of the other instructions are kept, and the cost limit of the store still applies if it runs out before the budget. After the call, the costs and the cost limit set before the call are restored.
+    ///
+    /// # Arguments
+    ///
+    /// * `mod_name` - The exported name of the module instance, which holds the target function. If `None`, then the active module is used.
+    ///
+    /// * `func_name` - The exported name of the target wasm function.
+    ///
+    /// * `args` - The arguments to be passed to the target wasm function.
+    ///
+    /// * `policy` - The costs of the instructions and the fuel budget.
+    ///
+    /// # Error
+    ///
+    /// * If the charged fuel exceeds the budget, then [WasmEdgeError::FuelExhausted] is returned with the spent fuel.
+    ///
+    /// * If the linked WasmEdge is not 0.14, whose cost table layout the opcodes are mapped to, then an error is returned.
+    ///
+    /// * If the cost measuring is not enabled, or the policy has an opcode unknown to WasmEdge, or fail to run the wasm function, then an error is returned.
+    pub fn run_func_in_fuel_mode(
+        &mut self,
+        mod_name: Option<&str>,
+        func_name: impl AsRef<str>,
+        args: impl IntoIterator<Item = WasmValue>,
+        policy: &FuelPolicy,
+    ) -> WasmEdgeResult<(Vec<WasmValue>, u64)> {
+        let costs = policy.wasmedge_costs()?;
+        let (result, spent) = self.call_func_in(
+            FuncOwner::Module(mod_name),
+            func_name.as_ref(),
+            |executor, func| {
+                let stat = executor.statistics_mut().ok_or_else(|| {
+                    Box::new(WasmEdgeError::Operation(
+                        "the cost measuring is not enabled in the config of the store".into(),
+                    ))
+                })?;
+                let cost_before = stat.cost_in_total();
+                let limit_before = stat.cost_limit();
+                let costs_before: Vec<(u16, u64)> = costs
+                    .iter()
+                    .map(|(index, _)| (*index, stat.cost(*index)))
+                    .collect();
+                stat.set_costs(costs);
+                stat.set_cost_limit(cost_before.saturating_add(policy.budget).min(limit_before));
+
+                let result = executor.call_func(func, args);
+
+                // restore the costs and the limit set before the call
+                let mut spent = 0;
+                if let Some(stat) = executor.statistics_mut() {
+                    spent = stat.cost_in_total().saturating_sub(cost_before);
+                    stat.set_costs(costs_before);
+                    stat.set_cost_limit(limit_before);
+                }
+                Ok((result, spent))
+            },
+        )?;
+        match result {
+            Ok(returns) => Ok((returns, policy.budget.saturating_sub(spent))),
+            Err(e)
+                if spent >= policy.budget
+                    && *e
+                        == WasmEdgeError::Core(CoreError::Common(
+                            CoreCommonError::CostLimitExceeded,
+                        )) =>
+            {
+                Err(Box::new(WasmEdgeError::FuelExhausted(spent)))
             }
-        };
-        executor.call_func(&mut func, args)
+            Err(e) => Err(e),
+        }
     }
 
     /// Runs an exported wasm function in a (named or active) [module instance](crate::Instance) with a timeout setting
     ///
+    /// The call blocks the current thread until the function returns or the timeout expires, and it does not require the `async` feature.
+    ///
     /// # Arguments
     ///
     /// * `mod_name` - The exported name of the module instance, which holds the target function. If `None`, then the active module is used.
@@ -174,8 +507,7 @@ impl<'inst, T: ?Sized + SyncInst> Vm<'inst, T> {
     ///
     /// # Error
     ///
-    /// If fail to run the wasm function, then an error is returned.
-    #[cfg(all(target_os = "linux", not(target_env = "musl")))]
+    /// If the timeout expires before the function returns, then [WasmEdgeError::ExecuteTimeout] is returned. If fail to run the wasm function, then an error is returned.
     pub fn run_func_with_timeout(
         &mut self,
         mod_name: Option<&str>,
@@ -183,33 +515,399 @@ impl<'inst, T: ?Sized + SyncInst> Vm<'inst, T> {
         args: impl IntoIterator<Item = WasmValue>,
         timeout: std::time::Duration,
     ) -> WasmEdgeResult<Vec<WasmValue>> {
-        let (mut func, executor) = match mod_name {
-            Some(mod_name) => {
+        self.call_func_in(
+            FuncOwner::Module(mod_name),
+            func_name.as_ref(),
+            |executor, func| executor.call_func_with_timeout(func, args, timeout),
+        )
+    }
+
+    /// Returns the [CancellationToken](crate::CancellationToken) of this vm, which cancels the function call running by [run_func_with_cancellation](Vm::run_func_with_cancellation) from another thread, for example, when the deadline of a request expires.
+    ///
+    /// A cancellation requested while no call is running cancels the next call. Once a call is cancelled, the vm replaces the token with a new one, so the token should be fetched again for the later calls.
+    pub fn cancellation_token(&self) -> crate::CancellationToken {
+        self.cancellation_token.clone()
+    }
+
+    /// Runs an exported wasm function in a (named or active) [module instance](crate::Instance), which can be cancelled from another thread by the [token](Vm::cancellation_token) of this vm.
+    ///
+    /// # Arguments
+    ///
+    /// * `mod_name` - The exported name of the module instance, which holds the target function. If `None`, then the active module is used.
+    ///
+    /// * `func_name` - The exported name of the target wasm function.
+    ///
+    /// * `args` - The arguments to be passed to the target wasm function.
+    ///
+    /// # Error
+    ///
+    /// If the token is cancelled before the function returns, then [WasmEdgeError::Cancelled] is returned. If fail to run the wasm function, then an error is returned.
+    pub fn run_func_with_cancellation(
+        &mut self,
+        mod_name: Option<&str>,
+        func_name: impl AsRef<str>,
+        args: impl IntoIterator<Item = WasmValue>,
+    ) -> WasmEdgeResult<Vec<WasmValue>> {
+        let token = self.cancellation_token.clone();
+        let result = self.call_func_in(
+            FuncOwner::Module(mod_name),
+            func_name.as_ref(),
+            |executor, func| executor.call_func_with_cancellation(func, args, &token),
+        );
+        if token.is_cancelled() {
+            self.cancellation_token = crate::CancellationToken::new();
+        }
+
+        result
+    }
+
+    /// Calls the function stored at a specific index of an exported table, which is the same as a `call_indirect` from the host side.
+    ///
+    /// # Arguments
+    ///
+    /// * `mod_name` - The exported name of the wasm module instance, which holds the target table. If `None`, then the active module is used.
+    ///
+    /// * `table_name` - The exported name of the target table.
+    ///
+    /// * `idx` - The index of the target function in the table.
+    ///
+    /// * `args` - The arguments to be passed to the target function.
+    ///
+    /// # Error
+    ///
+    /// If `idx` is not less than the size of the table, then [TableError::OutOfBounds](crate::error::TableError) is returned. If the element at `idx` is uninitialized, or fail to find the table or run the function, then an error is returned.
+    pub fn call_table_element(
+        &mut self,
+        mod_name: Option<&str>,
+        table_name: impl AsRef<str>,
+        idx: u32,
+        args: impl IntoIterator<Item = WasmValue>,
+    ) -> WasmEdgeResult<Vec<WasmValue>> {
+        let (inst, executor) = match mod_name {
+            Some(mod_name) => self
+                .store
+                .get_named_wasm_and_executor(mod_name)
+                .map(|(inst, executor)| (&*inst, executor))
+                .ok_or(Box::new(WasmEdgeError::Vm(VmError::NotFoundModule(
+                    mod_name.into(),
+                ))))?,
+            None => {
+                let active_inst = self
+                    .anonymous_instances
+                    .values()
+                    .next_back()
+                    .ok_or(Box::new(WasmEdgeError::Vm(VmError::NotFoundActiveModule)))?;
+                (active_inst, self.store.executor())
+            }
+        };
+
+        let table = inst.get_table(table_name)?;
+        let size = table.capacity();
+        if idx as usize >= size {
+            return Err(Box::new(WasmEdgeError::Table(TableError::OutOfBounds {
+                index: idx,
+                size: size as u32,
+            })));
+        }
+
+        let elem = table.get_data(idx)?;
+        if elem.ty() != ValType::FuncRef {
+            return Err(Box::new(WasmEdgeError::Core(CoreError::Execution(
+                CoreExecutionError::IndirectCallTypeMismatch,
+            ))));
+        }
+        let mut func_ref =
+            elem.func_ref()
+                .ok_or(Box::new(WasmEdgeError::Core(CoreError::Execution(
+                    CoreExecutionError::UninitializedElement,
+                ))))?;
+        executor.call_func_ref(&mut func_ref, args)
+    }
+
+    /// An alias of [run_func](Vm::run_func) with `None` as the module name, which runs the function in the active module instance.
+    pub fn run_func_in_latest_anonymous(
+        &mut self,
+        func_name: impl AsRef<str>,
+        args: impl IntoIterator<Item = WasmValue>,
+    ) -> WasmEdgeResult<Vec<WasmValue>> {
+        self.run_func(None, func_name, args)
+    }
+
+    /// Runs an exported wasm function in an anonymous [module instance](crate::Instance) specified by its id, which is the registration order of the anonymous module instance starting from `0`. The ids of the anonymous module instances dropped by the [limit](Vm::set_max_anonymous_instances) are not found any more.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The id of the target anonymous module instance.
+    ///
+    /// * `func_name` - The exported name of the target wasm function.
+    ///
+    /// * `args` - The arguments to be passed to the target wasm function.
+    ///
+    /// # Error
+    ///
+    /// If fail to find the anonymous module instance or run the wasm function, then an error is returned.
+    pub fn run_func_in_module_by_id(
+        &mut self,
+        id: u64,
+        func_name: impl AsRef<str>,
+        args: impl IntoIterator<Item = WasmValue>,
+    ) -> WasmEdgeResult<Vec<WasmValue>> {
+        self.call_func_in(
+            FuncOwner::Anonymous(id),
+            func_name.as_ref(),
+            |executor, func| executor.call_func(func, args),
+        )
+    }
+
+    /// Instantiates the given [wasm module](crate::Module) and runs an exported wasm function in it, which is a one-shot load-and-run. The module instance is dropped after the function returns, so neither this vm nor its [store](crate::Store) keeps the registration, and the active module is not changed.
+    ///
+    /// # Arguments
+    ///
+    /// * `module` - The module to be instantiated.
+    ///
+    /// * `func_name` - The exported name of the target wasm function.
+    ///
+    /// * `args` - The arguments to be passed to the target wasm function.
+    ///
+    /// # Error
+    ///
+    /// If fail to instantiate the module or run the wasm function, then an error is returned.
+    pub fn run_func_in_module(
+        &mut self,
+        module: Module,
+        func_name: impl AsRef<str>,
+        args: impl IntoIterator<Item = WasmValue>,
+    ) -> WasmEdgeResult<Vec<WasmValue>> {
+        let mut inst = self.store.register_active_module(&module)?;
+        let mut func = inst.get_func_mut(func_name.as_ref())?;
+        self.store.executor().call_func(&mut func, args)
+    }
+
+    /// Returns a [typed handle](TypedFunc) to an exported wasm function in a (named or active) [module instance](crate::Instance), whose arguments and returns are a value or a tuple of values of Rust types. The function type is checked once here, so the calls via the handle need not build and check the [WasmValue] lists by hand.
+    ///
+    /// # Arguments
+    ///
+    /// * `mod_name` - The exported name of the module instance, which holds the target function. If `None`, then the active module is used.
+    ///
+    /// * `func_name` - The exported name of the target wasm function.
+    ///
+    /// # Error
+    ///
+    /// * If the type of the target function does not match `Params` and `Results`, then [CoreExecutionError::FuncSigMismatch](crate::error::CoreExecutionError) is returned.
+    ///
+    /// * If fail to find the target function, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::collections::HashMap;
+    /// use wasmedge_sdk::{vm::SyncInst, wat2wasm, Module, Store, Vm};
+    ///
+    /// let mut vm =
+    ///     Vm::new(Store::new(None, HashMap::<String, &mut dyn SyncInst>::new()).unwrap());
+    /// let wasm_bytes = wat2wasm(
+    ///     br#"(module
+    ///       (func (export "add") (param i32 i32) (result i32)
+    ///         (i32.add (local.get 0) (local.get 1)))
+    ///     )"#,
+    /// )
+    /// .unwrap();
+    /// vm.register_module(None, Module::from_bytes(None, wasm_bytes).unwrap())
+    ///     .unwrap();
+    ///
+    /// let mut add = vm.get_typed_func::<(i32, i32), i32>(None, "add").unwrap();
+    /// assert_eq!(add.call((1, 2)).unwrap(), 3);
+    /// ```
+    pub fn get_typed_func<Params, Results>(
+        &mut self,
+        mod_name: Option<&str>,
+        func_name: impl AsRef<str>,
+    ) -> WasmEdgeResult<TypedFunc<'_, 'inst, T, Params, Results>>
+    where
+        Params: WasmValTypeList + WasmValList,
+        Results: WasmValTypeList + FromWasmValList,
+    {
+        let func_name = func_name.as_ref();
+        let ty = self.func_type(mod_name, func_name)?;
+        if ty.args() != Params::wasm_types() || ty.returns() != Results::wasm_types() {
+            return Err(Box::new(WasmEdgeError::Core(CoreError::Execution(
+                CoreExecutionError::FuncSigMismatch,
+            ))));
+        }
+
+        Ok(TypedFunc {
+            vm: self,
+            mod_name: mod_name.map(ToString::to_string),
+            func_name: func_name.to_string(),
+            _marker: std::marker::PhantomData,
+        })
+    }
+
+    // Finds the target function in the module instance specified by `owner`, and runs it by `call` with the executor of the store. The call is recorded into the profiling data if the `profiling` feature is enabled.
+    fn call_func_in<R>(
+        &mut self,
+        owner: FuncOwner<'_>,
+        func_name: &str,
+        call: impl FnOnce(&mut sys::Executor, &mut sys::Function) -> WasmEdgeResult<R>,
+    ) -> WasmEdgeResult<R> {
+        let (mut func, executor) = match owner {
+            FuncOwner::Module(Some(mod_name)) => {
                 if let Some((inst, executor)) = self.store.get_instance_and_executor(mod_name) {
-                    (inst.get_func_mut(func_name.as_ref())?, executor)
+                    (inst.get_func_mut(func_name)?, executor)
                 } else if let Some((wasm_mod, executor)) =
                     self.store.get_named_wasm_and_executor(mod_name)
                 {
-                    (wasm_mod.get_func_mut(func_name.as_ref())?, executor)
+                    (wasm_mod.get_func_mut(func_name)?, executor)
                 } else {
                     return Err(Box::new(WasmEdgeError::Vm(VmError::NotFoundModule(
                         mod_name.into(),
                     ))));
                 }
             }
-            None => {
+            FuncOwner::Module(None) => {
                 let active_inst = self
-                    .active_instance
-                    .as_mut()
+                    .anonymous_instances
+                    .values_mut()
+                    .next_back()
                     .ok_or(Box::new(WasmEdgeError::Vm(VmError::NotFoundActiveModule)))?;
+                (active_inst.get_func_mut(func_name)?, self.store.executor())
+            }
+            FuncOwner::Anonymous(id) => {
+                let inst =
+                    self.anonymous_instances
+                        .get_mut(&id)
+                        .ok_or(Box::new(WasmEdgeError::Vm(VmError::NotFoundModule(
+                            format!("anonymous module #{id}"),
+                        ))))?;
+                (inst.get_func_mut(func_name)?, self.store.executor())
+            }
+        };
+        #[cfg(feature = "profiling")]
+        let start = std::time::Instant::now();
 
-                (
-                    active_inst.get_func_mut(func_name.as_ref())?,
-                    self.store.executor(),
-                )
+        let result = call(executor, &mut func);
+
+        #[cfg(feature = "profiling")]
+        self.profiling.record(owner, func_name, start.elapsed());
+
+        result
+    }
+
+    fn func_type(&self, mod_name: Option<&str>, func_name: &str) -> WasmEdgeResult<FuncType> {
+        let func = match mod_name {
+            Some(mod_name) => match self.store.instances.get(mod_name) {
+                Some(inst) => inst.get_func(func_name)?,
+                None => self
+                    .store
+                    .wasm_instance_map
+                    .get(mod_name)
+                    .ok_or_else(|| {
+                        Box::new(WasmEdgeError::Vm(VmError::NotFoundModule(mod_name.into())))
+                    })?
+                    .get_func(func_name)?,
+            },
+            None => self
+                .anonymous_instances
+                .values()
+                .next_back()
+                .ok_or(Box::new(WasmEdgeError::Vm(VmError::NotFoundActiveModule)))?
+                .get_func(func_name)?,
+        };
+        func.ty().ok_or_else(|| {
+            Box::new(WasmEdgeError::Vm(VmError::NotFoundFuncType(
+                func_name.into(),
+            )))
+        })
+    }
+
+    /// Returns the profiling data of the wasm functions run by [run_func](Vm::run_func) and its variants on this vm, or `None` if no function has been run yet.
+    #[cfg(feature = "profiling")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "profiling")))]
+    pub fn profiling_data(&self) -> Option<ProfilingData> {
+        match self.profiling.function_call_counts.is_empty() {
+            true => None,
+            false => Some(self.profiling.clone()),
+        }
+    }
+
+    /// Loads the given WebAssembly binary and runs an exported wasm function in it, which is a one-shot load-and-run like [run_func_in_module](Vm::run_func_in_module), and returns the results together with the [metrics](crate::vm::ExecutionMetrics) of the call.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The global configuration used to load and validate the binary.
+    ///
+    /// * `bytes` - The in-memory bytes of the WebAssembly binary.
+    ///
+    /// * `func_name` - The exported name of the target wasm function.
+    ///
+    /// * `args` - The arguments to be passed to the target wasm function.
+    ///
+    /// # Error
+    ///
+    /// If fail to load, validate or instantiate the module, or run the wasm function, then an error is returned.
+    pub fn measure(
+        &mut self,
+        config: Option<&crate::config::Config>,
+        bytes: impl AsRef<[u8]>,
+        func_name: impl AsRef<str>,
+        args: impl IntoIterator<Item = WasmValue>,
+    ) -> WasmEdgeResult<(Vec<WasmValue>, ExecutionMetrics)> {
+        let start = std::time::Instant::now();
+        let module = Module::from_bytes(config, bytes)?;
+        let parse_ns = start.elapsed().as_nanos() as u64;
+
+        let mut inst = self.store.register_active_module(&module)?;
+        // the pages of the first exported memory
+        let memory_pages = |inst: &Instance| -> WasmEdgeResult<u32> {
+            match inst.mem_names().and_then(|names| names.into_iter().next()) {
+                Some(name) => Ok(inst.get_memory_ref(name)?.size()),
+                None => Ok(0),
             }
         };
-        executor.call_func_with_timeout(&mut func, args, timeout)
+        let memory_pages_before = memory_pages(&inst)?;
+
+        let mut func = inst.get_func_mut(func_name.as_ref())?;
+        let executor = self.store.executor();
+        let start = std::time::Instant::now();
+        let returns = executor.call_func(&mut func, args)?;
+        let exec_ns = start.elapsed().as_nanos() as u64;
+        let instructions_executed = executor.fuel_consumed().unwrap_or_default();
+
+        let metrics = ExecutionMetrics {
+            parse_ns,
+            exec_ns,
+            memory_pages_before,
+            memory_pages_after: memory_pages(&inst)?,
+            instructions_executed,
+        };
+        Ok((returns, metrics))
+    }
+
+    /// Returns the count of the anonymous [module instances](crate::Instance) kept by this vm.
+    pub fn anonymous_instance_count(&self) -> usize {
+        self.anonymous_instances.len()
+    }
+
+    /// Returns the maximum number of the anonymous [module instances](crate::Instance) kept by this vm, which is [DEFAULT_MAX_ANONYMOUS_INSTANCES](Vm::DEFAULT_MAX_ANONYMOUS_INSTANCES) unless it is changed by [set_max_anonymous_instances](Vm::set_max_anonymous_instances).
+    pub fn max_anonymous_instances(&self) -> usize {
+        self.max_anonymous_instances
+    }
+
+    /// Sets the maximum number of the anonymous [module instances](crate::Instance) kept by this vm. If more anonymous instances are registered, then the oldest ones are dropped. The active module instance is always kept, so a maximum of `0` is treated as `1`.
+    ///
+    /// # Argument
+    ///
+    /// * `max` - The maximum number of the anonymous module instances.
+    pub fn set_max_anonymous_instances(&mut self, max: usize) {
+        self.max_anonymous_instances = max.max(1);
+        self.evict_anonymous_instances();
+    }
+
+    // Drops the oldest anonymous module instances beyond the maximum.
+    fn evict_anonymous_instances(&mut self) {
+        while self.anonymous_instances.len() > self.max_anonymous_instances {
+            self.anonymous_instances.pop_first();
+        }
     }
 
     /// Returns a reference to the internal [store](crate::Store) from this vm.
@@ -228,7 +926,7 @@ impl<'inst, T: ?Sized + SyncInst> Vm<'inst, T> {
     ///
     /// If fail to get the reference to the active module instance, then an error is returned.
     pub fn active_module(&self) -> Option<&Instance> {
-        self.active_instance.as_ref()
+        self.anonymous_instances.values().next_back()
     }
 
     /// Returns a mutable reference to the active [module instance](crate::Instance) from this vm.
@@ -237,27 +935,486 @@ impl<'inst, T: ?Sized + SyncInst> Vm<'inst, T> {
     ///
     /// If fail to get the mutable reference to the active module instance, then an error is returned.
     pub fn active_module_mut(&mut self) -> Option<&mut Instance> {
-        self.active_instance.as_mut()
+        self.anonymous_instances.values_mut().next_back()
+    }
+
+    /// Returns a [view](crate::MemoryView) of an exported memory in a (named or active) wasm [module instance](crate::Instance).
+    ///
+    /// # Arguments
+    ///
+    /// * `mod_name` - The exported name of the wasm module instance, which holds the target memory. If `None`, then the active module is used.
+    ///
+    /// * `memory_name` - The exported name of the target memory.
+    ///
+    /// # Error
+    ///
+    /// If fail to find the module instance or the memory, then an error is returned.
+    pub fn memory_view(
+        &self,
+        mod_name: Option<&str>,
+        memory_name: impl AsRef<str>,
+    ) -> WasmEdgeResult<MemoryView<'_>> {
+        let inst = match mod_name {
+            Some(mod_name) => self.store.wasm_instance_map.get(mod_name).ok_or_else(|| {
+                Box::new(WasmEdgeError::Vm(VmError::NotFoundModule(mod_name.into())))
+            })?,
+            None => self
+                .anonymous_instances
+                .values()
+                .next_back()
+                .ok_or(Box::new(WasmEdgeError::Vm(VmError::NotFoundActiveModule)))?,
+        };
+        let memory = inst.get_memory_ref(memory_name.as_ref())?;
+        Ok(MemoryView::new(memory))
     }
 
     /// Checks if the vm contains a named module instance.
     ///
     /// # Argument
     ///
-    /// * `mod_name` - The exported name of the target module instance.
+    /// * `mod_name` - The exported name of the target module instance.
+    ///
+    pub fn contains_module(&self, mod_name: impl AsRef<str>) -> bool {
+        self.store.contains(mod_name)
+    }
+
+    /// Returns the count of the named [module instances](crate::Instance) this vm holds.
+    pub fn named_instance_count(&self) -> usize {
+        self.store.named_instance_count()
+    }
+
+    /// Returns the names of all named [module instances](crate::Instance) this vm holds.
+    pub fn instance_names(&self) -> Vec<String> {
+        self.store.instance_names()
+    }
+
+    /// Returns the names and the [types](crate::FuncType) of the functions exported by a named [module instance](crate::Instance), which can be either an import module or a registered wasm module. The functions are sorted by their names.
+    ///
+    /// # Argument
+    ///
+    /// * `mod_name` - The name of the target module instance.
+    ///
+    /// # Error
+    ///
+    /// * If the named module instance is not found, then [VmError::NotFoundModule](crate::error::VmError) is returned.
+    ///
+    /// * If fail to get the type of any function, then an error is returned.
+    pub fn named_instance_funcs(&self, mod_name: &str) -> WasmEdgeResult<Vec<(String, FuncType)>> {
+        match self.store.instances.get(mod_name) {
+            Some(inst) => instance_funcs(&**inst),
+            None => {
+                let inst = self.store.wasm_instance_map.get(mod_name).ok_or_else(|| {
+                    Box::new(WasmEdgeError::Vm(VmError::NotFoundModule(mod_name.into())))
+                })?;
+                instance_funcs(inst)
+            }
+        }
+    }
+
+    /// Returns an iterator over the names and the [module instances](crate::Instance) of the named wasm modules registered into this vm, in the order they were first registered. The import modules and the anonymous modules are not included.
+    ///
+    /// The module instances are looked up only when the iterator advances.
+    pub fn registered_module_iter(&self) -> impl Iterator<Item = (&str, &Instance)> {
+        self.store.registration_order.iter().filter_map(|name| {
+            self.store
+                .wasm_instance_map
+                .get_key_value(name)
+                .map(|(name, inst)| (name.as_str(), inst))
+        })
+    }
+
+    /// Spawns a blocking task in the current [tokio](https://docs.rs/tokio) runtime, which runs an exported function of a wasm module with the given [shared memory](crate::threads::SharedMemory).
+    ///
+    /// The module is instantiated in a new store before the task is spawned. The new store is created with the config of the store of this vm, in which the [threads](crate::config::CommonConfigOptions::threads) option is turned on. Notice that the threads option must be turned on in the config used to load a module importing a shared memory.
+    ///
+    /// The modules registered into this vm are not visible to the task, since they are not safe to use from two threads at the same time. The only import modules of the new store are the shared memory and the given import objects, which are moved into the task and dropped when it finishes.
+    ///
+    /// # Arguments
+    ///
+    /// * `module` - The validated module to run in the task.
+    ///
+    /// * `func_name` - The exported name of the target wasm function.
+    ///
+    /// * `shared_mem` - The shared memory imported by the module.
+    ///
+    /// * `imports` - The import objects used only by the task.
+    ///
+    /// * `args` - The arguments to be passed to the target wasm function.
+    ///
+    /// # Error
+    ///
+    /// If this method is not called in a tokio runtime, or fail to instantiate the module or find the target function, then an error is returned. If fail to run the wasm function, then the spawned task returns the error.
+    #[cfg(feature = "threads")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "threads")))]
+    pub fn spawn_thread<D: Send + Sync + 'static>(
+        &self,
+        module: &Module,
+        func_name: impl AsRef<str>,
+        shared_mem: std::sync::Arc<crate::threads::SharedMemory>,
+        imports: Vec<ImportObject<D>>,
+        args: Vec<WasmValue>,
+    ) -> WasmEdgeResult<tokio::task::JoinHandle<WasmEdgeResult<Vec<WasmValue>>>> {
+        let runtime = tokio::runtime::Handle::try_current().map_err(|e| {
+            Box::new(WasmEdgeError::Operation(format!(
+                "failed to spawn the thread: {e}"
+            )))
+        })?;
+
+        let config = match &self.store.config {
+            Some(config) => config.copy_with_threads()?,
+            None => {
+                let mut config = sys::Config::create()?;
+                config.threads(true);
+                config
+            }
+        };
+        let mut store = sys::Store::create()?;
+        let mut executor = crate::store::create_executor(Some(&config))?;
+        executor.register_import_module(&mut store, shared_mem.as_import_module())?;
+        for import in imports.iter() {
+            executor.register_import_module(&mut store, import)?;
+        }
+        let mut instance = executor.register_active_module(&mut store, &module.inner)?;
+        let func_name = func_name.as_ref().to_string();
+        instance.get_func(&func_name)?;
+
+        Ok(runtime.spawn_blocking(move || {
+            let result = instance
+                .get_func_mut(&func_name)
+                .and_then(|mut func| executor.call_func(&mut func, args));
+            // the instance is dropped before the store and the import modules it imports
+            drop(instance);
+            drop((store, shared_mem, imports));
+            result
+        }))
+    }
+}
+
+/// Returns the names and the types of the functions exported by a module instance sorted by the names.
+fn instance_funcs<I: AsInstance + ?Sized>(inst: &I) -> WasmEdgeResult<Vec<(String, FuncType)>> {
+    let mut funcs = Vec::new();
+    for name in inst.func_names().unwrap_or_default() {
+        let ty = inst
+            .get_func(&name)?
+            .ty()
+            .ok_or_else(|| Box::new(WasmEdgeError::Vm(VmError::NotFoundFuncType(name.clone()))))?;
+        funcs.push((name, ty));
+    }
+    funcs.sort_by(|a, b| a.0.cmp(&b.0));
+    Ok(funcs)
+}
+
+/// The signature of an export used to check if a module can be hot reloaded.
+#[derive(Debug, PartialEq, Eq)]
+enum ExportSig {
+    Func(FuncType),
+    Table,
+    Memory,
+    Global,
+}
+
+/// Returns the signatures of the exports of a module instance sorted by the export names.
+fn instance_exports(inst: &Instance) -> WasmEdgeResult<Vec<(String, ExportSig)>> {
+    let mut exports = Vec::new();
+    for name in inst.func_names().unwrap_or_default() {
+        let ty = inst
+            .get_func(&name)?
+            .ty()
+            .ok_or_else(|| Box::new(WasmEdgeError::Vm(VmError::NotFoundFuncType(name.clone()))))?;
+        exports.push((name, ExportSig::Func(ty)));
+    }
+    for name in inst.table_names().unwrap_or_default() {
+        exports.push((name, ExportSig::Table));
+    }
+    for name in inst.mem_names().unwrap_or_default() {
+        exports.push((name, ExportSig::Memory));
+    }
+    for name in inst.global_names().unwrap_or_default() {
+        exports.push((name, ExportSig::Global));
+    }
+    exports.sort_by(|a, b| a.0.cmp(&b.0));
+    Ok(exports)
+}
+
+/// Returns the signatures of the exports of a module sorted by the export names.
+fn module_exports(module: &Module) -> WasmEdgeResult<Vec<(String, ExportSig)>> {
+    let mut exports = Vec::new();
+    for export in module.exports() {
+        let sig = match export.ty()? {
+            ExternalInstanceType::Func(ty) => ExportSig::Func(ty),
+            ExternalInstanceType::Table(_) => ExportSig::Table,
+            ExternalInstanceType::Memory(_) => ExportSig::Memory,
+            ExternalInstanceType::Global(_) => ExportSig::Global,
+        };
+        exports.push((export.name().into_owned(), sig));
+    }
+    exports.sort_by(|a, b| a.0.cmp(&b.0));
+    Ok(exports)
+}
+
+// Specifies the module instance which holds the function run by a [Vm].
+#[derive(Debug, Clone, Copy)]
+enum FuncOwner<'a> {
+    // The named module instance, or the active one if `None`.
+    Module(Option<&'a str>),
+    // The anonymous module instance with the given id.
+    Anonymous(u64),
+}
+
+/// Defines the per-function profiling data collected by a [Vm].
+///
+/// The functions in the active module are keyed by their exported names, while the functions in a named module are keyed by `<module name>::<function name>`, and the ones run by [Vm::run_func_in_module_by_id] are keyed by `anonymous#<id>::<function name>`. The execution time is measured on the host side, so it includes the time spent in the host functions called by the wasm function.
+#[cfg(feature = "profiling")]
+#[cfg_attr(docsrs, doc(cfg(feature = "profiling")))]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProfilingData {
+    /// The number of calls to each function.
+    pub function_call_counts: std::collections::HashMap<String, u64>,
+    /// The total execution time of each function in nanoseconds.
+    pub function_total_time_ns: std::collections::HashMap<String, u64>,
+}
+#[cfg(feature = "profiling")]
+impl ProfilingData {
+    fn record(&mut self, owner: FuncOwner<'_>, func_name: &str, elapsed: std::time::Duration) {
+        let key = match owner {
+            FuncOwner::Module(Some(mod_name)) => format!("{mod_name}::{func_name}"),
+            FuncOwner::Module(None) => func_name.to_string(),
+            FuncOwner::Anonymous(id) => format!("anonymous#{id}::{func_name}"),
+        };
+        *self.function_call_counts.entry(key.clone()).or_default() += 1;
+        let elapsed_ns = u64::try_from(elapsed.as_nanos()).unwrap_or(u64::MAX);
+        let total = self.function_total_time_ns.entry(key).or_default();
+        *total = total.saturating_add(elapsed_ns);
+    }
+}
+
+/// Defines the opcode of a wasm instruction in its binary format.
+///
+/// The opcode of a single-byte instruction is the byte itself, for example, `0x6A` for `i32.add`. The opcode of a prefixed instruction puts the prefix byte in the high byte and the sub-opcode in the low byte, for example, `0xFC00` for `i32.trunc_sat_f32_s`, therefore, the instructions with a sub-opcode greater than `0xFF`, such as the relaxed SIMD ones, cannot be specified.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WasmOpcode(pub u16);
+impl WasmOpcode {
+    // The version of WasmEdge, in which the opcode ranges below index the cost table.
+    const WASMEDGE_OPCODE_VERSION: (u32, u32) = (0, 14);
+
+    // The ranges of the binary opcodes known to WasmEdge 0.14, listed in the order of its `OpCode` enumeration, whose values index the cost table. A prefixed opcode is encoded as `prefix << 16 | sub-opcode` here.
+    const WASMEDGE_OPCODE_RANGES: &'static [(u32, u32)] = &[
+        (0x00, 0x15),
+        (0x18, 0x1C),
+        (0x1F, 0x26),
+        (0x28, 0xC4),
+        (0xD0, 0xD6),
+        // GC instructions
+        (0xFB_0000, 0xFB_001E),
+        // saturating truncation, bulk memory and table instructions
+        (0xFC_0000, 0xFC_0011),
+        // SIMD instructions
+        (0xFD_0000, 0xFD_0099),
+        (0xFD_009B, 0xFD_00A1),
+        (0xFD_00A3, 0xFD_00A4),
+        (0xFD_00A7, 0xFD_00AE),
+        (0xFD_00B1, 0xFD_00B1),
+        (0xFD_00B5, 0xFD_00BA),
+        (0xFD_00BC, 0xFD_00C1),
+        (0xFD_00C3, 0xFD_00C4),
+        (0xFD_00C7, 0xFD_00CE),
+        (0xFD_00D1, 0xFD_00D1),
+        (0xFD_00D5, 0xFD_00E1),
+        (0xFD_00E3, 0xFD_00ED),
+        (0xFD_00EF, 0xFD_00FF),
+        // relaxed SIMD instructions
+        (0xFD_0100, 0xFD_0113),
+        // atomic instructions
+        (0xFE_0000, 0xFE_0003),
+        (0xFE_0010, 0xFE_004E),
+    ];
+
+    /// Returns the index of the instruction in the cost table of WasmEdge, or `None` if the opcode is unknown to WasmEdge.
+    fn wasmedge_index(&self) -> Option<u16> {
+        let code = match self.0 {
+            0..=0xFF => self.0 as u32,
+            _ => ((self.0 as u32 >> 8) << 16) | (self.0 as u32 & 0xFF),
+        };
+        let mut index = 0;
+        for &(first, last) in Self::WASMEDGE_OPCODE_RANGES {
+            if (first..=last).contains(&code) {
+                return u16::try_from(index + code - first).ok();
+            }
+            index += last - first + 1;
+        }
+        None
+    }
+}
+
+/// Defines the costs of the wasm instructions and the fuel budget used by [Vm::run_func_in_fuel_mode].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FuelPolicy {
+    /// The costs of the instructions. The instructions not in the map cost as much as in the cost table of the [store](crate::Store), which is `1` by default.
+    pub costs: std::collections::HashMap<WasmOpcode, u64>,
+    /// The fuel available to a call.
+    pub budget: u64,
+}
+impl FuelPolicy {
+    /// Returns the costs indexed by the instructions in the cost table of WasmEdge.
+    ///
+    /// The layout of the cost table follows the `OpCode` enumeration of WasmEdge, which may change between the minor versions, so the costs are only mapped for the version the opcode ranges are taken from.
+    fn wasmedge_costs(&self) -> WasmEdgeResult<Vec<(u16, u64)>> {
+        let (major, minor) = WasmOpcode::WASMEDGE_OPCODE_VERSION;
+        if (
+            crate::utils::CoreVersion::major(),
+            crate::utils::CoreVersion::minor(),
+        ) != (major, minor)
+        {
+            return Err(Box::new(WasmEdgeError::Operation(format!(
+                "the fuel policy requires WasmEdge {major}.{minor}, but WasmEdge {} is linked",
+                crate::utils::CoreVersion::version_string()
+            ))));
+        }
+
+        self.costs
+            .iter()
+            .map(|(opcode, cost)| match opcode.wasmedge_index() {
+                Some(index) => Ok((index, *cost)),
+                None => Err(Box::new(WasmEdgeError::Operation(format!(
+                    "unknown opcode {:#06X} in the fuel policy",
+                    opcode.0
+                )))),
+            })
+            .collect()
+    }
+}
+
+/// Defines the metrics of a call measured by [Vm::measure].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ExecutionMetrics {
+    /// The time spent on loading and validating the binary in nanoseconds.
+    pub parse_ns: u64,
+    /// The time spent on running the function in nanoseconds.
+    pub exec_ns: u64,
+    /// The page count of the first exported memory before the call, or `0` if no memory is exported.
+    pub memory_pages_before: u32,
+    /// The page count of the first exported memory after the call, or `0` if no memory is exported.
+    pub memory_pages_after: u32,
+    /// The number of the executed instructions, which is `0` unless the instruction counting is enabled in the [configuration](crate::config::StatisticsConfigOptions) of the [store](crate::Store).
+    pub instructions_executed: u64,
+}
+
+/// A handle to a named wasm [module instance](crate::Instance) registered into a [Vm], from which the exported instances can be fetched directly.
+#[derive(Debug, Clone, Copy)]
+pub struct NamedInstance<'vm> {
+    name: &'vm str,
+    inner: &'vm Instance,
+}
+impl<'vm> NamedInstance<'vm> {
+    /// Returns the exported name of this module instance.
+    pub fn name(&self) -> &'vm str {
+        self.name
+    }
+
+    /// Returns the exported [function instance](sys::Function) by name.
+    ///
+    /// # Argument
+    ///
+    /// * `name` - The name of the target exported function instance.
+    ///
+    /// # Error
+    ///
+    /// If fail to find the target function instance, then an error is returned.
+    pub fn get_func(&self, name: impl AsRef<str>) -> WasmEdgeResult<sys::FuncRef<&'vm Instance>> {
+        self.inner.get_func(name.as_ref())
+    }
+
+    /// Returns the exported [memory instance](sys::Memory) by name.
+    ///
+    /// # Argument
+    ///
+    /// * `name` - The name of the target exported memory instance.
+    ///
+    /// # Error
+    ///
+    /// If fail to find the target memory instance, then an error is returned.
+    pub fn get_memory(
+        &self,
+        name: impl AsRef<str>,
+    ) -> WasmEdgeResult<sys::instance::InnerRef<sys::Memory, &'vm Instance>> {
+        self.inner.get_memory_ref(name)
+    }
+
+    /// Returns the exported [global instance](sys::Global) by name.
+    ///
+    /// # Argument
+    ///
+    /// * `name` - The name of the target exported global instance.
+    ///
+    /// # Error
+    ///
+    /// If fail to find the target global instance, then an error is returned.
+    pub fn get_global(
+        &self,
+        name: impl AsRef<str>,
+    ) -> WasmEdgeResult<sys::instance::InnerRef<sys::Global, &'vm Instance>> {
+        self.inner.get_global(name)
+    }
+
+    /// Returns the exported [table instance](sys::Table) by name.
+    ///
+    /// # Argument
+    ///
+    /// * `name` - The name of the target exported table instance.
+    ///
+    /// # Error
+    ///
+    /// If fail to find the target table instance, then an error is returned.
+    pub fn get_table(
+        &self,
+        name: impl AsRef<str>,
+    ) -> WasmEdgeResult<sys::instance::InnerRef<sys::Table, &'vm Instance>> {
+        self.inner.get_table(name)
+    }
+}
+
+/// A handle to an exported wasm function with the arguments of type `Params` and the returns of type `Results`, which is created by [Vm::get_typed_func].
+///
+/// The handle borrows the [Vm] mutably, so the vm is available again once the handle is dropped.
+pub struct TypedFunc<'vm, 'inst, T: ?Sized + SyncInst, Params, Results> {
+    vm: &'vm mut Vm<'inst, T>,
+    mod_name: Option<String>,
+    func_name: String,
+    _marker: std::marker::PhantomData<fn(Params) -> Results>,
+}
+impl<T: ?Sized + SyncInst, Params, Results> TypedFunc<'_, '_, T, Params, Results>
+where
+    Params: WasmValList,
+    Results: FromWasmValList,
+{
+    /// Runs the wasm function.
+    ///
+    /// # Argument
+    ///
+    /// * `params` - The arguments to be passed to the wasm function, which are a value or a tuple of values of Rust types.
     ///
-    pub fn contains_module(&self, mod_name: impl AsRef<str>) -> bool {
-        self.store.contains(mod_name)
-    }
-
-    /// Returns the count of the named [module instances](crate::Instance) this vm holds.
-    pub fn named_instance_count(&self) -> usize {
-        self.store.named_instance_count()
+    /// # Error
+    ///
+    /// If fail to run the wasm function, then an error is returned.
+    pub fn call(&mut self, params: Params) -> WasmEdgeResult<Results> {
+        let returns = self.vm.run_func(
+            self.mod_name.as_deref(),
+            &self.func_name,
+            params.to_wasm_values(),
+        )?;
+        Results::from_wasm_values(&returns).ok_or(Box::new(WasmEdgeError::Core(
+            CoreError::Execution(CoreExecutionError::FuncSigMismatch),
+        )))
     }
-
-    /// Returns the names of all named [module instances](crate::Instance) this vm holds.
-    pub fn instance_names(&self) -> Vec<String> {
-        self.store.instance_names()
+}
+impl<T: ?Sized + SyncInst, Params, Results> std::fmt::Debug
+    for TypedFunc<'_, '_, T, Params, Results>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TypedFunc")
+            .field("mod_name", &self.mod_name)
+            .field("func_name", &self.func_name)
+            .finish()
     }
 }
 
@@ -270,6 +1427,25 @@ mod tests {
     use super::*;
     use crate::{params, WasmVal};
 
+    // a module exporting a function adding two `i32` values
+    const ADD_WAT: &[u8] = br#"(module
+      (func (export "add") (param i32 i32) (result i32)
+        (i32.add (local.get 0) (local.get 1)))
+    )"#;
+
+    // the module of `ADD_WAT` with an infinite loop exported as `spin`
+    const ADD_SPIN_WAT: &[u8] = br#"(module
+      (func (export "add") (param i32 i32) (result i32)
+        (i32.add (local.get 0) (local.get 1)))
+      (func (export "spin")
+        (loop $l (br $l)))
+    )"#;
+
+    // creates a vm without import modules
+    fn new_vm() -> Vm<'static, dyn SyncInst> {
+        Vm::new(Store::new(None, HashMap::<String, &mut dyn SyncInst>::new()).unwrap())
+    }
+
     #[test]
     #[cfg(target_os = "linux")]
     // To enable this test function, please install `wasi_crypto` plugin first.
@@ -319,8 +1495,7 @@ mod tests {
     #[test]
     fn test_vm_run_func_from_file() {
         // create a Vm context
-        let mut vm =
-            Vm::new(Store::new(None, HashMap::<String, &mut dyn SyncInst>::new()).unwrap());
+        let mut vm = new_vm();
 
         // register a wasm module from a specified wasm file
         let file = std::env::current_dir()
@@ -340,8 +1515,7 @@ mod tests {
     #[test]
     fn test_vm_run_func_from_bytes() {
         // create a Vm context
-        let mut vm =
-            Vm::new(Store::new(None, HashMap::<String, &mut dyn SyncInst>::new()).unwrap());
+        let mut vm = new_vm();
 
         // register a wasm module from the given in-memory wasm bytes
         // load wasm module
@@ -394,8 +1568,7 @@ mod tests {
     #[test]
     fn test_vm_run_func_in_named_module_instance() {
         // create a Vm context
-        let mut vm =
-            Vm::new(Store::new(None, HashMap::<String, &mut dyn SyncInst>::new()).unwrap());
+        let mut vm = new_vm();
 
         // register a wasm module from the given in-memory wasm bytes
         // load wasm module
@@ -436,6 +1609,8 @@ mod tests {
         let wasm_bytes = result.unwrap();
         let fib_module = Module::from_bytes(None, wasm_bytes).unwrap();
         vm.register_module(Some("extern"), fib_module).unwrap();
+        assert_eq!(vm.store().module_count(), 1);
+        assert_eq!(vm.store().import_module_count(), 0);
         // run `fib` function in the named module instance
         let result = vm.run_func(Some("extern"), "fib", params!(10));
         assert!(result.is_ok());
@@ -443,4 +1618,986 @@ mod tests {
         assert_eq!(returns.len(), 1);
         assert_eq!(returns[0].to_i32(), 89);
     }
+
+    #[test]
+    fn test_vm_memory_view() {
+        // create a Vm context
+        let mut vm = new_vm();
+
+        let wasm_bytes = wat2wasm(
+            br#"(module
+            (memory (export "memory") 1)
+            (data (i32.const 0) "\01\02\03\04\05\06\07\08")
+           )
+        "#,
+        )
+        .unwrap();
+        let module = Module::from_bytes(None, &wasm_bytes).unwrap();
+        vm.register_module(None, module).unwrap();
+        let module = Module::from_bytes(None, &wasm_bytes).unwrap();
+        vm.register_module(Some("extern"), module).unwrap();
+
+        for mod_name in [None, Some("extern")] {
+            let result = vm.memory_view(mod_name, "memory");
+            assert!(result.is_ok());
+            let view = result.unwrap();
+            assert_eq!(view.page_count(), 1);
+            assert_eq!(view.data_size(), 65536);
+            assert_eq!(view.read_u8(0).unwrap(), 0x01);
+            assert_eq!(view.read_u16_le(0).unwrap(), 0x0201);
+            assert_eq!(view.read_u32_le(0).unwrap(), 0x04030201);
+            assert_eq!(view.read_u64_le(0).unwrap(), 0x0807060504030201);
+            assert_eq!(view.read_bytes(6, 4).unwrap(), vec![7, 8, 0, 0]);
+
+            // read out of bounds
+            assert!(view.read_u32_le(65534).is_err());
+        }
+
+        // try to get a non-existent memory or module
+        assert!(vm.memory_view(None, "not-exist").is_err());
+        assert!(vm.memory_view(Some("not-exist"), "memory").is_err());
+    }
+
+    #[test]
+    fn test_vm_run_func_in_anonymous_modules() {
+        // create a Vm context
+        let mut vm = new_vm();
+        // only the active module instance is kept by default
+        assert_eq!(vm.max_anonymous_instances(), 1);
+        vm.set_max_anonymous_instances(2);
+
+        for value in [1, 2] {
+            let wat = format!(
+                r#"(module
+                (func (export "get") (result i32)
+                  (i32.const {value}))
+               )
+            "#
+            );
+            let wasm_bytes = wat2wasm(wat.as_bytes()).unwrap();
+            let module = Module::from_bytes(None, wasm_bytes).unwrap();
+            vm.register_module(None, module).unwrap();
+        }
+        assert_eq!(vm.anonymous_instance_count(), 2);
+
+        // run `get` function in the latest anonymous module instance
+        let returns = vm.run_func_in_latest_anonymous("get", params!()).unwrap();
+        assert_eq!(returns[0].to_i32(), 2);
+        let returns = vm.run_func(None, "get", params!()).unwrap();
+        assert_eq!(returns[0].to_i32(), 2);
+
+        // run `get` function in the anonymous module instances by id
+        let returns = vm.run_func_in_module_by_id(0, "get", params!()).unwrap();
+        assert_eq!(returns[0].to_i32(), 1);
+        let returns = vm.run_func_in_module_by_id(1, "get", params!()).unwrap();
+        assert_eq!(returns[0].to_i32(), 2);
+
+        // try to run a function in a non-existent anonymous module instance
+        let result = vm.run_func_in_module_by_id(2, "get", params!());
+        assert_eq!(
+            result.unwrap_err(),
+            Box::new(WasmEdgeError::Vm(VmError::NotFoundModule(
+                "anonymous module #2".into()
+            )))
+        );
+
+        // the oldest anonymous module instance is dropped beyond the limit
+        vm.set_max_anonymous_instances(1);
+        assert_eq!(vm.anonymous_instance_count(), 1);
+        assert!(vm.run_func_in_module_by_id(0, "get", params!()).is_err());
+        let returns = vm.run_func_in_module_by_id(1, "get", params!()).unwrap();
+        assert_eq!(returns[0].to_i32(), 2);
+    }
+
+    #[test]
+    fn test_vm_func_type() {
+        use crate::{AsFunc, AsInstance, ValType};
+
+        // create a Vm context
+        let mut vm = new_vm();
+
+        let wasm_bytes = wat2wasm(
+            br#"(module
+              (func (export "add") (param i32 i64) (result i64)
+                (i64.add (i64.extend_i32_s (local.get 0)) (local.get 1)))
+            )"#,
+        )
+        .unwrap();
+        let module = Module::from_bytes(None, wasm_bytes).unwrap();
+        vm.register_module(None, module).unwrap();
+
+        // inspect the type of the exported function
+        let instance = vm.active_module().unwrap();
+        let func = instance.get_func("add").unwrap();
+        let ty = func.ty().unwrap();
+        assert_eq!(ty.args(), &[ValType::I32, ValType::I64]);
+        assert_eq!(ty.returns(), &[ValType::I64]);
+    }
+
+    #[test]
+    fn test_vm_run_func_in_module() {
+        // create a Vm context
+        let mut vm = new_vm();
+
+        let wasm_bytes = wat2wasm(ADD_WAT).unwrap();
+        let module = Module::from_bytes(None, wasm_bytes).unwrap();
+
+        let returns = vm.run_func_in_module(module, "add", params!(2, 3)).unwrap();
+        assert_eq!(returns[0].to_i32(), 5);
+
+        // the module instance is not kept in the vm
+        assert_eq!(vm.anonymous_instance_count(), 0);
+        assert!(vm.active_module().is_none());
+        assert_eq!(vm.named_instance_count(), 0);
+        assert_eq!(vm.store().module_count(), 0);
+        assert_eq!(vm.store().import_module_count(), 0);
+    }
+
+    #[test]
+    fn test_vm_with_initial_modules() {
+        let lib = wat2wasm(ADD_WAT).unwrap();
+        let app = wat2wasm(
+            br#"(module
+              (import "lib" "add" (func $add (param i32 i32) (result i32)))
+              (func (export "inc") (param i32) (result i32)
+                (call $add (local.get 0) (i32.const 1)))
+            )"#,
+        )
+        .unwrap();
+
+        let mut vm = Vm::with_initial_modules(
+            Store::new(None, HashMap::<String, &mut dyn SyncInst>::new()).unwrap(),
+            vec![
+                (
+                    Some("lib".to_string()),
+                    Module::from_bytes(None, &lib).unwrap(),
+                ),
+                (None, Module::from_bytes(None, &app).unwrap()),
+            ],
+        )
+        .unwrap();
+        assert!(vm.contains_module("lib"));
+        let returns = vm.run_func(None, "inc", params!(41)).unwrap();
+        assert_eq!(returns[0].to_i32(), 42);
+
+        // the imports of `app` cannot be resolved if `lib` is registered later
+        let result = Vm::with_initial_modules(
+            Store::new(None, HashMap::<String, &mut dyn SyncInst>::new()).unwrap(),
+            vec![
+                (None, Module::from_bytes(None, &app).unwrap()),
+                (
+                    Some("lib".to_string()),
+                    Module::from_bytes(None, &lib).unwrap(),
+                ),
+            ],
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_vm_registered_module_iter() {
+        let mut vm = new_vm();
+        assert_eq!(vm.registered_module_iter().count(), 0);
+
+        let wasm_bytes = wat2wasm(br#"(module (func (export "f")))"#).unwrap();
+        for name in ["b", "a"] {
+            vm.register_module(Some(name), Module::from_bytes(None, &wasm_bytes).unwrap())
+                .unwrap();
+        }
+        vm.register_module(None, Module::from_bytes(None, &wasm_bytes).unwrap())
+            .unwrap();
+
+        let modules: Vec<_> = vm
+            .registered_module_iter()
+            .map(|(name, inst)| (name, inst.func_names().unwrap()))
+            .collect();
+        assert_eq!(
+            modules,
+            vec![("b", vec!["f".to_string()]), ("a", vec!["f".to_string()])]
+        );
+    }
+
+    #[cfg(feature = "threads")]
+    #[tokio::test]
+    async fn test_vm_spawn_thread() {
+        use crate::{
+            config::{CommonConfigOptions, ConfigBuilder},
+            threads::SharedMemory,
+        };
+        use std::sync::{
+            atomic::{AtomicI32, Ordering},
+            Arc,
+        };
+
+        let config = ConfigBuilder::new(CommonConfigOptions::new().threads(true))
+            .build()
+            .unwrap();
+        let wasm_bytes = wat2wasm(
+            br#"(module
+              (import "env" "memory" (memory 1 1 shared))
+              (func (export "store") (param i32 i32)
+                (i32.atomic.store (local.get 0) (local.get 1)))
+            )"#,
+        )
+        .unwrap();
+        let module = Module::from_bytes(Some(&config), wasm_bytes).unwrap();
+
+        let vm = new_vm();
+        let shared_mem = Arc::new(SharedMemory::new("env", "memory", 1, 1).unwrap());
+        let handles: Vec<_> = (0..4)
+            .map(|i| {
+                vm.spawn_thread(
+                    &module,
+                    "store",
+                    shared_mem.clone(),
+                    Vec::<ImportObject<()>>::new(),
+                    params!(i * 4, i + 1),
+                )
+                .unwrap()
+            })
+            .collect();
+        for handle in handles {
+            handle.await.unwrap().unwrap();
+        }
+
+        let data = shared_mem.get_data(0, 16).unwrap();
+        let values: Vec<_> = data
+            .chunks(4)
+            .map(|b| i32::from_le_bytes(b.try_into().unwrap()))
+            .collect();
+        assert_eq!(values, vec![1, 2, 3, 4]);
+
+        // the function is not exported
+        assert!(vm
+            .spawn_thread(
+                &module,
+                "missing",
+                shared_mem.clone(),
+                Vec::<ImportObject<()>>::new(),
+                params!()
+            )
+            .is_err());
+
+        // the host function of an import object passed to the task keeps its state in the host data
+        fn next(
+            counter: &mut Arc<AtomicI32>,
+            _inst: &mut Instance,
+            _frame: &mut crate::CallingFrame,
+            _args: (),
+        ) -> Result<i32, crate::error::CoreError> {
+            Ok(counter.fetch_add(1, Ordering::SeqCst) + 1)
+        }
+        let wasm_bytes = wat2wasm(
+            br#"(module
+              (import "env" "memory" (memory 1 1 shared))
+              (import "counter" "next" (func $next (result i32)))
+              (func (export "store") (param i32)
+                (i32.atomic.store (local.get 0) (call $next))
+                (i32.atomic.store (local.get 0) (call $next)))
+            )"#,
+        )
+        .unwrap();
+        let module = Module::from_bytes(Some(&config), wasm_bytes).unwrap();
+        let vm = Vm::new(
+            Store::new(Some(&config), HashMap::<String, &mut dyn SyncInst>::new()).unwrap(),
+        );
+        let counter = Arc::new(AtomicI32::new(0));
+        let mut builder = crate::ImportObjectBuilder::new("counter", counter.clone()).unwrap();
+        builder.with_typed_func("next", next).unwrap();
+        vm.spawn_thread(
+            &module,
+            "store",
+            shared_mem.clone(),
+            vec![builder.build()],
+            params!(16),
+        )
+        .unwrap()
+        .await
+        .unwrap()
+        .unwrap();
+        assert_eq!(shared_mem.get_data(16, 4).unwrap(), 2i32.to_le_bytes());
+        assert_eq!(counter.load(Ordering::SeqCst), 2);
+    }
+
+    #[cfg(feature = "component-model")]
+    #[test]
+    fn test_vm_register_module_from_component() {
+        use crate::component::ComponentComposer;
+        use wit_component::{embed_component_metadata, StringEncoding};
+        use wit_parser::Resolve;
+
+        let mut resolve = Resolve::new();
+        let pkg = resolve
+            .push_str(
+                "<inline>.wit",
+                r#"
+                package example:math;
+
+                world math {
+                    export add: func(a: s32, b: s32) -> s32;
+                }
+                "#,
+            )
+            .unwrap();
+        let world = resolve.select_world(&[pkg], Some("math")).unwrap();
+        let mut module = wat2wasm(ADD_WAT).unwrap().into_owned();
+        embed_component_metadata(&mut module, &resolve, world, StringEncoding::UTF8, false)
+            .unwrap();
+        let component = ComponentComposer::new()
+            .add_module("math", &module)
+            .compose()
+            .unwrap();
+
+        let mut vm = new_vm();
+        vm.register_module_from_component(Some("math"), &component)
+            .unwrap();
+        let returns = vm.run_func(Some("math"), "add", params!(1, 2)).unwrap();
+        assert_eq!(returns[0].to_i32(), 3);
+
+        // not a wasm binary
+        assert!(vm
+            .register_module_from_component(None, b"not a component")
+            .is_err());
+    }
+
+    #[test]
+    fn test_vm_debug() {
+        let mut vm = new_vm();
+        let wasm_bytes = wat2wasm(ADD_WAT).unwrap();
+        vm.register_module(Some("math"), Module::from_bytes(None, wasm_bytes).unwrap())
+            .unwrap();
+
+        let debug = format!("{vm:?}");
+        assert!(debug.starts_with("Vm { modules: [\"math\"]"));
+        assert!(debug.contains("store: Store {"));
+    }
+
+    #[test]
+    fn test_vm_run_start_all() {
+        use crate::error::{CoreError, CoreExecutionError};
+
+        let mut vm = new_vm();
+
+        let lib = wat2wasm(
+            br#"(module
+              (global $counter (export "counter") (mut i32) (i32.const 0))
+              (func (export "_start") (global.set $counter (i32.const 1)))
+            )"#,
+        )
+        .unwrap();
+        let app = wat2wasm(
+            br#"(module
+              (import "lib" "counter" (global $counter (mut i32)))
+              (func (export "_start")
+                (if (i32.ne (global.get $counter) (i32.const 1)) (then unreachable))
+                (global.set $counter (i32.const 2)))
+            )"#,
+        )
+        .unwrap();
+        let failing = wat2wasm(br#"(module (func (export "_start") unreachable))"#).unwrap();
+        let no_start = wat2wasm(br#"(module (func (export "main")))"#).unwrap();
+
+        vm.register_module(Some("lib"), Module::from_bytes(None, lib).unwrap())
+            .unwrap();
+        vm.register_module(Some("app"), Module::from_bytes(None, app).unwrap())
+            .unwrap();
+        vm.register_module(
+            Some("no_start"),
+            Module::from_bytes(None, no_start).unwrap(),
+        )
+        .unwrap();
+        vm.register_module(None, Module::from_bytes(None, failing).unwrap())
+            .unwrap();
+
+        // `lib` runs before `app`, and the failure of the anonymous module is collected
+        let result = vm.run_start_all();
+        assert_eq!(
+            result.unwrap_err(),
+            Box::new(WasmEdgeError::StartFailed(vec![(
+                "anonymous#0".to_string(),
+                WasmEdgeError::Core(CoreError::Execution(CoreExecutionError::Unreachable))
+            )]))
+        );
+        let inst = vm.named_module("lib").unwrap();
+        assert_eq!(inst.get_global("counter").unwrap().get_value().to_i32(), 2);
+    }
+
+    #[test]
+    fn test_vm_instantiate_named() {
+        use crate::{AsFunc, ValType};
+
+        // create a Vm context
+        let mut vm = new_vm();
+
+        let wasm_bytes = wat2wasm(
+            br#"(module
+              (func (export "get") (result i32) (i32.const 1))
+              (memory (export "memory") 1)
+              (global (export "global") i32 (i32.const 42))
+              (table (export "table") 2 funcref)
+            )"#,
+        )
+        .unwrap();
+        let module = Module::from_bytes(None, wasm_bytes).unwrap();
+
+        let inst = vm.instantiate_named("extern", module).unwrap();
+        assert_eq!(inst.name(), "extern");
+        let func = inst.get_func("get").unwrap();
+        assert_eq!(func.ty().unwrap().returns(), &[ValType::I32]);
+        let memory = inst.get_memory("memory").unwrap();
+        assert_eq!(memory.size(), 1);
+        let global = inst.get_global("global").unwrap();
+        assert_eq!(global.get_value().to_i32(), 42);
+        assert_eq!(global.get_value_as::<i32>().unwrap(), 42);
+        assert!(global.get_value_as::<f64>().is_err());
+        let table = inst.get_table("table").unwrap();
+        assert_eq!(table.capacity(), 2);
+        assert!(inst.get_func("not-exist").is_err());
+
+        // introspect the exported functions
+        let funcs = vm.named_instance_funcs("extern").unwrap();
+        assert_eq!(funcs.len(), 1);
+        assert_eq!(funcs[0].0, "get");
+        assert_eq!(funcs[0].1.returns(), &[ValType::I32]);
+        assert!(vm.named_instance_funcs("not-exist").is_err());
+
+        // get the handle again
+        let inst = vm.named_module("extern").unwrap();
+        assert_eq!(inst.name(), "extern");
+        assert!(vm.named_module("not-exist").is_err());
+    }
+
+    #[test]
+    fn test_vm_call_table_element() {
+        let mut vm = new_vm();
+
+        let wasm_bytes = wat2wasm(
+            br#"(module
+              (table (export "dispatch") 3 funcref)
+              (elem (i32.const 0) $add $sub)
+              (func $add (param i32 i32) (result i32)
+                (i32.add (local.get 0) (local.get 1)))
+              (func $sub (param i32 i32) (result i32)
+                (i32.sub (local.get 0) (local.get 1)))
+            )"#,
+        )
+        .unwrap();
+        let module = Module::from_bytes(None, wasm_bytes).unwrap();
+        vm.register_module(None, module).unwrap();
+
+        let returns = vm
+            .call_table_element(None, "dispatch", 0, params!(5, 3))
+            .unwrap();
+        assert_eq!(returns[0].to_i32(), 8);
+        let returns = vm
+            .call_table_element(None, "dispatch", 1, params!(5, 3))
+            .unwrap();
+        assert_eq!(returns[0].to_i32(), 2);
+
+        // the element at index 2 is uninitialized
+        let result = vm.call_table_element(None, "dispatch", 2, params!(5, 3));
+        assert_eq!(
+            result.unwrap_err(),
+            Box::new(WasmEdgeError::Core(CoreError::Execution(
+                CoreExecutionError::UninitializedElement
+            )))
+        );
+
+        let result = vm.call_table_element(None, "dispatch", 3, params!(5, 3));
+        assert_eq!(
+            result.unwrap_err(),
+            Box::new(WasmEdgeError::Table(TableError::OutOfBounds {
+                index: 3,
+                size: 3
+            }))
+        );
+    }
+
+    #[test]
+    fn test_vm_check_import_saturation() {
+        // create a Vm context
+        let mut vm = new_vm();
+
+        let wasm_bytes = wat2wasm(
+            br#"(module
+              (import "extern" "get" (func (result i32)))
+              (import "extern" "memory" (memory 1))
+            )"#,
+        )
+        .unwrap();
+        let module = Module::from_bytes(None, wasm_bytes).unwrap();
+
+        // no module named `extern` is registered
+        let result = vm.check_import_saturation(&module);
+        assert!(matches!(
+            *result.unwrap_err(),
+            WasmEdgeError::ImportNotSatisfied { ref module, ref name, .. } if module == "extern" && name == "get"
+        ));
+
+        // register a module exporting only the function
+        let extern_bytes = wat2wasm(
+            br#"(module
+              (func (export "get") (result i32) (i32.const 1))
+            )"#,
+        )
+        .unwrap();
+        let extern_module = Module::from_bytes(None, extern_bytes).unwrap();
+        vm.register_module(Some("extern"), extern_module).unwrap();
+        let result = vm.check_import_saturation(&module);
+        assert!(matches!(
+            *result.unwrap_err(),
+            WasmEdgeError::ImportNotSatisfied { ref name, .. } if name == "memory"
+        ));
+
+        // register a module exporting both the function and the memory
+        let mut vm = new_vm();
+        let extern_bytes = wat2wasm(
+            br#"(module
+              (func (export "get") (result i32) (i32.const 1))
+              (memory (export "memory") 1)
+            )"#,
+        )
+        .unwrap();
+        let extern_module = Module::from_bytes(None, extern_bytes).unwrap();
+        vm.register_module(Some("extern"), extern_module).unwrap();
+        assert!(vm.check_import_saturation(&module).is_ok());
+    }
+
+    #[test]
+    fn test_vm_run_func_in_fuel_mode() {
+        use crate::config::{CommonConfigOptions, ConfigBuilder, StatisticsConfigOptions};
+
+        let wasm_bytes = wat2wasm(ADD_SPIN_WAT).unwrap();
+
+        // the cost measuring is not enabled
+        let mut vm = new_vm();
+        vm.register_module(None, Module::from_bytes(None, &wasm_bytes).unwrap())
+            .unwrap();
+        let policy = FuelPolicy {
+            costs: HashMap::from([(WasmOpcode(0x6A), 10)]),
+            budget: 100,
+        };
+        assert!(vm
+            .run_func_in_fuel_mode(None, "add", params!(1, 2), &policy)
+            .is_err());
+
+        let config = ConfigBuilder::new(CommonConfigOptions::default())
+            .with_statistics_config(StatisticsConfigOptions::default().measure_cost(true))
+            .build()
+            .unwrap();
+        let mut vm = Vm::new(
+            Store::new(Some(&config), HashMap::<String, &mut dyn SyncInst>::new()).unwrap(),
+        );
+        let module = Module::from_bytes(Some(&config), &wasm_bytes).unwrap();
+        vm.register_module(None, module).unwrap();
+
+        let (returns, remaining) = vm
+            .run_func_in_fuel_mode(None, "add", params!(1, 2), &policy)
+            .unwrap();
+        assert_eq!(returns[0].to_i32(), 3);
+        // two `local.get`, `i32.add` and `end` are charged
+        let stat = vm.store_mut().executor().statistics_mut().unwrap();
+        let local_get = stat.cost(WasmOpcode(0x20).wasmedge_index().unwrap());
+        let end = stat.cost(WasmOpcode(0x0B).wasmedge_index().unwrap());
+        let i32_add = policy.costs[&WasmOpcode(0x6A)];
+        assert_eq!(remaining, policy.budget - 2 * local_get - i32_add - end);
+
+        // the infinite loop is stopped when the budget runs out
+        let result = vm.run_func_in_fuel_mode(None, "spin", params!(), &policy);
+        assert!(matches!(
+            *result.unwrap_err(),
+            WasmEdgeError::FuelExhausted(spent) if spent >= 100
+        ));
+
+        // the costs and the limit set before the call are restored
+        let i32_add = WasmOpcode(0x6A).wasmedge_index().unwrap();
+        let i32_sub = WasmOpcode(0x6B).wasmedge_index().unwrap();
+        let stat = vm.store_mut().executor().statistics_mut().unwrap();
+        stat.set_costs([(i32_sub, 5)]);
+        stat.set_cost_limit(1_000_000);
+        vm.run_func_in_fuel_mode(None, "add", params!(1, 2), &policy)
+            .unwrap();
+        let stat = vm.store_mut().executor().statistics_mut().unwrap();
+        assert_eq!(stat.cost(i32_add), 1);
+        assert_eq!(stat.cost(i32_sub), 5);
+        assert_eq!(stat.cost_limit(), 1_000_000);
+
+        // an unknown opcode is rejected
+        let policy = FuelPolicy {
+            costs: HashMap::from([(WasmOpcode(0x27), 10)]),
+            budget: 100,
+        };
+        assert!(vm
+            .run_func_in_fuel_mode(None, "add", params!(1, 2), &policy)
+            .is_err());
+    }
+
+    #[test]
+    fn test_vm_wasm_opcode_wasmedge_index() {
+        assert_eq!(WasmOpcode(0x00).wasmedge_index(), Some(0));
+        assert_eq!(WasmOpcode(0x18).wasmedge_index(), Some(22));
+        assert_eq!(WasmOpcode(0x6A).wasmedge_index(), Some(101));
+        assert_eq!(WasmOpcode(0xD6).wasmedge_index(), Some(198));
+        assert_eq!(WasmOpcode(0xFB00).wasmedge_index(), Some(199));
+        assert_eq!(WasmOpcode(0xFC00).wasmedge_index(), Some(230));
+        assert_eq!(WasmOpcode(0x16).wasmedge_index(), None);
+        assert_eq!(WasmOpcode(0xFD9A).wasmedge_index(), None);
+        assert_eq!(WasmOpcode(0xFF00).wasmedge_index(), None);
+    }
+
+    #[test]
+    fn test_vm_fuel_consumed() {
+        use crate::config::{CommonConfigOptions, ConfigBuilder, StatisticsConfigOptions};
+
+        // create a Vm context without instruction counting
+        let mut vm = new_vm();
+        assert_eq!(vm.store_mut().executor().fuel_consumed(), None);
+
+        // create a Vm context with instruction counting
+        let config = ConfigBuilder::new(CommonConfigOptions::default())
+            .with_statistics_config(StatisticsConfigOptions::default().count_instructions(true))
+            .build()
+            .unwrap();
+        let mut vm = Vm::new(
+            Store::new(Some(&config), HashMap::<String, &mut dyn SyncInst>::new()).unwrap(),
+        );
+
+        let wasm_bytes = wat2wasm(ADD_WAT).unwrap();
+        let module = Module::from_bytes(Some(&config), wasm_bytes).unwrap();
+        vm.register_module(None, module).unwrap();
+
+        vm.run_func(None, "add", params!(1, 2)).unwrap();
+        let fuel = vm.store_mut().executor().fuel_consumed().unwrap();
+        assert!(fuel > 0);
+
+        // the count is reset for each call
+        vm.run_func(None, "add", params!(3, 4)).unwrap();
+        assert_eq!(vm.store_mut().executor().fuel_consumed(), Some(fuel));
+    }
+
+    #[test]
+    #[cfg(feature = "profiling")]
+    fn test_vm_profiling_data() {
+        let mut vm = new_vm();
+        assert!(vm.profiling_data().is_none());
+
+        let wasm_bytes = wat2wasm(ADD_WAT).unwrap();
+        let module = Module::from_bytes(None, &wasm_bytes).unwrap();
+        vm.register_module(None, module).unwrap();
+        let module = Module::from_bytes(None, &wasm_bytes).unwrap();
+        vm.register_module(Some("math"), module).unwrap();
+
+        vm.run_func(None, "add", params!(1, 2)).unwrap();
+        vm.run_func(None, "add", params!(3, 4)).unwrap();
+        vm.run_func(Some("math"), "add", params!(5, 6)).unwrap();
+
+        let data = vm.profiling_data().unwrap();
+        assert_eq!(data.function_call_counts["add"], 2);
+        assert_eq!(data.function_call_counts["math::add"], 1);
+        assert!(data.function_total_time_ns.contains_key("add"));
+        assert!(data.function_total_time_ns.contains_key("math::add"));
+    }
+
+    #[test]
+    fn test_vm_import_globals() {
+        use crate::ImportObjectBuilder;
+
+        let mut import = ImportObjectBuilder::new("env", ())
+            .unwrap()
+            .with_const_global("version", WasmValue::from_i32(3))
+            .unwrap()
+            .with_mutable_global("counter", WasmValue::from_i64(10))
+            .unwrap()
+            .build();
+
+        let mut instances: HashMap<String, &mut dyn SyncInst> = HashMap::new();
+        instances.insert(import.name().unwrap(), &mut import);
+        let mut vm = Vm::new(Store::new(None, instances).unwrap());
+
+        let wasm_bytes = wat2wasm(
+            br#"(module
+              (import "env" "version" (global $version i32))
+              (import "env" "counter" (global $counter (mut i64)))
+              (func (export "version") (result i32) (global.get $version))
+              (func (export "incr") (result i64)
+                (global.set $counter (i64.add (global.get $counter) (i64.const 1)))
+                (global.get $counter))
+            )"#,
+        )
+        .unwrap();
+        let module = Module::from_bytes(None, wasm_bytes).unwrap();
+        vm.register_module(None, module).unwrap();
+
+        let returns = vm.run_func(None, "version", params!()).unwrap();
+        assert_eq!(returns[0].to_i32(), 3);
+        let returns = vm.run_func(None, "incr", params!()).unwrap();
+        assert_eq!(returns[0].to_i64(), 11);
+    }
+
+    #[test]
+    fn test_vm_store_new_owned() {
+        use crate::ImportObjectBuilder;
+
+        fn new_vm() -> Vm<'static, dyn SyncInst> {
+            let import = ImportObjectBuilder::new("env", ())
+                .unwrap()
+                .with_const_global("answer", WasmValue::from_i32(42))
+                .unwrap()
+                .build();
+
+            // the import module is moved into the store
+            let mut instances: HashMap<String, Box<dyn SyncInst>> = HashMap::new();
+            instances.insert(import.name().unwrap(), Box::new(import));
+            Vm::new(Store::new_owned(None, instances).unwrap())
+        }
+
+        let mut vm = new_vm();
+        assert!(vm.contains_module("env"));
+
+        let wasm_bytes = wat2wasm(
+            br#"(module
+              (import "env" "answer" (global $answer i32))
+              (func (export "answer") (result i32) (global.get $answer))
+            )"#,
+        )
+        .unwrap();
+        let module = Module::from_bytes(None, wasm_bytes).unwrap();
+        vm.register_module(None, module).unwrap();
+
+        let returns = vm.run_func(None, "answer", params!()).unwrap();
+        assert_eq!(returns[0].to_i32(), 42);
+    }
+
+    #[test]
+    fn test_vm_import_typed_func() {
+        use crate::{error::CoreError, CallingFrame, ImportObjectBuilder};
+
+        fn add(
+            data: &mut i64,
+            _inst: &mut Instance,
+            _frame: &mut CallingFrame,
+            (a, b): (i32, i64),
+        ) -> Result<i64, CoreError> {
+            *data += 1;
+            Ok(a as i64 + b)
+        }
+
+        fn count(
+            data: &mut i64,
+            _inst: &mut Instance,
+            _frame: &mut CallingFrame,
+            _args: (),
+        ) -> Result<(i64, f32), CoreError> {
+            Ok((*data, 0.5))
+        }
+
+        let mut builder = ImportObjectBuilder::new("env", 0i64).unwrap();
+        builder.with_typed_func("add", add).unwrap();
+        builder.with_typed_func("count", count).unwrap();
+        let mut import = builder.build();
+
+        let mut instances: HashMap<String, &mut dyn SyncInst> = HashMap::new();
+        instances.insert(import.name().unwrap(), &mut import);
+        let mut vm = Vm::new(Store::new(None, instances).unwrap());
+
+        let wasm_bytes = wat2wasm(
+            br#"(module
+              (import "env" "add" (func $add (param i32 i64) (result i64)))
+              (import "env" "count" (func $count (result i64 f32)))
+              (func (export "add") (param i32 i64) (result i64)
+                (call $add (local.get 0) (local.get 1)))
+              (func (export "count") (result i64 f32)
+                (call $count))
+            )"#,
+        )
+        .unwrap();
+        let module = Module::from_bytes(None, wasm_bytes).unwrap();
+        vm.register_module(None, module).unwrap();
+
+        let returns = vm.run_func(None, "add", params!(1, 2i64)).unwrap();
+        assert_eq!(returns[0].to_i64(), 3);
+        let returns = vm.run_func(None, "count", params!()).unwrap();
+        assert_eq!(returns[0].to_i64(), 1);
+        assert_eq!(returns[1].to_f32(), 0.5);
+    }
+
+    #[test]
+    fn test_vm_get_typed_func() {
+        let mut vm = new_vm();
+        let wasm_bytes = wat2wasm(
+            br#"(module
+              (func (export "add") (param i32 i64) (result i64)
+                (i64.add (i64.extend_i32_s (local.get 0)) (local.get 1)))
+              (func (export "swap") (param f32 f64) (result f64 f32)
+                (local.get 1) (local.get 0))
+              (func (export "nop"))
+            )"#,
+        )
+        .unwrap();
+        let module = Module::from_bytes(None, &wasm_bytes).unwrap();
+        vm.register_module(Some("math"), module).unwrap();
+        let module = Module::from_bytes(None, &wasm_bytes).unwrap();
+        vm.register_module(None, module).unwrap();
+
+        let mut add = vm.get_typed_func::<(i32, i64), i64>(None, "add").unwrap();
+        assert_eq!(add.call((1, 2)).unwrap(), 3);
+        assert_eq!(add.call((-1, 5)).unwrap(), 4);
+
+        let mut swap = vm
+            .get_typed_func::<(f32, f64), (f64, f32)>(Some("math"), "swap")
+            .unwrap();
+        assert_eq!(swap.call((0.5, 1.5)).unwrap(), (1.5, 0.5));
+
+        let mut nop = vm.get_typed_func::<(), ()>(Some("math"), "nop").unwrap();
+        nop.call(()).unwrap();
+
+        // the type of the function does not match
+        let result = vm.get_typed_func::<(i32, i32), i64>(None, "add");
+        assert_eq!(
+            *result.unwrap_err(),
+            WasmEdgeError::Core(CoreError::Execution(CoreExecutionError::FuncSigMismatch))
+        );
+        assert!(vm.get_typed_func::<(i32, i64), ()>(None, "add").is_err());
+
+        // the function or the module does not exist
+        assert!(vm.get_typed_func::<(), ()>(None, "missing").is_err());
+        assert!(vm.get_typed_func::<(), ()>(Some("missing"), "nop").is_err());
+    }
+
+    #[test]
+    fn test_vm_measure() {
+        let mut vm = new_vm();
+
+        let wasm_bytes = wat2wasm(
+            br#"(module
+              (memory (export "memory") 1)
+              (func (export "grow") (param i32) (result i32)
+                (memory.grow (local.get 0)))
+            )"#,
+        )
+        .unwrap();
+
+        let (returns, metrics) = vm.measure(None, &wasm_bytes, "grow", params!(2)).unwrap();
+        assert_eq!(returns[0].to_i32(), 1);
+        assert_eq!(metrics.memory_pages_before, 1);
+        assert_eq!(metrics.memory_pages_after, 3);
+        assert_eq!(metrics.instructions_executed, 0);
+
+        // the module is not kept
+        assert_eq!(vm.anonymous_instance_count(), 0);
+        assert!(vm.measure(None, &wasm_bytes, "missing", params!()).is_err());
+    }
+
+    #[test]
+    fn test_vm_run_func_with_timeout() {
+        let mut vm = new_vm();
+
+        let wasm_bytes = wat2wasm(ADD_SPIN_WAT).unwrap();
+        let module = Module::from_bytes(None, wasm_bytes).unwrap();
+        vm.register_module(None, module).unwrap();
+
+        let timeout = std::time::Duration::from_millis(100);
+        let returns = vm
+            .run_func_with_timeout(None, "add", params!(1, 2), timeout)
+            .unwrap();
+        assert_eq!(returns[0].to_i32(), 3);
+
+        let result = vm.run_func_with_timeout(None, "spin", params!(), timeout);
+        assert_eq!(result.unwrap_err(), Box::new(WasmEdgeError::ExecuteTimeout));
+    }
+
+    #[test]
+    fn test_vm_cancellation_token() {
+        let mut vm = new_vm();
+
+        let wasm_bytes = wat2wasm(ADD_SPIN_WAT).unwrap();
+        let module = Module::from_bytes(None, wasm_bytes).unwrap();
+        vm.register_module(None, module).unwrap();
+
+        let token = vm.cancellation_token();
+        let handle = std::thread::spawn(move || {
+            std::thread::sleep(std::time::Duration::from_millis(100));
+            token.cancel();
+        });
+        let result = vm.run_func_with_cancellation(None, "spin", params!());
+        assert_eq!(result.unwrap_err(), Box::new(WasmEdgeError::Cancelled));
+        handle.join().unwrap();
+
+        // the cancelled token is replaced, so the later calls run
+        assert!(!vm.cancellation_token().is_cancelled());
+        let returns = vm
+            .run_func_with_cancellation(None, "add", params!(1, 2))
+            .unwrap();
+        assert_eq!(returns[0].to_i32(), 3);
+    }
+
+    #[test]
+    fn test_vm_hotreload_module() {
+        use crate::error::HotreloadError;
+
+        // create a Vm context
+        let mut vm = new_vm();
+
+        let wasm_bytes =
+            wat2wasm(br#"(module (func (export "get") (result i32) (i32.const 1)))"#).unwrap();
+        let module = Module::from_bytes(None, wasm_bytes).unwrap();
+        vm.register_module(Some("extern"), module).unwrap();
+        let returns = vm.run_func(Some("extern"), "get", params!()).unwrap();
+        assert_eq!(returns[0].to_i32(), 1);
+
+        // reload the module with the same exports
+        let wasm_bytes =
+            wat2wasm(br#"(module (func (export "get") (result i32) (i32.const 2)))"#).unwrap();
+        let module = Module::from_bytes(None, wasm_bytes).unwrap();
+        vm.hotreload_module("extern", module).unwrap();
+        let returns = vm.run_func(Some("extern"), "get", params!()).unwrap();
+        assert_eq!(returns[0].to_i32(), 2);
+
+        // reload the module with different exports
+        let wasm_bytes =
+            wat2wasm(br#"(module (func (export "get") (result i64) (i64.const 3)))"#).unwrap();
+        let module = Module::from_bytes(None, wasm_bytes).unwrap();
+        let result = vm.hotreload_module("extern", module);
+        assert_eq!(
+            result.unwrap_err(),
+            Box::new(WasmEdgeError::Hotreload(HotreloadError::SignatureMismatch))
+        );
+        let returns = vm.run_func(Some("extern"), "get", params!()).unwrap();
+        assert_eq!(returns[0].to_i32(), 2);
+
+        // reload the module with unsatisfied imports
+        let wasm_bytes = wat2wasm(
+            br#"(module
+              (import "env" "missing" (func))
+              (func (export "get") (result i32) (i32.const 4))
+            )"#,
+        )
+        .unwrap();
+        let module = Module::from_bytes(None, wasm_bytes).unwrap();
+        assert!(matches!(
+            *vm.hotreload_module("extern", module).unwrap_err(),
+            WasmEdgeError::ImportNotSatisfied { .. }
+        ));
+        let returns = vm.run_func(Some("extern"), "get", params!()).unwrap();
+        assert_eq!(returns[0].to_i32(), 2);
+
+        // reload the module failing to instantiate, which registers the old module again
+        let wasm_bytes = wat2wasm(
+            br#"(module
+              (func $start unreachable)
+              (start $start)
+              (func (export "get") (result i32) (i32.const 4))
+            )"#,
+        )
+        .unwrap();
+        let module = Module::from_bytes(None, wasm_bytes).unwrap();
+        assert!(vm.hotreload_module("extern", module).is_err());
+        let returns = vm.run_func(Some("extern"), "get", params!()).unwrap();
+        assert_eq!(returns[0].to_i32(), 2);
+
+        // reload a module not registered
+        let wasm_bytes = wat2wasm(br#"(module)"#).unwrap();
+        let module = Module::from_bytes(None, wasm_bytes).unwrap();
+        assert!(vm.hotreload_module("not-exist", module).is_err());
+    }
 }
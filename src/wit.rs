@@ -0,0 +1,134 @@
+//! Defines the helpers for working with the [WIT](https://component-model.bytecodealliance.org/design/wit.html) (WebAssembly Interface Types) definitions.
+
+use std::str::FromStr;
+
+use crate::{
+    error::{CoreError, CoreExecutionError, WasmEdgeError},
+    FuncType, ImportObjectBuilder, ValType, WasmEdgeResult, WasmValue,
+};
+use wasmedge_sys::{CallingFrame, Instance};
+use wit_parser::{
+    abi::{AbiVariant, WasmType},
+    InterfaceId, Resolve,
+};
+
+/// A [WitInterface] is an interface parsed from a WIT definition, which describes the functions a component imports or exports.
+///
+/// # Example
+///
+/// ```rust
+/// use std::str::FromStr;
+/// use wasmedge_sdk::wit::WitInterface;
+///
+/// let iface = WitInterface::from_str(
+///     r#"
+///     package example:math;
+///
+///     interface math {
+///         add: func(a: s32, b: s32) -> s32;
+///     }
+///     "#,
+/// )
+/// .unwrap();
+/// assert_eq!(iface.name(), Some("math"));
+/// ```
+#[derive(Debug)]
+pub struct WitInterface {
+    resolve: Resolve,
+    id: InterfaceId,
+}
+impl WitInterface {
+    /// Returns the name of the interface, or `None` if the interface is anonymous.
+    pub fn name(&self) -> Option<&str> {
+        self.resolve.interfaces[self.id].name.as_deref()
+    }
+
+    /// Returns the names of the functions defined in the interface.
+    pub fn func_names(&self) -> Vec<String> {
+        self.resolve.interfaces[self.id]
+            .functions
+            .keys()
+            .cloned()
+            .collect()
+    }
+
+    /// Returns the core wasm [function types](crate::FuncType) of the functions defined in the interface, which are lowered by the canonical ABI. The types can be used to implement the host functions of the interface.
+    pub fn func_types(&self) -> Vec<(String, FuncType)> {
+        self.resolve.interfaces[self.id]
+            .functions
+            .iter()
+            .map(|(name, func)| {
+                let sig = self.resolve.wasm_signature(AbiVariant::GuestImport, func);
+                let args = sig.params.iter().map(to_val_type).collect();
+                let returns = sig.results.iter().map(to_val_type).collect();
+                (name.clone(), FuncType::new(args, returns))
+            })
+            .collect()
+    }
+
+    /// Scaffolds an [ImportObjectBuilder] for the host bindings of the interface, which is named after the interface.
+    ///
+    /// Each function of the interface is added as a placeholder host function with the lowered function type, which fails with [CoreExecutionError::HostFuncFailed](crate::error::CoreExecutionError) when called. To implement the functions, use [func_types](WitInterface::func_types) with [ImportObjectBuilder::with_func_by_type] instead.
+    ///
+    /// # Argument
+    ///
+    /// * `data` - The host context data shared by the host functions.
+    ///
+    /// # Error
+    ///
+    /// If fail to create the [ImportObjectBuilder] or add any host function, then an error is returned.
+    pub fn generate_import_object<Data>(
+        &self,
+        data: Data,
+    ) -> WasmEdgeResult<ImportObjectBuilder<Data>> {
+        let name = self.name().unwrap_or_default();
+        let mut builder = ImportObjectBuilder::new(name, data)?;
+        for (func_name, ty) in self.func_types() {
+            builder.with_func_by_type(func_name, ty, unimplemented_func::<Data>)?;
+        }
+        Ok(builder)
+    }
+}
+impl FromStr for WitInterface {
+    type Err = Box<WasmEdgeError>;
+
+    /// Parses a WIT definition and returns the first interface defined in it.
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        let mut resolve = Resolve::new();
+        let pkg_id = resolve.push_str("<inline>.wit", src).map_err(|e| {
+            Box::new(WasmEdgeError::Operation(format!(
+                "failed to parse the WIT definition: {e}"
+            )))
+        })?;
+        let id = resolve.packages[pkg_id]
+            .interfaces
+            .values()
+            .next()
+            .copied()
+            .ok_or_else(|| {
+                Box::new(WasmEdgeError::Operation(
+                    "no interface is defined in the WIT definition".into(),
+                ))
+            })?;
+
+        Ok(Self { resolve, id })
+    }
+}
+
+fn to_val_type(ty: &WasmType) -> ValType {
+    match ty {
+        WasmType::I32 | WasmType::Pointer | WasmType::Length => ValType::I32,
+        WasmType::I64 | WasmType::PointerOrI64 => ValType::I64,
+        WasmType::F32 => ValType::F32,
+        WasmType::F64 => ValType::F64,
+    }
+}
+
+fn unimplemented_func<Data>(
+    _data: &mut Data,
+    _inst: &mut Instance,
+    _frame: &mut CallingFrame,
+    _args: Vec<WasmValue>,
+) -> Result<Vec<WasmValue>, CoreError> {
+    Err(CoreError::Execution(CoreExecutionError::HostFuncFailed))
+}